@@ -11,8 +11,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ..Default::default()
     };
     
-    // Create Concat instance
-    let mut concat = Concat::new(tables);
+    // Create Concat instance on the data directory of the config file or working directory
+    let mut concat = Concat::new(tables, Datenquelle::standard())?;
     
     // Create sample data
     let mut relitable = vec![
@@ -39,7 +39,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // Test gleichheit_freiheit_vergleich
     for i in 1..=10 {
-        let result = concat.gleichheit_freiheit_vergleich(i);
+        let result = Concat::gleichheit_freiheit_vergleich(i);
         println!("{}: {}", i, result);
     }
     
@@ -0,0 +1,135 @@
+//! Breite module - display width of cell text by grapheme cluster and East Asian Width, ignoring ANSI escapes
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A piece of text that is either shown or only changes colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stueck<'a> {
+    /// One grapheme cluster and its width in terminal columns
+    Zeichen(&'a str, usize),
+    /// An ANSI escape sequence, zero columns wide
+    Steuer(&'a str),
+}
+
+/// Length of the escape sequence at the start of `text`: CSI (`ESC [ ... final`),
+/// OSC (`ESC ] ... BEL` or `ESC \`) or a two character escape
+fn steuer_laenge(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+    match bytes.get(1) {
+        Some(b'[') => Some(
+            bytes[2..]
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+                .map_or(bytes.len(), |i| i + 3),
+        ),
+        Some(b']') => {
+            let ende = bytes[2..].iter().enumerate().find_map(|(i, &b)| match b {
+                0x07 => Some(i + 3),
+                0x1b if bytes.get(i + 3) == Some(&b'\\') => Some(i + 4),
+                _ => None,
+            });
+            Some(ende.unwrap_or(bytes.len()))
+        }
+        Some(c) if c.is_ascii() => Some(2),
+        _ => Some(1),
+    }
+}
+
+/// Splits text into grapheme clusters and escape sequences
+pub fn stuecke(text: &str) -> Vec<Stueck<'_>> {
+    let mut stuecke = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let lauf = rest.find('\x1b').unwrap_or(rest.len());
+        for grapheme in rest[..lauf].graphemes(true) {
+            stuecke.push(Stueck::Zeichen(grapheme, grapheme_breite(grapheme)));
+        }
+        rest = &rest[lauf..];
+        if let Some(laenge) = steuer_laenge(rest) {
+            let laenge = laenge.min(rest.len());
+            stuecke.push(Stueck::Steuer(&rest[..laenge]));
+            rest = &rest[laenge..];
+        }
+    }
+    stuecke
+}
+
+/// Emoji sequences joined by ZWJ are drawn as one wide character
+fn grapheme_breite(grapheme: &str) -> usize {
+    if grapheme.chars().any(char::is_control) {
+        return 0;
+    }
+    grapheme.width().min(2)
+}
+
+/// Columns the text takes in a terminal
+pub fn anzeige_breite(text: &str) -> usize {
+    stuecke(text)
+        .iter()
+        .map(|stueck| match stueck {
+            Stueck::Zeichen(_, breite) => *breite,
+            Stueck::Steuer(_) => 0,
+        })
+        .sum()
+}
+
+/// Pads with spaces up to `breite` columns
+pub fn auffuellen(text: &str, breite: usize) -> String {
+    let luecke = breite.saturating_sub(anzeige_breite(text));
+    format!("{}{}", text, " ".repeat(luecke))
+}
+
+/// Cuts to at most `breite` columns, ending in "…" if anything was cut.
+/// Escape sequences are kept, so colors still get reset.
+pub fn abschneiden(text: &str, breite: usize) -> String {
+    if anzeige_breite(text) <= breite {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut belegt = 0;
+    let mut voll = false;
+    for stueck in stuecke(text) {
+        match stueck {
+            Stueck::Steuer(steuer) => out.push_str(steuer),
+            // One column stays free for the ellipsis
+            Stueck::Zeichen(zeichen, b) if !voll && belegt + b < breite => {
+                out.push_str(zeichen);
+                belegt += b;
+            }
+            Stueck::Zeichen(..) => voll = true,
+        }
+    }
+    if breite > 0 {
+        out.push('…');
+    }
+    out
+}
+
+/// Breaks text into lines of at most `breite` columns without splitting a grapheme;
+/// a grapheme wider than `breite` gets a line of its own
+pub fn umbrechen(text: &str, breite: usize) -> Vec<String> {
+    let mut zeilen = Vec::new();
+    let mut zeile = String::new();
+    let mut belegt = 0;
+    for stueck in stuecke(text) {
+        match stueck {
+            Stueck::Steuer(steuer) => zeile.push_str(steuer),
+            Stueck::Zeichen(zeichen, b) => {
+                if belegt > 0 && belegt + b > breite {
+                    zeilen.push(std::mem::take(&mut zeile));
+                    belegt = 0;
+                }
+                zeile.push_str(zeichen);
+                belegt += b;
+            }
+        }
+    }
+    if !zeile.is_empty() {
+        zeilen.push(zeile);
+    }
+    zeilen
+}
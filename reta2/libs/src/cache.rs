@@ -0,0 +1,234 @@
+//! Cache module - per-number metadata computed once and kept on disk between runs
+
+use std::borrow::Cow;
+use std::env;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::center::primfaktoren;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{
+    could_be_prime_number_primzahlkreuz_fuer_aussen,
+    could_be_prime_number_primzahlkreuz_fuer_innen, divisor_generator, prim_creativity,
+    prim_repeat,
+};
+use crate::types::OrderedDict;
+use crate::zahl::Zaehlungen;
+
+/// Bump when the layout of the cache or of its metadata changes
+pub const CACHE_VERSION: u32 = 3;
+
+/// Everything about one number that does not depend on the CSV contents
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZahlMetadaten {
+    pub primfaktoren: Vec<(i32, i32)>,
+    pub prim_creativity: i32,
+    pub teiler: Vec<i32>,
+    pub primzahlkreuz_innen: bool,
+    pub primzahlkreuz_aussen: bool,
+}
+
+impl ZahlMetadaten {
+    pub fn new(zahl: i32) -> Self {
+        let mut faktoren = prim_repeat(primfaktoren(zahl));
+        faktoren.sort_unstable();
+
+        Self {
+            primfaktoren: faktoren,
+            prim_creativity: prim_creativity(zahl),
+            teiler: divisor_generator(zahl),
+            primzahlkreuz_innen: could_be_prime_number_primzahlkreuz_fuer_innen(zahl),
+            primzahlkreuz_aussen: could_be_prime_number_primzahlkreuz_fuer_aussen(zahl),
+        }
+    }
+}
+
+/// Metadata for 1..=hoechste_zeile plus the countings, valid for one set of data files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadatenCache {
+    pub schluessel: String,
+    pub hoechste_zeile: i32,
+    pub zaehlungen: Zaehlungen,
+    pub zahlen: OrderedDict<i32, ZahlMetadaten>,
+}
+
+impl MetadatenCache {
+    pub fn berechnen(schluessel: String, hoechste_zeile: i32) -> Self {
+        Self {
+            schluessel,
+            hoechste_zeile,
+            zaehlungen: Zaehlungen::new(hoechste_zeile),
+            zahlen: (1..=hoechste_zeile)
+                .map(|zahl| (zahl, ZahlMetadaten::new(zahl)))
+                .collect(),
+        }
+    }
+
+    /// Reads the cache file if it was written for the same data files and
+    /// range, otherwise computes everything and tries to write it back.
+    /// A cache that cannot be written only costs the next run its speed.
+    pub fn laden_oder_berechnen(
+        cache_datei: &Path,
+        daten_dateien: &[PathBuf],
+        hoechste_zeile: i32,
+    ) -> Result<Self> {
+        let schluessel = cache_schluessel(daten_dateien, hoechste_zeile)?;
+
+        // Someone else's file may have been planted to feed us wrong metadata
+        if eigene_datei(cache_datei) {
+            if let Ok(inhalt) = fs::read(cache_datei) {
+                if let Ok(cache) = serde_json::from_slice::<Self>(&inhalt) {
+                    if cache.schluessel == schluessel && cache.hoechste_zeile == hoechste_zeile {
+                        return Ok(cache);
+                    }
+                }
+            }
+        }
+
+        let cache = Self::berechnen(schluessel, hoechste_zeile);
+        let _ = cache.speichern(cache_datei);
+        Ok(cache)
+    }
+
+    pub fn speichern(&self, cache_datei: &Path) -> Result<()> {
+        let inhalt = serde_json::to_vec(self).map_err(|e| ConcatError::Parse(e.to_string()))?;
+        if let Some(ordner) = cache_datei.parent().filter(|o| !o.as_os_str().is_empty()) {
+            ordner_anlegen(ordner)?;
+        }
+        // Write to a temporary file first so parallel runs never read half a cache;
+        // create_new refuses a file or symlink someone put there before
+        let temp = cache_datei.with_extension(format!("tmp{}", std::process::id()));
+        let mut datei = fs::OpenOptions::new().write(true).create_new(true).open(&temp)?;
+        if let Err(e) = datei.write_all(&inhalt).and_then(|_| fs::rename(&temp, cache_datei)) {
+            let _ = fs::remove_file(&temp);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, zahl: i32) -> Option<&ZahlMetadaten> {
+        self.zahlen.get(&zahl)
+    }
+}
+
+/// The countings up to `bis`: the cached ones if they reach that far, else counted anew
+pub fn zaehlungen_bis(cache: Option<&MetadatenCache>, bis: i32) -> Cow<'_, Zaehlungen> {
+    match cache {
+        Some(cache) if bis <= cache.zaehlungen.bis => Cow::Borrowed(&cache.zaehlungen),
+        _ => Cow::Owned(Zaehlungen::new(bis)),
+    }
+}
+
+/// All CSV files of the data directory, sorted, as the inputs of the cache key
+pub fn daten_dateien(datenordner: &Path) -> Result<Vec<PathBuf>> {
+    let mut dateien = Vec::new();
+    if datenordner.is_dir() {
+        for eintrag in fs::read_dir(datenordner)? {
+            let pfad = eintrag?.path();
+            if pfad.extension().is_some_and(|e| e == "csv") {
+                dateien.push(pfad);
+            }
+        }
+    }
+    dateien.sort();
+    Ok(dateien)
+}
+
+/// Base cache file location: `$RETA_CACHE`, else `reta_metadaten.json` in the user's
+/// cache directory, see `benutzer_cache_ordner`.
+/// Each data source gets its own file next to it, see `Datenquelle::cache_datei`.
+pub fn standard_cache_datei() -> PathBuf {
+    env::var_os("RETA_CACHE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| benutzer_cache_ordner().join("reta_metadaten.json"))
+}
+
+/// `$XDG_CACHE_HOME/reta`, else `~/.cache/reta`; without either a directory of this
+/// user in the system temp directory, never the shared temp directory itself
+pub fn benutzer_cache_ordner() -> PathBuf {
+    let nicht_leer = |name| env::var_os(name).filter(|wert| !wert.is_empty()).map(PathBuf::from);
+    nicht_leer("XDG_CACHE_HOME")
+        .or_else(|| nicht_leer("HOME").map(|home| home.join(".cache")))
+        .map(|ordner| ordner.join("reta"))
+        .unwrap_or_else(|| env::temp_dir().join(format!("reta-{}", benutzer_id())))
+}
+
+/// Creates the cache directory and its missing parents readable only by this user
+#[cfg(unix)]
+fn ordner_anlegen(ordner: &Path) -> Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+    fs::DirBuilder::new().recursive(true).mode(0o700).create(ordner)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn ordner_anlegen(ordner: &Path) -> Result<()> {
+    fs::create_dir_all(ordner)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn benutzer_id() -> u32 {
+    // geteuid cannot fail and has no preconditions
+    unsafe { libc::geteuid() }
+}
+
+#[cfg(not(unix))]
+fn benutzer_id() -> u32 {
+    0
+}
+
+/// Whether the file exists, is a regular file and belongs to this user
+#[cfg(unix)]
+fn eigene_datei(datei: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    fs::symlink_metadata(datei).is_ok_and(|m| m.is_file() && m.uid() == benutzer_id())
+}
+
+#[cfg(not(unix))]
+fn eigene_datei(datei: &Path) -> bool {
+    fs::symlink_metadata(datei).is_ok_and(|m| m.is_file())
+}
+
+/// Key from the cache version, the range and the names and contents of the data
+/// files, so an edited file invalidates the cache even with its old size and time
+pub fn cache_schluessel(daten_dateien: &[PathBuf], hoechste_zeile: i32) -> Result<String> {
+    let mut hash = Fnv1a::new();
+    for datei in daten_dateien {
+        let inhalt = fs::read(datei).map_err(|_| ConcatError::FileNotFound(datei.clone()))?;
+        let name = datei.file_name().map(|n| n.to_string_lossy().into_owned());
+        hash.schreiben(name.unwrap_or_default().as_bytes());
+        hash.schreiben(&[0]);
+        hash.schreiben(&(inhalt.len() as u64).to_le_bytes());
+        hash.schreiben(&inhalt);
+    }
+    Ok(format!(
+        "v{}-{}-{:016x}",
+        CACHE_VERSION,
+        hoechste_zeile,
+        hash.wert()
+    ))
+}
+
+/// FNV-1a, because the std hasher is not guaranteed to be stable between builds
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub(crate) fn schreiben(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    pub(crate) fn wert(&self) -> u64 {
+        self.0
+    }
+}
@@ -1,11 +1,16 @@
 //! Center module - equivalent to Python center module
 
-use std::collections::{HashMap, HashSet};
-use indexmap::{IndexSet, IndexMap};
-use num_rational::Ratio;
+use std::collections::{BTreeSet, HashSet};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
 use serde::{Deserialize, Serialize};
 
-pub type DefaultOrderedDict<K, V> = IndexMap<K, V>;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::divisor_generator;
+use crate::types::Table;
+
+pub use crate::types::DefaultOrderedDict;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Multiplikationen {
@@ -27,11 +32,7 @@ pub fn cliout(msg: &str) {
 }
 
 pub fn get_text_wrap_things(text: &str, width: usize) -> Vec<String> {
-    text.chars()
-        .collect::<Vec<char>>()
-        .chunks(width)
-        .map(|chunk| chunk.iter().collect())
-        .collect()
+    crate::breite::umbrechen(text, width)
 }
 
 pub mod i18n {
@@ -173,8 +174,8 @@ pub mod i18n {
     }
     
     // Convenience functions for specific keys
-    pub fn polygon1(s: &str) -> &str { " der eigenen Strukturgröße (" }
-    pub fn polygon2(s: &str) -> &str { ") auf dich bei gleichförmigen Polygonen" }
+    pub fn polygon1(_s: &str) -> &str { " der eigenen Strukturgröße (" }
+    pub fn polygon2(_s: &str) -> &str { ") auf dich bei gleichförmigen Polygonen" }
     pub fn gleichheit_freiheit_vergleich(s: &str) -> String { get(s) }
     pub fn energietopologie1(s: &str) -> String { get(s) }
     pub fn ausgabe_string(s: &str) -> String { get(s) }
@@ -232,7 +233,7 @@ pub fn primfaktoren(zahl: i32) -> Vec<i32> {
             n /= d;
         }
         d += 1;
-        if d * d > n {
+        if d > n / d {
             if n > 1 {
                 faktoren.push(n);
             }
@@ -244,13 +245,91 @@ pub fn primfaktoren(zahl: i32) -> Vec<i32> {
 }
 
 pub fn multiples(n: i32) -> Vec<(i32, i32)> {
-    let mut result = Vec::new();
-    for i in 1..=n {
-        if n % i == 0 {
-            result.push((i, n / i));
+    divisor_generator(n).into_iter().map(|i| (i, n / i)).collect()
+}
+
+/// Reads a semicolon separated reta CSV; row 0 is the heading, so row n belongs to number n
+pub fn csv_lesen(pfad: &Path) -> Result<Table> {
+    let file = File::open(pfad).map_err(|_| ConcatError::FileNotFound(pfad.to_path_buf()))?;
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(BufReader::new(file));
+
+    let mut table = Table::new();
+    for result in reader.records() {
+        let record = result?;
+        table.push(record.iter().map(|field| field.to_string()).collect());
+    }
+    Ok(table)
+}
+
+/// Highest number of a range expression without `max_zahl`; every number of a range is
+/// put into the set, so "1-2147483647" must not be taken at its word
+pub const BEREICH_HOECHSTENS: i32 = 1 << 20;
+
+/// Numbers of a range expression like "1-5,8,v3,-4,10-12+1", equivalent to
+/// Python's BereichToNumbers2: "v" means multiples, a leading "-" removes,
+/// "+a" adds the neighbours in distance a. A `max_zahl` of 0 without `vielfache`
+/// stands for no limit, which is `BEREICH_HOECHSTENS` here.
+pub fn bereich_to_numbers2(mehrere_bereiche: &str, vielfache: bool, max_zahl: i32) -> BTreeSet<i32> {
+    let max_zahl = if !vielfache && max_zahl == 0 { BEREICH_HOECHSTENS } else { max_zahl };
+    let mut dazu = BTreeSet::new();
+    let mut hinfort = BTreeSet::new();
+
+    for ein_bereich in mehrere_bereiche.split(',').map(str::trim).filter(|b| !b.is_empty()) {
+        let (ein_bereich, menge) = match ein_bereich.strip_prefix('-') {
+            Some(rest) => (rest, &mut hinfort),
+            None => (ein_bereich, &mut dazu),
+        };
+        let (ein_bereich, vielfache) = match ein_bereich.strip_prefix('v') {
+            Some(rest) => (rest, true),
+            None => (ein_bereich, vielfache),
+        };
+
+        let mut teile = ein_bereich.split('+');
+        let von_bis = teile.next().unwrap_or_default();
+        let around: Vec<i32> = teile.filter_map(|a| a.trim().parse().ok()).collect();
+        let (von, bis) = match von_bis.split_once('-') {
+            Some((von, bis)) => (von.trim().parse::<i32>(), bis.trim().parse::<i32>()),
+            None => (von_bis.trim().parse::<i32>(), von_bis.trim().parse::<i32>()),
+        };
+        let (von, bis) = match (von, bis) {
+            (Ok(von), Ok(bis)) if von > 0 && von <= bis => (von, bis),
+            _ => continue,
+        };
+
+        let mut einfuegen = |zahl: i32| {
+            if around.is_empty() && zahl > 0 && zahl <= max_zahl {
+                menge.insert(zahl);
+            }
+            for &a in &around {
+                for c in [zahl.checked_sub(a), zahl.checked_add(a)].into_iter().flatten() {
+                    if c > 0 && c <= max_zahl {
+                        menge.insert(c);
+                    }
+                }
+            }
+        };
+
+        for zahl in von..=bis.min(max_zahl) {
+            if vielfache {
+                let mut vielfaches = zahl;
+                while vielfaches <= max_zahl {
+                    einfuegen(vielfaches);
+                    match vielfaches.checked_add(zahl) {
+                        Some(naechstes) => vielfaches = naechstes,
+                        None => break,
+                    }
+                }
+            } else {
+                einfuegen(zahl);
+            }
         }
     }
-    result
+
+    dazu.difference(&hinfort).copied().collect()
 }
 
 pub fn unique_everseen<T, I, F>(iter: I, key: F) -> Vec<T>
@@ -277,7 +356,10 @@ pub fn x<T: std::fmt::Debug>(label: &str, value: T) {
     println!("{}: {:?}", label, value);
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Highest denominator of the gebrochen-rational columns (uni, gal, emo, groe)
+pub const GEBROCHEN_SPALTEN_HOECHSTENS: i32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NPmEnum {
     UniN,
     Uni1pN,
@@ -314,6 +396,32 @@ impl NPmEnum {
         vec![Self::Uni1pN, Self::Gal1pN, Self::Emo1pN, Self::Groe1pN]
     }
     
+    /// Classification of a whole number: every category has its n/1 row, the
+    /// 1/n columns only exist for the gebrochen-rational parameters 2 to
+    /// `GEBROCHEN_SPALTEN_HOECHSTENS`, as in the former reta's store_parameters
+    pub fn of_zahl(zahl: i32) -> Vec<Self> {
+        if (2..=GEBROCHEN_SPALTEN_HOECHSTENS).contains(&zahl) {
+            Self::n().into_iter().chain(Self::eins_pn()).collect()
+        } else if zahl >= 1 {
+            Self::n()
+        } else {
+            Vec::new()
+        }
+    }
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UniN => "uniN",
+            Self::Uni1pN => "uni1pN",
+            Self::GalN => "galN",
+            Self::Gal1pN => "gal1pN",
+            Self::EmoN => "emoN",
+            Self::Emo1pN => "emo1pN",
+            Self::GroeN => "groeN",
+            Self::Groe1pN => "groe1pN",
+        }
+    }
+    
     pub fn value(&self) -> usize {
         match self {
             Self::UniN => 4,
@@ -0,0 +1,328 @@
+//! Classifier module - number categories shared by row filters, coloring and generated columns
+
+use std::sync::{RwLock, RwLockReadGuard};
+
+use once_cell::sync::Lazy;
+
+use crate::center::{primfaktoren, NPmEnum};
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{
+    could_be_prime_number_primzahlkreuz_fuer_aussen,
+    could_be_prime_number_primzahlkreuz_fuer_innen, moon_number, prim_creativity, prim_repeat,
+    STANDARD_FARBEN,
+};
+use crate::types::{OrderedDict, OrderedSet};
+
+/// A number category: maps a number to the labels it carries
+pub trait Classifier: Send + Sync {
+    /// Key in the registry and header of the generated column
+    fn name(&self) -> &'static str;
+
+    /// Every label this classifier can return, in display order
+    fn alle_labels(&self) -> Vec<&'static str>;
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str>;
+
+    /// Cell text of the generated column
+    fn zelle(&self, zahl: i32) -> String {
+        self.labels(zahl).join(", ")
+    }
+
+    /// Color class of a number for HTML, ODS and diagrams; None leaves it to others
+    fn farb_klasse(&self, _zahl: i32) -> Option<&'static str> {
+        None
+    }
+
+    /// The color classes `farb_klasse` returns, with background and foreground
+    fn farben(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        Vec::new()
+    }
+
+    /// Terminal colors of a number as ANSI escapes; None leaves it to others
+    fn shell_farben(&self, _zahl: i32) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Equivalent to prim_creativity: 1, prime, prime multiple or moon number
+pub struct PrimCreativity;
+
+impl Classifier for PrimCreativity {
+    fn name(&self) -> &'static str {
+        "primkreativitaet"
+    }
+
+    fn alle_labels(&self) -> Vec<&'static str> {
+        vec!["eins", "primzahl", "primvielfaches", "mondzahl"]
+    }
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str> {
+        if zahl < 1 {
+            return Vec::new();
+        }
+        match prim_creativity(zahl) {
+            0 => vec!["eins"],
+            1 => vec!["primzahl"],
+            2 => vec!["primvielfaches"],
+            _ => vec!["mondzahl"],
+        }
+    }
+
+    fn farb_klasse(&self, zahl: i32) -> Option<&'static str> {
+        let gerade = zahl % 2 == 0;
+        Some(match *self.labels(zahl).first()? {
+            "primzahl" if gerade => "primzahl-gerade",
+            "primzahl" => "primzahl-ungerade",
+            "eins" | "primvielfaches" if gerade => "vielfaches-gerade",
+            "eins" | "primvielfaches" => "vielfaches-ungerade",
+            "mondzahl" if gerade => "mondzahl-gerade",
+            "mondzahl" => "mondzahl-ungerade",
+            _ => return None,
+        })
+    }
+
+    fn farben(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        STANDARD_FARBEN
+            .iter()
+            .copied()
+            .filter(|(klasse, _, _)| *klasse != "null")
+            .collect()
+    }
+
+    fn shell_farben(&self, zahl: i32) -> Option<&'static str> {
+        match self.labels(zahl).first() {
+            Some(&"primzahl") if zahl % 2 == 0 => Some("\x1b[103m\x1b[30m\x1b[1m"),
+            Some(&"primzahl") => Some("\x1b[43m\x1b[30m"),
+            _ => None,
+        }
+    }
+}
+
+/// Moons, suns, planets and black suns, as in Prepare.filterOriginalLines
+pub struct Gestirn;
+
+impl Classifier for Gestirn {
+    fn name(&self) -> &'static str {
+        "gestirn"
+    }
+
+    fn alle_labels(&self) -> Vec<&'static str> {
+        vec!["mond", "sonne", "planet", "schwarzesonne", "sonnemitmondanteil"]
+    }
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str> {
+        if zahl < 1 {
+            return Vec::new();
+        }
+        let mut labels = Vec::new();
+        if moon_number(zahl).0.is_empty() {
+            labels.push("sonne");
+        } else {
+            labels.push("mond");
+        }
+        if zahl % 2 == 0 {
+            labels.push("planet");
+        }
+        if zahl % 3 == 0 {
+            labels.push("schwarzesonne");
+        }
+        let anzahlen = prim_repeat(primfaktoren(zahl));
+        if anzahlen.iter().any(|&(_, n)| n == 1) && anzahlen.iter().any(|&(_, n)| n > 1) {
+            labels.push("sonnemitmondanteil");
+        }
+        labels
+    }
+
+    fn shell_farben(&self, zahl: i32) -> Option<&'static str> {
+        match (self.labels(zahl).contains(&"mond"), zahl % 2 == 0) {
+            (false, _) => None,
+            (true, true) => Some("\x1b[106m\x1b[30m"),
+            (true, false) => Some("\x1b[46m\x1b[30m"),
+        }
+    }
+}
+
+/// Position of primes on the Primzahlkreuz
+pub struct Primzahlkreuz;
+
+impl Classifier for Primzahlkreuz {
+    fn name(&self) -> &'static str {
+        "primzahlkreuz"
+    }
+
+    fn alle_labels(&self) -> Vec<&'static str> {
+        vec!["innen", "aussen"]
+    }
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str> {
+        let mut labels = Vec::new();
+        if could_be_prime_number_primzahlkreuz_fuer_innen(zahl) {
+            labels.push("innen");
+        }
+        if could_be_prime_number_primzahlkreuz_fuer_aussen(zahl) {
+            labels.push("aussen");
+        }
+        labels
+    }
+}
+
+/// The nPm categories of NPmEnum
+pub struct NPm;
+
+impl Classifier for NPm {
+    fn name(&self) -> &'static str {
+        "npm"
+    }
+
+    fn alle_labels(&self) -> Vec<&'static str> {
+        NPmEnum::n()
+            .into_iter()
+            .chain(NPmEnum::eins_pn())
+            .map(|e| e.name())
+            .collect()
+    }
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str> {
+        NPmEnum::of_zahl(zahl).iter().map(NPmEnum::name).collect()
+    }
+}
+
+/// Classifiers by name, in registration order
+pub struct ClassifierRegistry {
+    klassifikatoren: OrderedDict<&'static str, Box<dyn Classifier>>,
+}
+
+static STANDARD: Lazy<RwLock<ClassifierRegistry>> =
+    Lazy::new(|| RwLock::new(ClassifierRegistry::default()));
+
+impl Default for ClassifierRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(PrimCreativity));
+        registry.register(Box::new(Gestirn));
+        registry.register(Box::new(Primzahlkreuz));
+        registry.register(Box::new(NPm));
+        registry
+    }
+}
+
+impl ClassifierRegistry {
+    /// An empty registry, see `default` for the built-in classifiers
+    pub fn new() -> Self {
+        Self {
+            klassifikatoren: OrderedDict::new(),
+        }
+    }
+
+    /// The shared registry behind filters, coloring, grouping and generated columns;
+    /// starts with the built-in classifiers, see `classifier_registrieren`
+    pub fn standard() -> &'static RwLock<Self> {
+        &STANDARD
+    }
+
+    /// Adds a classifier, replacing one with the same name
+    pub fn register(&mut self, klassifikator: Box<dyn Classifier>) {
+        self.klassifikatoren.insert(klassifikator.name(), klassifikator);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Classifier> {
+        self.klassifikatoren.get(name).map(|k| k.as_ref())
+    }
+
+    pub fn namen(&self) -> Vec<&'static str> {
+        self.klassifikatoren.keys().copied().collect()
+    }
+
+    /// Every label of every classifier
+    pub fn alle_labels(&self) -> OrderedSet<&'static str> {
+        self.klassifikatoren
+            .values()
+            .flat_map(|k| k.alle_labels())
+            .collect()
+    }
+
+    /// Union of the labels of all classifiers for one number
+    pub fn labels(&self, zahl: i32) -> OrderedSet<&'static str> {
+        self.klassifikatoren
+            .values()
+            .flat_map(|k| k.labels(zahl))
+            .collect()
+    }
+
+    pub fn hat(&self, zahl: i32, label: &str) -> bool {
+        self.klassifikatoren
+            .values()
+            .any(|k| k.labels(zahl).contains(&label))
+    }
+
+    /// Parses a `--typ=` list like "sonne,primzahl", rejecting unknown labels
+    pub fn typen(&self, typen: &str) -> Result<Vec<String>> {
+        let bekannt = self.alle_labels();
+        typen
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| {
+                if bekannt.contains(t) {
+                    Ok(t.to_string())
+                } else {
+                    Err(ConcatError::Parse(format!("unknown number type: {}", t)))
+                }
+            })
+            .collect()
+    }
+
+    /// Row filter: true if the number carries any of the labels, or if none are given
+    pub fn passt(&self, zahl: i32, typen: &[String]) -> bool {
+        typen.is_empty() || typen.iter().any(|t| self.hat(zahl, t))
+    }
+
+    /// Cell text of the generated column of one classifier
+    pub fn zelle(&self, name: &str, zahl: i32) -> Result<String> {
+        self.get(name)
+            .map(|k| k.zelle(zahl))
+            .ok_or_else(|| ConcatError::Parse(format!("unknown classifier: {}", name)))
+    }
+
+    /// Color class of a number; classifiers registered later take precedence
+    pub fn farb_klasse(&self, zahl: i32) -> Option<&'static str> {
+        self.klassifikatoren
+            .values()
+            .rev()
+            .find_map(|k| k.farb_klasse(zahl))
+    }
+
+    /// Terminal colors of a number; classifiers registered later take precedence
+    pub fn shell_farben(&self, zahl: i32) -> Option<&'static str> {
+        self.klassifikatoren
+            .values()
+            .rev()
+            .find_map(|k| k.shell_farben(zahl))
+    }
+
+    /// Every color class of every classifier, each once
+    pub fn farben(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        let mut farben: Vec<(&'static str, &'static str, &'static str)> = Vec::new();
+        for farbe in self.klassifikatoren.values().flat_map(|k| k.farben()) {
+            if !farben.iter().any(|(klasse, _, _)| *klasse == farbe.0) {
+                farben.push(farbe);
+            }
+        }
+        farben
+    }
+}
+
+/// Adds a classifier to the shared registry, replacing one with the same name
+pub fn classifier_registrieren(klassifikator: Box<dyn Classifier>) {
+    ClassifierRegistry::standard()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(klassifikator);
+}
+
+/// The shared registry for reading
+pub fn klassifikatoren() -> RwLockReadGuard<'static, ClassifierRegistry> {
+    ClassifierRegistry::standard()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+}
@@ -1,16 +1,20 @@
 use crate::{
+    cache::{MetadatenCache, ZahlMetadaten},
     center::*,
     lib4tables::*,
-    lib4tables_enum::ST,
+    lib4tables_enum::SpaltenTypKey,
+    datenquelle::Datenquelle,
+    spalten::{SpaltenDefinition, SpaltenRegistry},
     types::*,
     errors::*,
 };
-use std::path::{Path, PathBuf};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::fs::File;
-use std::io::{BufReader, BufRead};
+use std::io::BufReader;
 use num_rational::Ratio;
-use indexmap::IndexSet;
-use rayon::prelude::*;
 
 /// Main Concat struct - equivalent to Python Concat class
 #[derive(Debug, Clone)]
@@ -38,11 +42,23 @@ pub struct Concat {
     pub gebr_rat_etwa_schon_mal_dabei_gewesen: OrderedSet<Ratio<i32>>,
     pub strukt_and_invers_spalten: Option<(usize, usize)>,
     pub gebr_univ_table_4meta_konkret: Option<Table>,
+    /// Column lookups by id, the spalten.json of `datenquelle`
+    pub spalten: SpaltenRegistry,
+    /// Where prim.csv and the bruch*.csv files are read from
+    pub datenquelle: Datenquelle,
+    /// Prime factors, creativity and moons of the numbers, see `mit_metadaten`
+    pub metadaten: Option<Arc<MetadatenCache>>,
 }
 
 impl Concat {
-    /// Create a new Concat instance
-    pub fn new(tables: Tables) -> Self {
+    /// Create a new Concat instance on the data of `datenquelle`, with its column registry
+    pub fn new(tables: Tables, datenquelle: Datenquelle) -> Result<Self> {
+        let spalten = datenquelle.spalten_registry()?;
+        Ok(Self::mit_spalten(tables, datenquelle, spalten))
+    }
+
+    /// The same with a column registry that was already read, like the one of `Daten`
+    pub fn mit_spalten(tables: Tables, datenquelle: Datenquelle, spalten: SpaltenRegistry) -> Self {
         Self {
             tables,
             ones: OrderedSet::new(),
@@ -73,9 +89,34 @@ impl Concat {
             gebr_rat_etwa_schon_mal_dabei_gewesen: OrderedSet::new(),
             strukt_and_invers_spalten: None,
             gebr_univ_table_4meta_konkret: None,
+            spalten,
+            datenquelle,
+            metadaten: None,
+        }
+    }
+
+    /// Takes the per-number metadata from the cache instead of computing it for every row
+    pub fn mit_metadaten(mut self, cache: Arc<MetadatenCache>) -> Self {
+        self.metadaten = Some(cache);
+        self
+    }
+
+    /// Metadata of a number: the cached one, else computed
+    fn zahl_metadaten(&self, zahl: i32) -> Cow<'_, ZahlMetadaten> {
+        match self.metadaten.as_ref().and_then(|cache| cache.get(zahl)) {
+            Some(metadaten) => Cow::Borrowed(metadaten),
+            None => Cow::Owned(ZahlMetadaten::new(zahl)),
         }
     }
 
+    /// Moon bases and exponents minus 2 of a number: the counted ones, else computed
+    fn monde(&self, zahl: i32) -> (Vec<i32>, Vec<i32>) {
+        self.metadaten
+            .as_ref()
+            .and_then(|cache| cache.zaehlungen.monde.get(&zahl).cloned())
+            .unwrap_or_else(|| moon_number(zahl))
+    }
+
     /// Equivalent to Python's concatLovePolygon method
     pub fn concat_love_polygon(
         &mut self,
@@ -83,25 +124,27 @@ impl Concat {
         rows_as_numbers: &mut HashSet<usize>,
     ) -> Result<()> {
         self.relitable = Some(relitable.clone());
+        let parameter = self.spalten.nummer("liebe_polygon")?;
+        let liebe = self.spalten.nummer("liebe")?;
+        let strukturgroesse = self.spalten.nummer("strukturgroesse")?;
         
-        if rows_as_numbers.contains(&9) {
-            if let Some(first_row_len) = relitable.get(0).map(|r| r.len()) {
+        if rows_as_numbers.contains(&parameter) {
+            if let Some(first_row_len) = relitable.first().map(|r| r.len()) {
                 rows_as_numbers.insert(first_row_len);
                 
-                let tags = vec![ST::SternPolygon, ST::Galaxie, ST::GleichfoermigesPolygon];
                 self.tables.generated_spalten_parameter_tags.insert(
                     rows_as_numbers.len() - 1,
-                    tags.into_iter().collect(),
+                    self.spalten.tags("liebe_polygon_generiert")?,
                 );
                 
                 for i in 0..relitable.len() {
                     if let Some(row) = relitable.get_mut(i) {
-                        if row.len() > 8 && !row[8].trim().is_empty() {
+                        if row.len() > liebe && !row[liebe].trim().is_empty() {
                             let new_value = format!(
                                 "{}{}{}{}",
-                                row[8],
+                                row[liebe],
                                 i18n::polygon1(" der eigenen Strukturgröße ("),
-                                row.get(4).unwrap_or(&String::new()),
+                                row.get(strukturgroesse).unwrap_or(&String::new()),
                                 i18n::polygon2(") auf dich bei gleichförmigen Polygonen")
                             );
                             row.push(new_value);
@@ -118,11 +161,11 @@ impl Concat {
                     return Err(ConcatError::DuplicateIndex);
                 }
                 
-                if let Some(row) = self.tables.data_dict.get(&0) {
-                    if row.len() > 9 {
+                if let Some(row) = self.tables.data_dict.get(&SpaltenTypKey::Ordinary.schluessel()) {
+                    if row.len() > parameter {
                         self.tables.generated_spalten_parameter.insert(
                             new_index,
-                            row[9].clone(),
+                            row[parameter].clone(),
                         );
                     }
                 }
@@ -134,7 +177,7 @@ impl Concat {
     }
 
     /// Equivalent to Python's gleichheitFreiheitVergleich method
-    pub fn gleichheit_freiheit_vergleich(&self, zahl: i32) -> String {
+    pub fn gleichheit_freiheit_vergleich(zahl: i32) -> String {
         let mut ausgabe_string_list = Vec::new();
         
         match zahl % 4 {
@@ -160,7 +203,12 @@ impl Concat {
 
     /// Equivalent to Python's geistEmotionEnergieMaterieTopologie method
     pub fn geist_emotion_energie_materie_topologie(&self, zahl: i32) -> String {
-        let pr_fa = primfaktoren(zahl);
+        Self::geist_emotion_energie_materie_topologie_von(&self.zahl_metadaten(zahl))
+    }
+
+    /// The same from the metadata of the number
+    pub fn geist_emotion_energie_materie_topologie_von(metadaten: &ZahlMetadaten) -> String {
+        let pr_fa: Vec<i32> = metadaten.primfaktoren.iter().map(|&(prim, _)| prim).collect();
         let auss: Vec<bool> = pr_fa.iter()
             .map(|&a| could_be_prime_number_primzahlkreuz_fuer_aussen(a))
             .collect();
@@ -168,7 +216,11 @@ impl Concat {
             .map(|&a| could_be_prime_number_primzahlkreuz_fuer_innen(a))
             .collect();
         
-        let zwei = pr_fa.iter().filter(|&&a| a == 2).count();
+        let zwei = metadaten
+            .primfaktoren
+            .iter()
+            .find(|&&(prim, _)| prim == 2)
+            .map_or(0, |&(_, anzahl)| anzahl as usize);
         let gefuehl = auss.iter().any(|&a| a);
         let denken = innen.iter().any(|&a| a);
         
@@ -179,10 +231,14 @@ impl Concat {
         let wenig_materie = zwei == 3;
         let kaum_materie = zwei == 2;
         
-        let (x, y, z) = (denken, pr_fa.contains(&2), pr_fa.contains(&3));
-        let total_energie = x && y && z;
-        let einermassen_energie = ((x && y) || (y && z) || (x && z)) && !total_energie;
-        let kaum_energie = !einermassen_energie && !total_energie && (x || y || z);
+        // All three, exactly two or exactly one of thinking, 2 and 3
+        let energien = [denken, pr_fa.contains(&2), pr_fa.contains(&3)]
+            .iter()
+            .filter(|&&e| e)
+            .count();
+        let total_energie = energien == 3;
+        let einermassen_energie = energien == 2;
+        let kaum_energie = energien == 1;
         
         let mut ausgabe_string_list = Vec::new();
         
@@ -230,15 +286,15 @@ impl Concat {
         rows_as_numbers: &mut HashSet<usize>,
     ) -> Result<()> {
         self.relitable = Some(relitable.clone());
+        let parameter = self.spalten.nummer("gleichheit_freiheit")?;
         
-        if rows_as_numbers.contains(&132) {
-            if let Some(first_row_len) = relitable.get(0).map(|r| r.len()) {
+        if rows_as_numbers.contains(&parameter) {
+            if let Some(first_row_len) = relitable.first().map(|r| r.len()) {
                 rows_as_numbers.insert(first_row_len);
                 
-                let tags = vec![ST::SternPolygon, ST::Universum];
                 self.tables.generated_spalten_parameter_tags.insert(
                     rows_as_numbers.len() - 1,
-                    tags.into_iter().collect(),
+                    self.spalten.tags("gleichheit_freiheit_generiert")?,
                 );
                 
                 for i in 0..=self.tables.last_line_number {
@@ -247,7 +303,7 @@ impl Concat {
                             "Gleichheit, Freiheit, Dominieren (Ordnungen [12]) Generiert"
                         )
                     } else {
-                        self.gleichheit_freiheit_vergleich(i as i32)
+                        Self::gleichheit_freiheit_vergleich(i as i32)
                     };
                     
                     if let Some(row) = relitable.get_mut(i) {
@@ -262,11 +318,11 @@ impl Concat {
                     return Err(ConcatError::DuplicateIndex);
                 }
                 
-                if let Some(row) = self.tables.data_dict.get(&0) {
-                    if row.len() > 132 {
+                if let Some(row) = self.tables.data_dict.get(&SpaltenTypKey::Ordinary.schluessel()) {
+                    if row.len() > parameter {
                         self.tables.generated_spalten_parameter.insert(
                             new_index,
-                            row[132].clone(),
+                            row[parameter].clone(),
                         );
                     }
                 }
@@ -284,15 +340,15 @@ impl Concat {
         rows_as_numbers: &mut HashSet<usize>,
     ) -> Result<()> {
         self.relitable = Some(relitable.clone());
+        let parameter = self.spalten.nummer("energie_topologie")?;
         
-        if rows_as_numbers.contains(&242) {
-            if let Some(first_row_len) = relitable.get(0).map(|r| r.len()) {
+        if rows_as_numbers.contains(&parameter) {
+            if let Some(first_row_len) = relitable.first().map(|r| r.len()) {
                 rows_as_numbers.insert(first_row_len);
                 
-                let tags = vec![ST::SternPolygon, ST::Universum];
                 self.tables.generated_spalten_parameter_tags.insert(
                     rows_as_numbers.len() - 1,
-                    tags.into_iter().collect(),
+                    self.spalten.tags("energie_topologie_generiert")?,
                 );
                 
                 for i in 0..=self.tables.last_line_number {
@@ -316,11 +372,11 @@ impl Concat {
                     return Err(ConcatError::DuplicateIndex);
                 }
                 
-                if let Some(row) = self.tables.data_dict.get(&0) {
-                    if row.len() > 242 {
+                if let Some(row) = self.tables.data_dict.get(&SpaltenTypKey::Ordinary.schluessel()) {
+                    if row.len() > parameter {
                         self.tables.generated_spalten_parameter.insert(
                             new_index,
-                            row[242].clone(),
+                            row[parameter].clone(),
                         );
                     }
                 }
@@ -338,23 +394,22 @@ impl Concat {
         rows_as_numbers: &mut HashSet<usize>,
     ) -> Result<()> {
         self.relitable = Some(relitable.clone());
+        let parameter = self.spalten.nummer("kreativitaet")?;
         
-        if rows_as_numbers.contains(&64) {
-            if let Some(first_row_len) = relitable.get(0).map(|r| r.len()) {
+        if rows_as_numbers.contains(&parameter) {
+            if let Some(first_row_len) = relitable.first().map(|r| r.len()) {
                 rows_as_numbers.insert(first_row_len);
                 
-                let tags = vec![ST::SternPolygon, ST::Galaxie];
                 self.tables.generated_spalten_parameter_tags.insert(
                     rows_as_numbers.len() - 1,
-                    tags.into_iter().collect(),
+                    self.spalten.tags("primkreativitaet_generiert")?,
                 );
                 
                 for i in 0..=self.tables.last_line_number {
-                    let prim_creativity_type = prim_creativity(i as i32);
                     let value = if i == 0 {
                         i18n::krea_zahl("Evolutions-Züchtungs-Kreativität")
                     } else {
-                        match prim_creativity_type {
+                        match self.zahl_metadaten(i as i32).prim_creativity {
                             0 => i18n::krea_zahl("0. Primzahl 1"),
                             1 => i18n::krea_zahl("1. Primzahl und Sonnenzahl"),
                             2 => i18n::krea_zahl("2. Sonnenzahl, aber keine Primzahl"),
@@ -375,11 +430,11 @@ impl Concat {
                     return Err(ConcatError::DuplicateIndex);
                 }
                 
-                if let Some(row) = self.tables.data_dict.get(&0) {
-                    if row.len() > 64 {
+                if let Some(row) = self.tables.data_dict.get(&SpaltenTypKey::Ordinary.schluessel()) {
+                    if row.len() > parameter {
                         self.tables.generated_spalten_parameter.insert(
                             new_index,
-                            row[64].clone(),
+                            row[parameter].clone(),
                         );
                     }
                 }
@@ -397,31 +452,37 @@ impl Concat {
         rows_as_numbers: &mut HashSet<usize>,
     ) -> Result<()> {
         self.relitable = Some(relitable.clone());
+        let strukturgroesse = self.spalten.nummer("strukturgroesse")?;
+        let wirkung = self.spalten.nummer("mond_wirkung")?;
+        let wirkung_zusatz = self.spalten.nummer("mond_wirkung_zusatz")?;
+        let exponent = self.spalten.nummer("mond_exponent")?;
         
-        if rows_as_numbers.contains(&64) {
-            let hardcoded_couple = (44, 56);
-            let row_headings = [
-                i18n::mond_exp_log1("Mond-Typ eines Sternpolygons"),
-                i18n::mond_exp_log1("Mond-Typ eines gleichförmigen Polygons"),
-            ];
-            
-            for (rownum, rowheading) in hardcoded_couple.into_iter().zip(row_headings) {
-                if let Some(first_row_len) = relitable.get(0).map(|r| r.len()) {
+        // Moon type columns of star and uniform polygons, with the column they are made from
+        let mut generiert = Vec::new();
+        for definition in self.spalten.mit_generator("concat_mond_exponzieren_logarithmus_typ") {
+            let (Some(parameter), Some(aus)) = (&definition.parameter, &definition.aus) else {
+                continue;
+            };
+            generiert.push((
+                self.spalten.nummer(parameter)?,
+                self.spalten.nummer(aus)?,
+                i18n::mond_exp_log1(definition.name("de")),
+                definition.tag_menge(),
+            ));
+        }
+        
+        for (parameter, rownum, rowheading, tags) in generiert {
+            if rows_as_numbers.contains(&parameter) {
+                if let Some(first_row_len) = relitable.first().map(|r| r.len()) {
                     rows_as_numbers.insert(first_row_len);
                     
-                    let tags = if rownum == 44 {
-                        vec![ST::SternPolygon, ST::Universum, ST::Galaxie]
-                    } else {
-                        vec![ST::GleichfoermigesPolygon, ST::Universum, ST::Galaxie]
-                    };
-                    
                     self.tables.generated_spalten_parameter_tags.insert(
                         rows_as_numbers.len() - 1,
-                        tags.into_iter().collect(),
+                        tags,
                     );
                     
                     for i in 0..=self.tables.last_line_number {
-                        let moon_types_of_1_num = moon_number(i as i32);
+                        let moon_types_of_1_num = self.monde(i as i32);
                         let into = if i == 0 {
                             vec![rowheading.to_string()]
                         } else {
@@ -456,12 +517,12 @@ impl Concat {
                                 
                                 // Handle SG replacement
                                 let insert = if let Some(row_i) = relitable.get(i) {
-                                    if row_i.len() > 4 {
-                                        let sg = row_i[4].trim();
+                                    if row_i.len() > strukturgroesse {
+                                        let sg = row_i[strukturgroesse].trim();
                                         self.relitable.as_ref()
                                             .and_then(|r| r.get(*basis as usize))
                                             .and_then(|row_basis| {
-                                                if row_basis.len() > rownum as usize {
+                                                if row_basis.len() > rownum {
                                                     let mut text = row_basis[rownum].clone();
                                                     text = text.replace("<SG>", sg);
                                                     text = text.replace("&lt;SG&gt;", sg);
@@ -482,8 +543,8 @@ impl Concat {
                                 parts.push(" - ".to_string());
                                 
                                 if let Some(row_exp) = relitable.get((exponent_minus2 + 2) as usize) {
-                                    if row_exp.len() > 10 {
-                                        parts.push(row_exp[10].clone());
+                                    if row_exp.len() > wirkung {
+                                        parts.push(row_exp[wirkung].clone());
                                     }
                                 }
                                 
@@ -494,20 +555,20 @@ impl Concat {
                                 }
                                 
                                 if let Some(row_i) = relitable.get(i) {
-                                    if row_i.len() > 10 {
-                                        parts.push(row_i[10].clone());
+                                    if row_i.len() > wirkung {
+                                        parts.push(row_i[wirkung].clone());
                                     }
-                                    if row_i.len() > 11 {
+                                    if row_i.len() > wirkung_zusatz {
                                         parts.push(" + ".to_string());
-                                        parts.push(row_i[11].clone());
+                                        parts.push(row_i[wirkung_zusatz].clone());
                                     }
                                 }
                                 
                                 parts.push(", ".to_string());
                                 
                                 if let Some(row_exp) = relitable.get((exponent_minus2 + 2) as usize) {
-                                    if row_exp.len() > 85 {
-                                        parts.push(row_exp[85].clone());
+                                    if row_exp.len() > exponent {
+                                        parts.push(row_exp[exponent].clone());
                                     }
                                 }
                             }
@@ -531,11 +592,11 @@ impl Concat {
                         return Err(ConcatError::DuplicateIndex);
                     }
                     
-                    if let Some(row) = self.tables.data_dict.get(&0) {
-                        if row.len() > 64 {
+                    if let Some(row) = self.tables.data_dict.get(&SpaltenTypKey::Ordinary.schluessel()) {
+                        if row.len() > parameter {
                             self.tables.generated_spalten_parameter.insert(
                                 new_index,
-                                row[64].clone(),
+                                row[parameter].clone(),
                             );
                         }
                     }
@@ -555,10 +616,13 @@ impl Concat {
     ) -> Result<()> {
         self.relitable = Some(relitable.clone());
         
-        let spalten_to_vervielfache: HashSet<usize> = 
-            rows_as_numbers.intersection(&[90, 19].iter().cloned().collect())
-            .cloned()
-            .collect();
+        let mut spalten_to_vervielfache: HashSet<usize> = HashSet::new();
+        for definition in self.spalten.mit_generator("vervielfache_zeile") {
+            let s = self.spalten.nummer(&definition.id)?;
+            if rows_as_numbers.contains(&s) {
+                spalten_to_vervielfache.insert(s);
+            }
+        }
         
         for &s in &spalten_to_vervielfache {
             let mut store: OrderedDict<(usize, usize), String> = OrderedDict::new();
@@ -576,12 +640,12 @@ impl Concat {
             
             let mut multis: OrderedDict<usize, Vec<usize>> = OrderedDict::new();
             
-            for (&(orig_z, _), content) in &store {
+            for (&(orig_z, _), _content) in &store {
                 let mut vielfacher = 1;
                 let mut ergebnis = vielfacher * orig_z;
                 
                 multis.entry(ergebnis)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(orig_z);
                 
                 while ergebnis < relitable.len() {
@@ -589,7 +653,7 @@ impl Concat {
                     ergebnis = vielfacher * orig_z;
                     
                     multis.entry(ergebnis)
-                        .or_insert_with(Vec::new)
+                        .or_default()
                         .push(orig_z);
                 }
             }
@@ -684,7 +748,7 @@ impl Concat {
             let div_rounded = div_int.round() as i32;
             
             result.entry(div_rounded)
-                .or_insert_with(OrderedSet::new)
+                .or_default()
                 .insert(paar);
         }
         
@@ -702,7 +766,7 @@ impl Concat {
         
         for &paar in paare_set {
             let mul = if !gleichf {
-                paar.0 * paar.1
+                (paar.0 * paar.1) as f64
             } else {
                 ((1.0 / (paar.0 as f64 * paar.1 as f64)) * 1000.0).round() / 1000.0
             };
@@ -710,7 +774,7 @@ impl Concat {
             let mul_rounded = mul.round() as i32;
             
             result.entry(mul_rounded)
-                .or_insert_with(OrderedSet::new)
+                .or_default()
                 .insert(paar);
         }
         
@@ -723,8 +787,8 @@ impl Concat {
         fracs: &OrderedSet<Ratio<i32>>,
         fracs2: &OrderedSet<Ratio<i32>>,
         gleichf: bool,
-    ) -> DefaultOrderedDict<i32, OrderedSet<(Ratio<i32>, Ratio<i32>)>> {
-        let mut result: DefaultOrderedDict<i32, OrderedSet<(Ratio<i32>, Ratio<i32>)>> = 
+    ) -> DefaultOrderedDict<i32, BruchPaare> {
+        let mut result: DefaultOrderedDict<i32, BruchPaare> = 
             DefaultOrderedDict::new();
         
         if !gleichf {
@@ -743,7 +807,7 @@ impl Concat {
                     
                     let mul_rounded = mul_float.round() as i32;
                     result.entry(mul_rounded)
-                        .or_insert_with(OrderedSet::new)
+                        .or_default()
                         .insert(paar);
                 }
                 
@@ -762,7 +826,7 @@ impl Concat {
                         
                         let mul_rounded = mul_float.round() as i32;
                         result.entry(mul_rounded)
-                            .or_insert_with(OrderedSet::new)
+                            .or_default()
                             .insert(paar);
                     }
                 }
@@ -787,7 +851,7 @@ impl Concat {
                     
                     let div_rounded = div_float.round() as i32;
                     result.entry(div_rounded)
-                        .or_insert_with(OrderedSet::new)
+                        .or_default()
                         .insert(paar);
                 }
                 
@@ -806,7 +870,7 @@ impl Concat {
                         
                         let mul_rounded = mul_float.round() as i32;
                         result.entry(mul_rounded)
-                            .or_insert_with(OrderedSet::new)
+                            .or_default()
                             .insert(paar);
                     }
                 }
@@ -839,11 +903,11 @@ impl Concat {
 
     /// Read a CSV file and return its contents
     pub fn read_one_csv_and_return(&mut self, wahl: usize) -> Result<&Table> {
-        let place = self.read_concat_csv_chose_csv_file(wahl);
+        let place = self.read_concat_csv_chose_csv_file(wahl)?;
         
         if !self.csvs_already_read.contains_key(&wahl) {
             let file = File::open(&place)
-                .map_err(|e| ConcatError::FileNotFound(place.clone()))?;
+                .map_err(|_e| ConcatError::FileNotFound(place.clone()))?;
             
             let mut reader = csv::ReaderBuilder::new()
                 .delimiter(b';')
@@ -903,26 +967,34 @@ impl Concat {
         menge
     }
 
-    /// Choose CSV file based on type
-    fn read_concat_csv_chose_csv_file(&self, concat_table: usize) -> PathBuf {
-        let filename = match concat_table {
-            1 => "prim.csv",
-            2 | 3 => "bruch13.csv",
-            4 | 5 => "bruch15.csv",
-            6 | 7 => "bruch7.csv",
-            8 | 9 => "bruchStrukGroesse.csv",
-            _ => "unknown.csv",
+    /// The registry entry of the columns a concat table number adds
+    fn concat_tabelle(&self, concat_table: usize) -> Result<&SpaltenDefinition> {
+        let id = match concat_table {
+            1 => "primzahlvielfache",
+            2 => "gebrochen_galaxie_n",
+            3 => "gebrochen_galaxie_1pn",
+            4 => "gebrochen_universum_n",
+            5 => "gebrochen_universum_1pn",
+            6 => "gebrochen_emotion_n",
+            7 => "gebrochen_emotion_1pn",
+            8 => "gebrochen_groesse_n",
+            9 => "gebrochen_groesse_1pn",
+            _ => return Err(ConcatError::UnknownCsvType(concat_table)),
         };
-        
-        let mut path = std::env::current_dir().unwrap_or_default();
-        path.push("csv");
-        path.push(filename);
-        
-        path
+        self.spalten.definition(id)
+    }
+
+    /// Choose CSV file based on type: the `quelle` of the concat table in the registry
+    fn read_concat_csv_chose_csv_file(&self, concat_table: usize) -> Result<PathBuf> {
+        let definition = self.concat_tabelle(concat_table)?;
+        let datei = definition.quelle.as_deref().ok_or_else(|| {
+            ConcatError::Config(format!("column {} has no source file", definition.id))
+        })?;
+        Ok(self.datenquelle.pfad(datei))
     }
 
     /// Find all fractions and their combinations
-    pub fn find_all_brueche_and_their_combinations(&mut self) -> Result<OrderedDict<String, OrderedDict<String, OrderedDict<String, OrderedSet<(Ratio<i32>, Ratio<i32>)>>>> {
+    pub fn find_all_brueche_and_their_combinations(&mut self) -> Result<BruchKombis> {
         self.read_one_csv_and_return(2)?; // galN
         self.read_one_csv_and_return(4)?; // uniN
         
@@ -957,8 +1029,8 @@ impl Concat {
                         
                         // Check multiplication for stern
                         let mul_result = brueche_un * brueche_un2;
-                        if (mul_result.numer() as f64 / mul_result.denom() as f64)
-                            .round() == (mul_result.numer() as f64 / mul_result.denom() as f64)
+                        if (*mul_result.numer() as f64 / *mul_result.denom() as f64)
+                            .round() == (*mul_result.numer() as f64 / *mul_result.denom() as f64)
                         {
                             gebr_rat_all_combis
                                 .get_mut(&key).unwrap()
@@ -969,8 +1041,8 @@ impl Concat {
                         
                         // Check division for stern
                         let div_result = brueche_un / brueche_un2;
-                        if (div_result.numer() as f64 / div_result.denom() as f64)
-                            .round() == (div_result.numer() as f64 / div_result.denom() as f64)
+                        if (*div_result.numer() as f64 / *div_result.denom() as f64)
+                            .round() == (*div_result.numer() as f64 / *div_result.denom() as f64)
                         {
                             gebr_rat_all_combis
                                 .get_mut(&key).unwrap()
@@ -981,8 +1053,8 @@ impl Concat {
                         
                         // Check multiplication for gleichf
                         let mul_inv = Ratio::new(1, 1) / (brueche_un * brueche_un2);
-                        if (mul_inv.numer() as f64 / mul_inv.denom() as f64)
-                            .round() == (mul_inv.numer() as f64 / mul_inv.denom() as f64)
+                        if (*mul_inv.numer() as f64 / *mul_inv.denom() as f64)
+                            .round() == (*mul_inv.numer() as f64 / *mul_inv.denom() as f64)
                         {
                             gebr_rat_all_combis
                                 .get_mut(&key).unwrap()
@@ -993,8 +1065,8 @@ impl Concat {
                         
                         // Check division for gleichf
                         let div_inv = Ratio::new(1, 1) / (brueche_un / brueche_un2);
-                        if (div_inv.numer() as f64 / div_inv.denom() as f64)
-                            .round() == (div_inv.numer() as f64 / div_inv.denom() as f64)
+                        if (*div_inv.numer() as f64 / *div_inv.denom() as f64)
+                            .round() == (*div_inv.numer() as f64 / *div_inv.denom() as f64)
                         {
                             gebr_rat_all_combis
                                 .get_mut(&key).unwrap()
@@ -1023,10 +1095,10 @@ impl Concat {
         let mut concat_csv_spalten = HashSet::new();
         
         if !concat_table_selection.is_empty() && (1..=9).contains(&concat_table) {
-            let table_to_add = self.read_one_csv_and_return(concat_table)?;
+            let table_to_add = self.read_one_csv_and_return(concat_table)?.clone();
             let mut table_to_add = self.read_concat_csv_change_table_to_add_to_table(
                 concat_table,
-                table_to_add,
+                &table_to_add,
             )?;
             
             if concat_table == 1 {
@@ -1034,7 +1106,7 @@ impl Concat {
                     vec![i18n::prim_viel_gen("Primzahlvielfache, nicht generiert")],
                 ];
                 
-                for (t, zeile) in table_to_add.iter().enumerate().skip(1) {
+                for (_t, zeile) in table_to_add.iter().enumerate().skip(1) {
                     let mut zeile_neu = Vec::new();
                     
                     for zelle in zeile {
@@ -1106,7 +1178,7 @@ impl Concat {
                 
                 if i == 0 {
                     for (u, heading) in relitable[i][last_len..].iter().enumerate() {
-                        if ((u + 2) as usize) < concat_table_selection.len() 
+                        if (u + 2) < concat_table_selection.len() 
                             && (2..=9).contains(&concat_table)
                             || (concat_table == 1 && !heading.trim().is_empty())
                         {
@@ -1179,16 +1251,23 @@ impl Concat {
     ) -> Result<Vec<String>> {
         let mut tabelle_dazu_col_neu = Vec::new();
         
-        for (i, cell) in tabelle_dazu_col.iter().enumerate() {
+        for (i, _cell) in tabelle_dazu_col.iter().enumerate() {
             let gebr_rat_zahl = if concat_table == 2 || concat_table == 4 || concat_table == 6 || concat_table == 8 {
                 Ratio::new(zeilen_nr as i32, (i + 1) as i32)
             } else {
                 Ratio::new((i + 1) as i32, zeilen_nr as i32)
             };
             
+            let strukt_and_invers_spalten = match self.strukt_and_invers_spalten {
+                Some(spalten) => spalten,
+                None => (
+                    self.spalten.nummer("strukturalie")?,
+                    self.spalten.nummer("strukturalie_invers")?,
+                ),
+            };
             let cell_neu = self.spalte_meta_konkret_theorie_abstrakt_get_gebr_rat_univ_strukturalie(
                 gebr_rat_zahl,
-                self.strukt_and_invers_spalten.unwrap_or((5, 131)),
+                strukt_and_invers_spalten,
                 self.gebr_univ_table_4meta_konkret.as_ref(),
                 !matches!(concat_table, 2 | 3),
             )?;
@@ -1203,19 +1282,11 @@ impl Concat {
     fn read_concat_csv_set_html_parameters(
         &mut self,
         concat_table: usize,
-        heading: &str,
+        _heading: &str,
         u: usize,
     ) -> Result<()> {
         if (2..=9).contains(&concat_table) {
-            let range_to_data_dict = vec![
-                (2, 6), (3, 6), (4, 5), (5, 5), (6, 9), (7, 9), (8, 10), (9, 10)
-            ];
-            
-            let data_dict_key = range_to_data_dict
-                .iter()
-                .find(|&&(ct, _)| ct == concat_table)
-                .map(|&(_, dk)| dk)
-                .ok_or_else(|| ConcatError::UnknownCsvType(concat_table))?;
+            let data_dict_key = self.concat_tabelle(concat_table)?.typ.schluessel();
             
             if let Some(data_vec) = self.tables.data_dict.get(&data_dict_key) {
                 if u + 2 < data_vec.len() {
@@ -1285,7 +1356,8 @@ impl Concat {
                                 "{} (1/{}); {}",
                                 row[n_and_invers_spalten.1],
                                 denom,
-                                row.get(201).unwrap_or(&String::new())
+                                row.get(self.spalten.nummer("strukturalie_invers_universum")?)
+                                    .unwrap_or(&String::new())
                             )
                         };
                         return Ok(Some(strukname));
@@ -1308,7 +1380,8 @@ impl Concat {
                                 "{} ({}); {}",
                                 row[n_and_invers_spalten.0],
                                 numer,
-                                row.get(198).unwrap_or(&String::new())
+                                row.get(self.spalten.nummer("strukturalie_universum")?)
+                                    .unwrap_or(&String::new())
                             )
                         };
                         return Ok(Some(strukname));
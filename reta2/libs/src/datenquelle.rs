@@ -0,0 +1,278 @@
+//! Datenquelle module - where reta finds its CSV files: data directory resolution and dataset profiles
+//!
+//! The data directory is taken from, in this order: `--daten=`, the directory of the
+//! profile chosen with `--profil=`, `$RETA_DATEN`, the config file, `csv/` next to the
+//! binary, and `csv/` in the working directory.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{daten_dateien, standard_cache_datei, Fnv1a};
+use crate::errors::{ConcatError, Result};
+use crate::spalten::{SpaltenRegistry, SPALTEN_JSON};
+use crate::spaltenwahl::Vorlage;
+use crate::types::OrderedDict;
+
+/// Environment variable naming the data directory
+pub const DATEN_VARIABLE: &str = "RETA_DATEN";
+/// Environment variable naming the config file
+pub const KONFIG_VARIABLE: &str = "RETA_KONFIG";
+
+/// A named dataset: a directory and/or single files swapped in, like an English religion.csv
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profil {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daten: Option<PathBuf>,
+    /// File read instead of a file of the data directory, by the name reta asks for
+    #[serde(default)]
+    pub dateien: OrderedDict<String, PathBuf>,
+}
+
+/// Contents of the config file, `reta.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Konfiguration {
+    /// Data directory when no profile names one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daten: Option<PathBuf>,
+    /// Profile used when none is chosen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profil: Option<String>,
+    #[serde(default)]
+    pub profile: OrderedDict<String, Profil>,
+    /// Saved table layouts, used with `--vorlage=`
+    #[serde(default)]
+    pub vorlagen: OrderedDict<String, Vorlage>,
+}
+
+/// Which step of the resolution order chose the data directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Herkunft {
+    Flag,
+    Profil,
+    Umgebung,
+    Konfiguration,
+    NebenProgramm,
+    Arbeitsverzeichnis,
+}
+
+/// A resolved data directory with the files a profile swaps in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Datenquelle {
+    pub ordner: PathBuf,
+    pub dateien: OrderedDict<String, PathBuf>,
+    pub profil: Option<String>,
+    pub herkunft: Herkunft,
+}
+
+impl Datenquelle {
+    /// Just a directory, as given with `--daten=`
+    pub fn ordner(ordner: &Path) -> Self {
+        Self {
+            ordner: ordner.to_path_buf(),
+            dateien: OrderedDict::new(),
+            profil: None,
+            herkunft: Herkunft::Flag,
+        }
+    }
+
+    /// Resolved without flags; a config file that cannot be read falls back to the working directory
+    pub fn standard() -> Self {
+        Konfiguration::laden_standard()
+            .and_then(|k| k.datenquelle(None, None))
+            .unwrap_or_else(|_| Self {
+                herkunft: Herkunft::Arbeitsverzeichnis,
+                ..Self::ordner(&arbeitsverzeichnis())
+            })
+    }
+
+    /// Path of a data file by the name reta asks for, like "religion.csv"
+    pub fn pfad(&self, name: &str) -> PathBuf {
+        self.dateien
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| self.ordner.join(name))
+    }
+
+    /// The column registry of the data: spalten.json of the directory or profile,
+    /// else the built-in one
+    pub fn spalten_registry(&self) -> Result<SpaltenRegistry> {
+        let pfad = self.pfad(SPALTEN_JSON);
+        if pfad.exists() {
+            SpaltenRegistry::laden(&pfad)
+        } else {
+            Ok(SpaltenRegistry::standard().clone())
+        }
+    }
+
+    /// All CSV files by the name reta asks for: the directory's, with the profile's swapped in
+    pub fn csv_dateien(&self) -> Result<OrderedDict<String, PathBuf>> {
+        let mut dateien: OrderedDict<String, PathBuf> = daten_dateien(&self.ordner)?
+            .into_iter()
+            .filter_map(|pfad| {
+                let name = pfad.file_name()?.to_str()?.to_string();
+                Some((name, pfad))
+            })
+            .collect();
+        for (name, pfad) in &self.dateien {
+            dateien.insert(name.clone(), pfad.clone());
+        }
+        dateien.sort_keys();
+        Ok(dateien)
+    }
+
+    /// Metadata cache file of this source, so that data directories and profiles
+    /// do not overwrite each other's cache: the standard file with a hash of the
+    /// directory, the profile and its files in the name
+    pub fn cache_datei(&self) -> PathBuf {
+        let mut hash = Fnv1a::new();
+        let ordner = fs::canonicalize(&self.ordner).unwrap_or_else(|_| self.ordner.clone());
+        hash.schreiben(ordner.to_string_lossy().as_bytes());
+        hash.schreiben(&[0]);
+        hash.schreiben(self.profil.as_deref().unwrap_or_default().as_bytes());
+        for (name, pfad) in &self.dateien {
+            hash.schreiben(&[0]);
+            hash.schreiben(name.as_bytes());
+            hash.schreiben(&[0]);
+            hash.schreiben(pfad.to_string_lossy().as_bytes());
+        }
+
+        let standard = standard_cache_datei();
+        let stamm = standard
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "reta_metadaten".to_string());
+        standard.with_file_name(format!("{}-{:016x}.json", stamm, hash.wert()))
+    }
+}
+
+impl Konfiguration {
+    /// Reads a config file; relative paths in it are relative to the file
+    pub fn laden(pfad: &Path) -> Result<Self> {
+        let text =
+            fs::read_to_string(pfad).map_err(|_| ConcatError::FileNotFound(pfad.to_path_buf()))?;
+        let mut konfiguration: Self = serde_json::from_str(&text)
+            .map_err(|e| ConcatError::Config(format!("{}: {}", pfad.display(), e)))?;
+        let basis = pfad.parent().unwrap_or(Path::new(""));
+        let absolut = |p: &mut PathBuf| *p = basis.join(&*p);
+        konfiguration.daten.iter_mut().for_each(absolut);
+        for profil in konfiguration.profile.values_mut() {
+            profil.daten.iter_mut().for_each(absolut);
+            profil.dateien.values_mut().for_each(absolut);
+        }
+        Ok(konfiguration)
+    }
+
+    /// `$RETA_KONFIG`, else `reta/reta.json` in `$XDG_CONFIG_HOME` or `~/.config`
+    pub fn standard_datei() -> Option<PathBuf> {
+        if let Some(pfad) = env::var_os(KONFIG_VARIABLE) {
+            return Some(PathBuf::from(pfad));
+        }
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+            .map(|ordner| ordner.join("reta").join("reta.json"))
+    }
+
+    /// The standard config file, or an empty configuration if there is none
+    pub fn laden_standard() -> Result<Self> {
+        match Self::standard_datei() {
+            Some(pfad) if pfad.is_file() => Self::laden(&pfad),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn profil_namen(&self) -> Vec<&str> {
+        self.profile.keys().map(String::as_str).collect()
+    }
+
+    pub fn vorlage(&self, name: &str) -> Result<&Vorlage> {
+        self.vorlagen
+            .get(name)
+            .ok_or_else(|| ConcatError::Parse(format!("unknown view: {}", name)))
+    }
+
+    /// Adds or replaces one layout in a config file, leaving the rest of the file as written
+    pub fn vorlage_speichern(pfad: &Path, name: &str, vorlage: &Vorlage) -> Result<()> {
+        let fehler = |e: serde_json::Error| ConcatError::Config(format!("{}: {}", pfad.display(), e));
+        let mut wert = match fs::read_to_string(pfad) {
+            Ok(text) => serde_json::from_str(&text).map_err(fehler)?,
+            Err(_) => serde_json::json!({}),
+        };
+        let vorlagen = wert
+            .as_object_mut()
+            .ok_or_else(|| ConcatError::Config(format!("{}: not a JSON object", pfad.display())))?
+            .entry("vorlagen")
+            .or_insert_with(|| serde_json::json!({}));
+        let vorlagen = vorlagen.as_object_mut().ok_or_else(|| {
+            ConcatError::Config(format!("{}: vorlagen is not a JSON object", pfad.display()))
+        })?;
+        vorlagen.insert(name.to_string(), serde_json::to_value(vorlage).map_err(fehler)?);
+        if let Some(ordner) = pfad.parent().filter(|o| !o.as_os_str().is_empty()) {
+            fs::create_dir_all(ordner)?;
+        }
+        fs::write(pfad, serde_json::to_string_pretty(&wert).map_err(fehler)?)?;
+        Ok(())
+    }
+
+    /// Resolves the data directory for a query, reading `$RETA_DATEN`
+    pub fn datenquelle(&self, daten: Option<&Path>, profil: Option<&str>) -> Result<Datenquelle> {
+        let umgebung = env::var_os(DATEN_VARIABLE).map(PathBuf::from);
+        self.aufloesen(daten, profil, umgebung.as_deref())
+    }
+
+    /// The resolution order with the value of `$RETA_DATEN` passed in
+    pub fn aufloesen(
+        &self,
+        daten: Option<&Path>,
+        profil: Option<&str>,
+        umgebung: Option<&Path>,
+    ) -> Result<Datenquelle> {
+        let gewaehlt = profil.is_some();
+        let profil_name = profil.map(str::to_string).or_else(|| self.profil.clone());
+        let profil = match &profil_name {
+            Some(name) => Some(
+                self.profile
+                    .get(name)
+                    .ok_or_else(|| ConcatError::Parse(format!("unknown profile: {}", name)))?,
+            ),
+            None => None,
+        };
+        let profil_ordner = profil.and_then(|p| p.daten.clone());
+
+        let (ordner, herkunft) = if let Some(ordner) = daten {
+            (ordner.to_path_buf(), Herkunft::Flag)
+        } else if let (true, Some(ordner)) = (gewaehlt, &profil_ordner) {
+            (ordner.clone(), Herkunft::Profil)
+        } else if let Some(ordner) = umgebung {
+            (ordner.to_path_buf(), Herkunft::Umgebung)
+        } else if let Some(ordner) = profil_ordner.or_else(|| self.daten.clone()) {
+            (ordner, Herkunft::Konfiguration)
+        } else if let Some(ordner) = neben_programm().filter(|o| o.is_dir()) {
+            (ordner, Herkunft::NebenProgramm)
+        } else {
+            (arbeitsverzeichnis(), Herkunft::Arbeitsverzeichnis)
+        };
+
+        Ok(Datenquelle {
+            ordner,
+            dateien: profil.map(|p| p.dateien.clone()).unwrap_or_default(),
+            profil: profil_name,
+            herkunft,
+        })
+    }
+}
+
+/// `csv/` next to the running binary
+fn neben_programm() -> Option<PathBuf> {
+    env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|o| o.join("csv")))
+}
+
+/// `csv/` in the working directory, where reta used to look
+fn arbeitsverzeichnis() -> PathBuf {
+    env::current_dir().unwrap_or_default().join("csv")
+}
@@ -0,0 +1,292 @@
+//! Diagramm module - SVG charts of selected rows, drawn without any external service
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cache::{zaehlungen_bis, MetadatenCache};
+use crate::center::{bereich_to_numbers2, primfaktoren};
+use crate::classifier::klassifikatoren;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{alle_farben, farb_klasse, farben, html_escape};
+use crate::types::OrderedDict;
+
+const BREITE: f64 = 800.0;
+const HOEHE: f64 = 400.0;
+const RAND: f64 = 50.0;
+/// Height of one strip of the heat strip chart
+const STREIFEN_HOEHE: f64 = 40.0;
+const MOND_FARBE: &str = "#46b4c8";
+const SONNE_FARBE: &str = "#f0c020";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagrammArt {
+    /// Bars: how many of the selected numbers fall into each counting
+    Zaehlungen,
+    /// Scatter: n against its number of prime factors, with multiplicity
+    Primfaktoren,
+    /// One strip colored by prim_creativity and one by moon or sun
+    Streifen,
+}
+
+impl DiagrammArt {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "zaehlungen" | "zaehlung" => Some(Self::Zaehlungen),
+            "primfaktoren" => Some(Self::Primfaktoren),
+            "streifen" => Some(Self::Streifen),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagramm {
+    pub zahlen: Vec<i32>,
+    /// Countings computed once, see `MetadatenCache`
+    pub cache: Option<Arc<MetadatenCache>>,
+}
+
+impl Diagramm {
+    /// The rows as a range expression like "1-100,v7"
+    pub fn new(zahlen: &str, max_zahl: i32) -> Result<Self> {
+        let zahlen: Vec<i32> = bereich_to_numbers2(zahlen, false, max_zahl).into_iter().collect();
+        if zahlen.is_empty() {
+            return Err(ConcatError::Parse("no numbers for chart".to_string()));
+        }
+        Ok(Self {
+            zahlen,
+            cache: None,
+        })
+    }
+
+    pub fn mit_cache(mut self, cache: Arc<MetadatenCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Keeps only numbers with one of the `--typ=` labels
+    pub fn typen_filtern(&mut self, typen: &str) -> Result<()> {
+        let registry = klassifikatoren();
+        let typen = registry.typen(typen)?;
+        self.zahlen.retain(|&zahl| registry.passt(zahl, &typen));
+        Ok(())
+    }
+
+    pub fn svg(&self, art: DiagrammArt) -> String {
+        match art {
+            DiagrammArt::Zaehlungen => self.balken(),
+            DiagrammArt::Primfaktoren => self.streuung(),
+            DiagrammArt::Streifen => self.streifen(),
+        }
+    }
+
+    pub fn speichern(&self, art: DiagrammArt, pfad: &Path) -> Result<()> {
+        fs::write(pfad, self.svg(art))?;
+        Ok(())
+    }
+
+    /// Number of selected numbers per counting
+    pub fn pro_zaehlung(&self) -> OrderedDict<i32, usize> {
+        let bis = self.zahlen.iter().copied().max().unwrap_or(0);
+        let zaehlungen = zaehlungen_bis(self.cache.as_deref(), bis);
+        let mut anzahlen = OrderedDict::new();
+        for &zahl in &self.zahlen {
+            if let Some(zaehlung) = zaehlungen.zeile_which_zaehlung(zahl) {
+                *anzahlen.entry(zaehlung).or_insert(0) += 1;
+            }
+        }
+        anzahlen.sort_keys();
+        anzahlen
+    }
+
+    fn balken(&self) -> String {
+        let anzahlen = self.pro_zaehlung();
+        let hoechste = anzahlen.values().copied().max().unwrap_or(0).max(1) as f64;
+        let schritt = (BREITE - 2.0 * RAND) / anzahlen.len().max(1) as f64;
+
+        let mut out = svg_anfang(BREITE, HOEHE, "Zahlen je Zählung");
+        achsen(&mut out, "Zählung", "Anzahl", hoechste);
+        for (i, (zaehlung, anzahl)) in anzahlen.iter().enumerate() {
+            let hoehe = (HOEHE - 2.0 * RAND) * *anzahl as f64 / hoechste;
+            let x = RAND + i as f64 * schritt;
+            let _ = writeln!(
+                out,
+                r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"><title>Zählung {}: {}</title></rect>"##,
+                x + schritt * 0.1,
+                HOEHE - RAND - hoehe,
+                schritt * 0.8,
+                hoehe,
+                MOND_FARBE,
+                zaehlung,
+                anzahl
+            );
+            let _ = writeln!(
+                out,
+                r##"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="10">{}</text>"##,
+                x + schritt / 2.0,
+                HOEHE - RAND + 14.0,
+                zaehlung
+            );
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    fn streuung(&self) -> String {
+        let faktoren: Vec<(i32, usize)> = self
+            .zahlen
+            .iter()
+            .map(|&zahl| (zahl, primfaktoren(zahl).len()))
+            .collect();
+        let (von, bis) = (
+            self.zahlen.iter().copied().min().unwrap_or(0),
+            self.zahlen.iter().copied().max().unwrap_or(0),
+        );
+        let spanne = (bis - von).max(1) as f64;
+        let hoechste = faktoren.iter().map(|&(_, n)| n).max().unwrap_or(0).max(1) as f64;
+
+        let mut out = svg_anfang(BREITE, HOEHE, "Anzahl der Primfaktoren");
+        achsen(&mut out, "n", "Primfaktoren", hoechste);
+        let _ = writeln!(
+            out,
+            r##"<text x="{}" y="{}" font-size="10">{}</text><text x="{}" y="{}" text-anchor="end" font-size="10">{}</text>"##,
+            RAND,
+            HOEHE - RAND + 14.0,
+            von,
+            BREITE - RAND,
+            HOEHE - RAND + 14.0,
+            bis
+        );
+        for (zahl, anzahl) in faktoren {
+            let x = RAND + (BREITE - 2.0 * RAND) * (zahl - von) as f64 / spanne;
+            let y = HOEHE - RAND - (HOEHE - 2.0 * RAND) * anzahl as f64 / hoechste;
+            let farbe = farben(zahl).map_or("#888888", |(bg, _)| bg);
+            let _ = writeln!(
+                out,
+                r##"<circle cx="{:.1}" cy="{:.1}" r="3" fill="{}" stroke="#333333" stroke-width="0.5"><title>{}: {}</title></circle>"##,
+                x, y, farbe, zahl, anzahl
+            );
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    fn streifen(&self) -> String {
+        let farb_klassen = alle_farben();
+        let breite = (BREITE - 2.0 * RAND) / self.zahlen.len().max(1) as f64;
+        let hoehe = 2.0 * STREIFEN_HOEHE + 2.0 * RAND + 20.0 * farb_klassen.len() as f64;
+
+        let mut out = svg_anfang(BREITE, hoehe, "Primkreativität und Mondzahlen");
+        for (reihe, name) in ["Primkreativität", "Mond"].iter().enumerate() {
+            let y = RAND + reihe as f64 * STREIFEN_HOEHE;
+            let _ = writeln!(
+                out,
+                r##"<text x="{}" y="{:.1}" text-anchor="end" font-size="10">{}</text>"##,
+                RAND - 4.0,
+                y + STREIFEN_HOEHE / 2.0,
+                name
+            );
+            for (i, &zahl) in self.zahlen.iter().enumerate() {
+                let (farbe, label) = if reihe == 0 {
+                    (
+                        farben(zahl).map_or("#888888", |(bg, _)| bg),
+                        farb_klasse(zahl).unwrap_or("-"),
+                    )
+                } else if klassifikatoren().hat(zahl, "mond") {
+                    (MOND_FARBE, "mond")
+                } else {
+                    (SONNE_FARBE, "sonne")
+                };
+                let _ = writeln!(
+                    out,
+                    r##"<rect x="{:.2}" y="{:.1}" width="{:.2}" height="{}" fill="{}"><title>{}: {}</title></rect>"##,
+                    RAND + i as f64 * breite,
+                    y,
+                    breite,
+                    STREIFEN_HOEHE,
+                    farbe,
+                    zahl,
+                    label
+                );
+            }
+        }
+
+        // Legend
+        let legende = farb_klassen
+            .iter()
+            .map(|&(klasse, bg, _)| (klasse, bg))
+            .chain([("mond", MOND_FARBE), ("sonne", SONNE_FARBE)]);
+        for (i, (klasse, farbe)) in legende.enumerate() {
+            let x = RAND + (i % 3) as f64 * 200.0;
+            let y = RAND + 2.0 * STREIFEN_HOEHE + 20.0 + (i / 3) as f64 * 20.0;
+            let _ = writeln!(
+                out,
+                r##"<rect x="{}" y="{:.1}" width="12" height="12" fill="{}"/><text x="{}" y="{:.1}" font-size="10">{}</text>"##,
+                x,
+                y,
+                farbe,
+                x + 16.0,
+                y + 10.0,
+                klasse
+            );
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn svg_anfang(breite: f64, hoehe: f64, titel: &str) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="sans-serif">"##,
+        breite, hoehe, breite, hoehe
+    );
+    let _ = writeln!(out, "<title>{}</title>", html_escape(titel));
+    let _ = writeln!(out, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+    let _ = writeln!(
+        out,
+        r##"<text x="{}" y="{}" text-anchor="middle" font-size="14">{}</text>"##,
+        breite / 2.0,
+        RAND / 2.0,
+        html_escape(titel)
+    );
+    out
+}
+
+/// Axes with labels and the highest value on the y axis
+fn achsen(out: &mut String, x_name: &str, y_name: &str, hoechste: f64) {
+    let _ = writeln!(
+        out,
+        r##"<path d="M{rand} {oben} V{unten} H{rechts}" fill="none" stroke="#333333"/>"##,
+        rand = RAND,
+        oben = RAND,
+        unten = HOEHE - RAND,
+        rechts = BREITE - RAND
+    );
+    let _ = writeln!(
+        out,
+        r##"<text x="{}" y="{}" text-anchor="end" font-size="10">{}</text>"##,
+        RAND - 4.0,
+        RAND + 4.0,
+        hoechste
+    );
+    let _ = writeln!(
+        out,
+        r##"<text x="{}" y="{}" text-anchor="middle" font-size="12">{}</text>"##,
+        BREITE / 2.0,
+        HOEHE - RAND / 4.0,
+        html_escape(x_name)
+    );
+    let _ = writeln!(
+        out,
+        r##"<text x="{}" y="{}" text-anchor="middle" font-size="12" transform="rotate(-90 {} {})">{}</text>"##,
+        RAND / 3.0,
+        HOEHE / 2.0,
+        RAND / 3.0,
+        HOEHE / 2.0,
+        html_escape(y_name)
+    );
+}
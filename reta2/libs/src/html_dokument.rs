@@ -0,0 +1,311 @@
+//! HTML document module - self-contained HTML pages with a theme stylesheet and column filters
+
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+use crate::lib4tables::{
+    alle_farben, farb_klasse, html_escape, html_zelle, markiert, parameter_klassen, Zelle,
+    STANDARD_FARBEN,
+};
+use crate::query::TableData;
+use crate::strukturiert::StrukturierteTabelle;
+use crate::syntax::{Gruppe, OutputSyntax, ZellenKontext};
+
+/// Colors of a page, the HTML counterpart of ColorConfig
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtmlThema {
+    pub hintergrund: &'static str,
+    pub text: &'static str,
+    pub kopf_hintergrund: &'static str,
+    pub kopf_text: &'static str,
+    pub rahmen: &'static str,
+    /// Color class, background and foreground, as in STANDARD_FARBEN
+    pub zeilen: [(&'static str, &'static str, &'static str); 7],
+}
+
+const HELL: HtmlThema = HtmlThema {
+    hintergrund: "#ffffff",
+    text: "#000000",
+    kopf_hintergrund: "#dddddd",
+    kopf_text: "#000000",
+    rahmen: "#999999",
+    zeilen: STANDARD_FARBEN,
+};
+
+const DUNKEL: HtmlThema = HtmlThema {
+    hintergrund: "#1e1e1e",
+    text: "#dddddd",
+    kopf_hintergrund: "#333333",
+    kopf_text: "#ffffff",
+    rahmen: "#555555",
+    zeilen: [
+        ("null", "#661111", "#ffdddd"),
+        ("primzahl-gerade", "#1f5f1f", "#e0ffe0"),
+        ("primzahl-ungerade", "#0f3f0f", "#c0ffc0"),
+        ("vielfaches-gerade", "#5f5f1f", "#ffffd0"),
+        ("vielfaches-ungerade", "#3f3f0f", "#e0e0ff"),
+        ("mondzahl-gerade", "#2f2f6f", "#e0e0ff"),
+        ("mondzahl-ungerade", "#1f1f4f", "#ffffb0"),
+    ],
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Thema {
+    #[default]
+    Hell,
+    Dunkel,
+}
+
+impl Thema {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "hell" => Some(Self::Hell),
+            "dunkel" => Some(Self::Dunkel),
+            _ => None,
+        }
+    }
+
+    pub fn farben(&self) -> &'static HtmlThema {
+        match self {
+            Self::Hell => &HELL,
+            Self::Dunkel => &DUNKEL,
+        }
+    }
+}
+
+impl HtmlThema {
+    /// Stylesheet with sticky headers and one rule per color class
+    pub fn css(&self) -> String {
+        let mut css = String::new();
+        let _ = writeln!(
+            css,
+            "body {{ background-color:{}; color:{}; font-family:sans-serif; }}",
+            self.hintergrund, self.text
+        );
+        let _ = writeln!(css, "#bigtable {{ border-collapse:collapse; }}");
+        let _ = writeln!(
+            css,
+            "#bigtable th, #bigtable td {{ border:1px solid {}; padding:0.2em 0.4em; vertical-align:top; }}",
+            self.rahmen
+        );
+        let _ = writeln!(
+            css,
+            "#bigtable thead th {{ position:sticky; top:0; background-color:{}; color:{}; }}",
+            self.kopf_hintergrund, self.kopf_text
+        );
+        let _ = writeln!(css, ".reta-aus {{ display:none; }}");
+        let _ = writeln!(css, "#reta-filter label {{ margin-right:1em; }}");
+        // Color classes of registered classifiers the theme has no colors for keep their own
+        let eigene = alle_farben()
+            .into_iter()
+            .filter(|(klasse, _, _)| !self.zeilen.iter().any(|(k, _, _)| k == klasse));
+        for (klasse, bg, fg) in self.zeilen.iter().copied().chain(eigene) {
+            let _ = writeln!(
+                css,
+                "#bigtable .{} {{ background-color:{}; color:{}; }}",
+                klasse, bg, fg
+            );
+        }
+        css
+    }
+}
+
+/// Toggles all columns sharing a p1_ (parameter), p2_ (name) or p4_ (ST tag) header class
+const SKRIPT: &str = r#"(function () {
+  var tabelle = document.getElementById("bigtable");
+  var filter = document.getElementById("reta-filter");
+  var gruppen = {};
+  tabelle.querySelectorAll("thead th").forEach(function (th) {
+    th.classList.forEach(function (klasse) {
+      if (/^p[124]_./.test(klasse)) {
+        (gruppen[klasse] = gruppen[klasse] || []).push(th.dataset.spalte);
+      }
+    });
+  });
+  Object.keys(gruppen).sort().forEach(function (klasse) {
+    var label = document.createElement("label");
+    var box = document.createElement("input");
+    box.type = "checkbox";
+    box.checked = true;
+    box.addEventListener("change", function () {
+      gruppen[klasse].forEach(function (spalte) {
+        tabelle.querySelectorAll(".r_" + spalte).forEach(function (zelle) {
+          zelle.classList.toggle("reta-aus", !box.checked);
+        });
+      });
+    });
+    label.appendChild(box);
+    label.appendChild(document.createTextNode(" " + klasse.slice(3).replace(/_/g, " ")));
+    filter.appendChild(label);
+  });
+})();
+"#;
+
+/// A whole page: stylesheet from the theme, `<thead>` with sticky headers carrying the
+/// z_/r_/p1_/p2_/p4_ classes of HtmlSyntax, and the filter script.
+/// `farben[i][k]` is the number coloring cell k of row i, if any; `sprache` is the page's `lang`.
+pub fn html_dokument(
+    tabelle: &StrukturierteTabelle,
+    farben: &[Vec<Option<i32>>],
+    thema: Thema,
+    titel: &str,
+    sprache: &str,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "<!DOCTYPE html>");
+    let _ = writeln!(out, "<html lang=\"{}\">", html_escape(sprache));
+    let _ = writeln!(out, "<head>");
+    let _ = writeln!(out, "<meta charset=\"utf-8\">");
+    let _ = writeln!(out, "<title>{}</title>", html_escape(titel));
+    let _ = writeln!(out, "<style>\n{}</style>", thema.farben().css());
+    let _ = writeln!(out, "</head>");
+    let _ = writeln!(out, "<body>");
+    let _ = writeln!(out, "<div id=\"reta-filter\"></div>");
+    let _ = writeln!(out, "<table id=\"bigtable\">");
+
+    out.push_str("<thead><tr>");
+    for spalte in &tabelle.spalten {
+        let mut klassen = vec![format!("z_0 r_{}", spalte.index)];
+        let (haupt, unter) = match &spalte.parameter {
+            Some((haupt, unter)) => (Some(haupt.as_str()), unter),
+            None => (None, spalte.original.as_ref().unwrap_or(&spalte.name)),
+        };
+        klassen.extend(parameter_klassen(haupt, unter, &spalte.tags));
+        let _ = write!(
+            out,
+            "<th class=\"{}\" data-spalte=\"{}\">{}</th>",
+            klassen.join(" "),
+            spalte.index,
+            html_escape(&spalte.name)
+        );
+    }
+    out.push_str("</tr></thead>\n<tbody>\n");
+
+    let mut gruppe = None;
+    for (i, zeile) in tabelle.zeilen.iter().enumerate() {
+        if zeile.gruppe.is_some() && zeile.gruppe != gruppe {
+            gruppe = zeile.gruppe.clone();
+            let _ = writeln!(
+                out,
+                "<tr class=\"gruppe\"><th colspan=\"{}\">{}</th></tr>",
+                tabelle.spalten.len(),
+                html_escape(gruppe.as_deref().unwrap_or_default())
+            );
+        }
+        let zeilen_klasse = farb_klasse(zeile.zeile)
+            .map(|k| format!(" class=\"{}\"", k))
+            .unwrap_or_default();
+        let zaehlung = zeile
+            .zaehlung
+            .map(|z| format!(" data-zaehlung=\"{}\"", z))
+            .unwrap_or_default();
+        let _ = write!(out, "<tr{} data-zeile=\"{}\"{}>", zeilen_klasse, zeile.zeile, zaehlung);
+        for (k, zelle) in zeile.zellen.iter().enumerate() {
+            let farbe = farben
+                .get(i)
+                .and_then(|f| f.get(k).copied().flatten())
+                .and_then(farb_klasse)
+                .map(|f| format!(" {}", f))
+                .unwrap_or_default();
+            // The browser wraps, so cells keep only the breaks of the unwrapped text,
+            // which the match ranges refer to
+            let treffer: Vec<_> = zelle.treffer.iter().map(|&(von, bis)| von..bis).collect();
+            let text = markiert(&zelle.text, &treffer, html_zelle, |t| format!("<mark>{}</mark>", t))
+                .replace("&lt;br&gt;", "<br>")
+                .replace('\n', "<br>");
+            let _ = write!(out, "<td class=\"z_{} r_{}{}\">{}</td>", zeile.zeile, k, farbe, text);
+        }
+        out.push_str("</tr>\n");
+    }
+
+    let _ = writeln!(out, "</tbody>\n</table>");
+    let _ = writeln!(out, "<script>\n{}</script>", SKRIPT);
+    let _ = writeln!(out, "</body>\n</html>");
+    out
+}
+
+/// Document for plain cells, as used by HtmlDokumentSyntax
+pub fn html_dokument_aus_zellen(
+    kopf: &[Zelle],
+    zeilen: &[(i32, Vec<Zelle>)],
+    thema: Thema,
+    titel: &str,
+) -> String {
+    let tabelle = StrukturierteTabelle::gezaehlt(kopf, zeilen, None);
+    html_dokument(&tabelle, &zellen_farben(zeilen), thema, titel, "de")
+}
+
+/// Document for plain cells with group headings
+pub fn html_dokument_gruppiert(
+    kopf: &[Zelle],
+    zeilen: &[(i32, Vec<Zelle>)],
+    gruppen: &[Gruppe],
+    thema: Thema,
+    titel: &str,
+) -> String {
+    let tabelle = StrukturierteTabelle::gezaehlt(kopf, zeilen, None).mit_gruppen(gruppen);
+    html_dokument(&tabelle, &zellen_farben(zeilen), thema, titel, "de")
+}
+
+/// The number colors of each cell
+fn zellen_farben(zeilen: &[(i32, Vec<Zelle>)]) -> Vec<Vec<Option<i32>>> {
+    zeilen
+        .iter()
+        .map(|(_, zellen)| zellen.iter().map(|z| z.farbe).collect())
+        .collect()
+}
+
+/// A standalone HTML page with stylesheet and column filters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlDokumentSyntax {
+    pub thema: Thema,
+}
+
+impl HtmlDokumentSyntax {
+    pub fn new(thema: Thema) -> Self {
+        Self { thema }
+    }
+}
+
+impl OutputSyntax for HtmlDokumentSyntax {
+    fn name(&self) -> &'static str {
+        "html-dokument"
+    }
+
+    fn kann_farben(&self) -> bool {
+        true
+    }
+
+    fn format(&self, text: &str) -> String {
+        format!("<strong>{}</strong>", text)
+    }
+
+    fn treffer(&self, text: &str) -> String {
+        format!("<mark>{}</mark>", text)
+    }
+
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        markiert(&zelle.zelle.text, &zelle.zelle.treffer, html_zelle, |t| self.treffer(t))
+    }
+
+    fn format_table(&self, kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> Result<String> {
+        Ok(html_dokument_aus_zellen(kopf, zeilen, self.thema, "reta"))
+    }
+
+    fn format_gruppiert(
+        &self,
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        gruppen: &[Gruppe],
+    ) -> Result<String> {
+        Ok(html_dokument_gruppiert(kopf, zeilen, gruppen, self.thema, "reta"))
+    }
+
+    fn format_daten(&self, tabelle: &TableData) -> Result<String> {
+        let farben = zellen_farben(&tabelle.zeilen);
+        let sprache = tabelle.sprache.as_deref().unwrap_or("de");
+        Ok(html_dokument(&tabelle.strukturiert(), &farben, self.thema, "reta", sprache))
+    }
+}
@@ -1,11 +1,33 @@
 //! lib4tables_concat - Rust implementation of Python lib4tables_concat module
 
+pub mod breite;
+pub mod cache;
 pub mod center;
+pub mod classifier;
 pub mod lib4tables;
 pub mod lib4tables_enum;
 pub mod concat;
+pub mod datenquelle;
+pub mod diagramm;
 pub mod errors;
+#[cfg(feature = "html_output")]
+pub mod html_dokument;
+pub mod modulo;
+pub mod pager;
+pub mod pivot;
+pub mod potenz;
+pub mod pruefung;
+pub mod query;
+pub mod sortierung;
+pub mod spalten;
+pub mod spaltenwahl;
+pub mod strukturiert;
+pub mod suche;
+pub mod syntax;
+pub mod tabellenkalkulation;
 pub mod types;
+pub mod zahl;
+pub mod zeilen;
 
 pub use concat::Concat;
 pub use errors::ConcatError;
@@ -13,18 +35,54 @@ pub use types::*;
 
 // Re-exports
 pub use lib4tables::{
-    OutputSyntax, BbCodeSyntax, CsvSyntax, EmacsSyntax, HtmlSyntax, MarkdownSyntax,
+    Zelle, AsciiDocSyntax, EmacsSyntax, LatexSyntax, NichtsSyntax, ShellSyntax, TypstSyntax,
+    alle_farben, farb_klasse, farben, shell_farben,
     could_be_prime_number_primzahlkreuz,
     could_be_prime_number_primzahlkreuz_fuer_aussen,
     could_be_prime_number_primzahlkreuz_fuer_innen,
-    divisor_generator, is_prim_multiple, moon_number, prim_creativity,
-    prim_fak, prim_multiple, prim_repeat,
+    divisor_generator, get_logarithm_only_as_pure_int, html_escape, html_zelle, is_prim_multiple,
+    asciidoc_zelle, klassen_name, latex_escape, parameter_klassen, latex_zelle, moon_number, org_tabelle, prim_creativity, prim_fak,
+    prim_multiple, prim_repeat, typst_escape, typst_zelle,
 };
 
 pub use center::{
     DefaultOrderedDict, Multiplikationen, PrimzahlkreuzProContraStrs, alxp, cliout,
-    get_text_wrap_things, i18n, info_log, multiples, output, primfaktoren,
-    unique_everseen, n_pm_enum,
+    bereich_to_numbers2, BEREICH_HOECHSTENS, csv_lesen, GEBROCHEN_SPALTEN_HOECHSTENS, get_text_wrap_things, i18n, info_log, multiples, output,
+    primfaktoren,
+    unique_everseen, n_pm_enum, NPmEnum,
 };
 
-pub use lib4tables_enum::ST;
+pub use breite::{abschneiden, anzeige_breite, auffuellen, umbrechen};
+pub use cache::{MetadatenCache, ZahlMetadaten};
+pub use classifier::{classifier_registrieren, klassifikatoren, Classifier, ClassifierRegistry};
+pub use datenquelle::{Datenquelle, Herkunft, Konfiguration, Profil};
+pub use diagramm::{Diagramm, DiagrammArt};
+#[cfg(feature = "html_output")]
+pub use html_dokument::{HtmlDokumentSyntax, HtmlThema, Thema};
+#[cfg(feature = "bbcode_output")]
+pub use lib4tables::BbCodeSyntax;
+#[cfg(feature = "csv_output")]
+pub use lib4tables::CsvSyntax;
+#[cfg(feature = "html_output")]
+pub use lib4tables::HtmlSyntax;
+#[cfg(feature = "markdown_output")]
+pub use lib4tables::MarkdownSyntax;
+pub use lib4tables_enum::{SpaltenTypKey, ST};
+pub use modulo::ModuloTabelle;
+pub use pager::{Ansicht, Pager, Taste};
+pub use pivot::{Anordnung, Gruppierung, Pivot, Zusammenfassung};
+pub use potenz::{Potenz, PotenzTabelle};
+pub use pruefung::{Befund, ErsteSpalte, Schema};
+pub use query::{Daten, Kombi, Query, RangeExpr, Spalte, SpaltenInfo, SpaltenQuelle, TableData};
+pub use sortierung::{Schluessel, Sortierung, Wert};
+pub use spalten::{SpaltenDefinition, SpaltenRegistry};
+pub use spaltenwahl::{Auswahl, Beitrag, Erklaerung, SpaltenAuswahl, Term, Vorlage};
+pub use strukturiert::{JsonLinesSyntax, JsonSyntax, StrukturierteTabelle};
+pub use suche::Suche;
+pub use syntax::{
+    syntax_fuer_art, syntax_namen, syntax_registrieren, Gruppe, OutputSyntax, SyntaxRegistry,
+    TabellenKontext, ZeilenKontext, ZellenKontext,
+};
+pub use tabellenkalkulation::{Arbeitsblatt, Tabellenkalkulation};
+pub use zahl::{ProfilFormat, Zaehlungen, ZahlProfil};
+pub use zeilen::{zeilen_filtern, Gestirn, Primzahlen, Zeilenbedingung, Zeit};
@@ -1,46 +1,1095 @@
 //! lib4tables module - equivalent to Python lib4tables module
 
-use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum OutputSyntax {
-    Html,
-    BbCode,
-    Csv,
-    Markdown,
-    Emacs,
+use crate::breite::{anzeige_breite, auffuellen};
+use crate::cache::Fnv1a;
+use crate::classifier::klassifikatoren;
+use crate::errors::Result;
+use crate::lib4tables_enum::ST;
+use crate::syntax::{
+    rendern, Gruppe, OutputSyntax, TabellenKontext, ZeilenKontext, ZellenKontext,
+};
+
+/// Color class, background and foreground of the HTML palette, see farb_klasse
+pub const STANDARD_FARBEN: [(&str, &str, &str); 7] = [
+    ("null", "#ff2222", "#002222"),
+    ("primzahl-gerade", "#66ff66", "#000000"),
+    ("primzahl-ungerade", "#009900", "#ffffff"),
+    ("vielfaches-gerade", "#ffff66", "#000099"),
+    ("vielfaches-ungerade", "#555500", "#aaaaff"),
+    ("mondzahl-gerade", "#9999ff", "#202000"),
+    ("mondzahl-ungerade", "#000099", "#ffff66"),
+];
+
+/// Tables with more rows than this become a `longtable` that can break across pages
+pub const LATEX_LONGTABLE_AB: usize = 40;
+
+/// One table cell; `farbe` is the number whose type colors the cell
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Zelle {
+    pub text: String,
+    pub farbe: Option<i32>,
+    /// Header cells: the parameter the column was chosen by, kept when the header is renamed
+    pub parameter: Option<String>,
+    /// Header cells: ST tags of the column
+    pub tags: Vec<ST>,
+    /// Header cells: the header as in the data when it was renamed
+    pub original: Option<String>,
+    /// Byte ranges of search matches to highlight, see `--hervorheben`
+    pub treffer: Vec<Range<usize>>,
+}
+
+impl Zelle {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), ..Self::default() }
+    }
+    
+    pub fn farbig(text: impl Into<String>, num: i32) -> Self {
+        Self { text: text.into(), farbe: Some(num), ..Self::default() }
+    }
+
+    /// A header cell with the parameter and tags its HTML classes are made of
+    pub fn mit_parameter(mut self, parameter: impl Into<String>, tags: Vec<ST>) -> Self {
+        self.parameter = Some(parameter.into());
+        self.tags = tags;
+        self
+    }
+
+    /// Shows a header under another text; the classes keep the original
+    pub fn umbenannt(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        if text != self.text {
+            let original = std::mem::replace(&mut self.text, text);
+            self.original.get_or_insert(original);
+        }
+        self
+    }
+}
+
+/// Color class of a number: "null" for 0, else as the classifiers of the shared registry color it
+pub fn farb_klasse(num: i32) -> Option<&'static str> {
+    if num == 0 {
+        return Some("null");
+    }
+    klassifikatoren().farb_klasse(num)
+}
+
+/// Every color class with background and foreground: "null" and those of the classifiers
+pub fn alle_farben() -> Vec<(&'static str, &'static str, &'static str)> {
+    let mut farben = vec![STANDARD_FARBEN[0]];
+    farben.extend(klassifikatoren().farben());
+    farben
+}
+
+/// Background and foreground color by number class, as in HtmlSyntax.colored_begin_col
+pub fn farben(num: i32) -> Option<(&'static str, &'static str)> {
+    let klasse = farb_klasse(num)?;
+    alle_farben()
+        .into_iter()
+        .find(|(name, _, _)| *name == klasse)
+        .map(|(_, bg, fg)| (bg, fg))
 }
 
+/// Terminal colors by number class, as in Output.colorize; numbers no classifier colors
+/// are gray, lighter when even
+pub fn shell_farben(num: i32) -> &'static str {
+    if num == 0 {
+        return "\x1b[41m\x1b[30m\x1b[4m";
+    }
+    klassifikatoren()
+        .shell_farben(num)
+        .unwrap_or(if num % 2 == 0 { "\x1b[47m\x1b[30m" } else { "\x1b[100m\x1b[37m" })
+}
+
+/// Padded columns with ANSI colors, the default of `--art=`
+pub struct ShellSyntax;
+#[cfg(feature = "html_output")]
+pub struct HtmlSyntax;
+#[cfg(feature = "bbcode_output")]
 pub struct BbCodeSyntax;
+#[cfg(feature = "markdown_output")]
+pub struct MarkdownSyntax;
+#[cfg(feature = "csv_output")]
 pub struct CsvSyntax;
+/// Org-mode tables, see org_tabelle
 pub struct EmacsSyntax;
-pub struct HtmlSyntax;
-pub struct MarkdownSyntax;
+/// booktabs `tabular`/`longtable`; needs booktabs, longtable and xcolor with the table option
+pub struct LatexSyntax;
+/// Typst `#table` with the rows filled by number type
+pub struct TypstSyntax;
+/// AsciiDoc `|===` tables; list cells become `a|` cells
+pub struct AsciiDocSyntax;
+/// Renders nothing, for timing the table generation alone
+pub struct NichtsSyntax;
 
-impl BbCodeSyntax {
-    pub fn format(&self, text: &str) -> String {
+impl OutputSyntax for ShellSyntax {
+    fn name(&self) -> &'static str {
+        "shell"
+    }
+    
+    fn aliase(&self) -> &'static [&'static str] {
+        &[""]
+    }
+    
+    fn kann_farben(&self) -> bool {
+        true
+    }
+    
+    fn zellen_trenner(&self) -> &'static str {
+        " "
+    }
+    
+    /// Reverse video, ended without resetting the color of the cell
+    fn treffer(&self, text: &str) -> String {
+        format!("\x1b[7m{}\x1b[27m", text)
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        // Padded before marking, by the width of the plain text; the matches stay in front
+        let text = auffuellen(&zelle.zelle.text, zelle.breite);
+        let text = markiert(&text, &zelle.zelle.treffer, str::to_string, |t| self.treffer(t));
+        match zelle.zelle.farbe {
+            Some(num) if !zelle.kopf => format!("{}{}\x1b[0m", shell_farben(num), text),
+            _ => text,
+        }
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!("\x1b[1m{}\x1b[0m\n", gruppe.titel));
+    }
+}
+
+#[cfg(feature = "html_output")]
+impl OutputSyntax for HtmlSyntax {
+    fn name(&self) -> &'static str {
+        "html"
+    }
+    
+    fn kann_farben(&self) -> bool {
+        true
+    }
+    
+    fn format(&self, text: &str) -> String {
+        format!("<strong>{}</strong>", text)
+    }
+    
+    fn begin_tabelle(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("<table border=0 id=\"bigtable\">\n");
+    }
+    
+    fn end_tabelle(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("</table>\n");
+    }
+    
+    fn begin_zeile(&self, zeile: &ZeilenKontext, out: &mut String) {
+        match farben(zeile.zeile).filter(|_| !zeile.kopf) {
+            Some((bg, fg)) => {
+                out.push_str(&format!("<tr style=\"background-color:{};color:{};\">", bg, fg));
+            }
+            None => out.push_str("<tr>"),
+        }
+    }
+    
+    fn end_zeile(&self, _zeile: &ZeilenKontext, out: &mut String) {
+        out.push_str("</tr>\n");
+    }
+    
+    fn treffer(&self, text: &str) -> String {
+        format!("<mark>{}</mark>", text)
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        let tag = if zelle.kopf { "th" } else { "td" };
+        let text = markiert(&zelle.zelle.text, &zelle.zelle.treffer, html_zelle, |t| self.treffer(t));
+        if let (true, Some(parameter)) = (zelle.kopf, &zelle.zelle.parameter) {
+            let name = zelle.zelle.original.as_ref().unwrap_or(&zelle.zelle.text);
+            let klassen = parameter_klassen(Some(parameter), name, &zelle.zelle.tags);
+            return format!("<th class=\"{}\">{}</th>", klassen.join(" "), text);
+        }
+        match zelle.zelle.farbe.and_then(farben) {
+            Some((bg, fg)) => format!(
+                "<{} style=\"background-color:{};color:{};\">{}</{}>",
+                tag, bg, fg, text, tag
+            ),
+            None => format!("<{}>{}</{}>", tag, text, tag),
+        }
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!(
+            "<tr class=\"gruppe\"><th colspan=\"{}\">{}</th></tr>\n",
+            tabelle.kopf.len(),
+            html_escape(&gruppe.titel)
+        ));
+    }
+}
+
+#[cfg(feature = "bbcode_output")]
+impl OutputSyntax for BbCodeSyntax {
+    fn name(&self) -> &'static str {
+        "bbcode"
+    }
+    
+    fn kann_farben(&self) -> bool {
+        true
+    }
+    
+    fn format(&self, text: &str) -> String {
         format!("[b]{}[/b]", text)
     }
+    
+    fn begin_tabelle(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("[table]\n");
+    }
+    
+    fn end_tabelle(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("[/table]\n");
+    }
+    
+    fn begin_zeile(&self, zeile: &ZeilenKontext, out: &mut String) {
+        match farben(zeile.zeile).filter(|_| !zeile.kopf) {
+            Some((bg, fg)) => {
+                out.push_str(&format!("[tr=\"background-color:{};color:{};\"]", bg, fg));
+            }
+            None => out.push_str("[tr]"),
+        }
+    }
+    
+    fn end_zeile(&self, _zeile: &ZeilenKontext, out: &mut String) {
+        out.push_str("[/tr]\n");
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        let text = markiert(&zelle.zelle.text, &zelle.zelle.treffer, str::to_string, |t| self.format(t));
+        match zelle.zelle.farbe.and_then(farben) {
+            Some((bg, fg)) => format!(
+                "[td=\"background-color:{};color:{}\"]{}[/td]",
+                bg, fg, text
+            ),
+            None => format!("[td]{}[/td]", text),
+        }
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!("[tr][td]{}[/td][/tr]\n", self.format(&gruppe.titel)));
+    }
 }
 
-impl CsvSyntax {
-    pub fn format(&self, text: &str) -> String {
+#[cfg(feature = "markdown_output")]
+impl OutputSyntax for MarkdownSyntax {
+    fn name(&self) -> &'static str {
+        "markdown"
+    }
+    
+    fn format(&self, text: &str) -> String {
+        format!("**{}**", text)
+    }
+    
+    fn end_kopf(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!("|{}\n", ":--|".repeat(tabelle.kopf.len())));
+    }
+    
+    fn begin_zeile(&self, _zeile: &ZeilenKontext, out: &mut String) {
+        out.push('|');
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        let escape = |t: &str| t.replace('|', "\\|");
+        format!(" {} |", markiert(&zelle.zelle.text, &zelle.zelle.treffer, escape, |t| self.format(t)))
+    }
+    
+    /// Markdown cannot span columns: the heading stands in the first cell of its own row
+    fn gruppen_kopf(&self, gruppe: &Gruppe, tabelle: &TabellenKontext, out: &mut String) {
+        let titel = self.format(&gruppe.titel.replace('|', "\\|"));
+        out.push_str(&format!("| {} |{}\n", titel, " |".repeat(tabelle.kopf.len().saturating_sub(1))));
+    }
+}
+
+#[cfg(feature = "csv_output")]
+impl OutputSyntax for CsvSyntax {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+    
+    fn format(&self, text: &str) -> String {
         text.replace(";", ",")
     }
+    
+    fn zellen_trenner(&self) -> &'static str {
+        ";"
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        let text = &zelle.zelle.text;
+        if text.contains(';') || text.contains('"') || text.contains('\n') {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text.clone()
+        }
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, tabelle: &TabellenKontext, out: &mut String) {
+        let titel = Zelle::new(gruppe.titel.as_str());
+        out.push_str(&self.zelle(&ZellenKontext { zelle: &titel, zeile: 0, spalte: 0, breite: 0, kopf: false }));
+        out.push_str(&";".repeat(tabelle.kopf.len().saturating_sub(1)));
+        out.push('\n');
+    }
 }
 
-impl HtmlSyntax {
-    pub fn format(&self, text: &str) -> String {
-        format!("<strong>{}</strong>", text)
+impl OutputSyntax for EmacsSyntax {
+    fn name(&self) -> &'static str {
+        "emacs"
+    }
+    
+    fn format(&self, text: &str) -> String {
+        format!("*{}*", text)
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        if zelle.zelle.treffer.is_empty() || ist_liste(&zelle.zelle.text) {
+            return markiert(&zelle.zelle.text, &zelle.zelle.treffer, org_zelle, |t| self.format(t));
+        }
+        markiert(&zelle.zelle.text, &zelle.zelle.treffer, org_escape, |t| self.format(t))
+    }
+    
+    /// Org tables need all rows for alignment and width cookies, so they are built at once
+    fn format_table(&self, kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> Result<String> {
+        let tabelle = TabellenKontext::new(kopf, zeilen);
+        Ok(org_tabelle(kopf, zeilen, &tabelle.breiten, None))
+    }
+    
+    /// The heading of a group becomes a row of its own, emphasized in the first cell
+    fn format_gruppiert(&self, kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)], gruppen: &[Gruppe]) -> Result<String> {
+        let mut mit_koepfen = Vec::with_capacity(zeilen.len() + gruppen.len());
+        for (position, zeile) in zeilen.iter().enumerate() {
+            for gruppe in gruppen.iter().filter(|g| g.ab == position) {
+                let mut zellen = vec![Zelle::new(self.format(&gruppe.titel))];
+                zellen.resize(kopf.len().max(1), Zelle::default());
+                // Negative numbers have no color class
+                mit_koepfen.push((-1, zellen));
+            }
+            mit_koepfen.push(zeile.clone());
+        }
+        self.format_table(kopf, &mit_koepfen)
     }
 }
 
-impl MarkdownSyntax {
-    pub fn format(&self, text: &str) -> String {
-        format!("**{}**", text)
+impl OutputSyntax for LatexSyntax {
+    fn name(&self) -> &'static str {
+        "latex"
+    }
+    
+    fn aliase(&self) -> &'static [&'static str] {
+        &["tex"]
+    }
+    
+    fn kann_farben(&self) -> bool {
+        true
+    }
+    
+    fn format(&self, text: &str) -> String {
+        format!("\\textbf{{{}}}", text)
+    }
+    
+    fn begin_tabelle(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&latex_begin(tabelle.kopf, tabelle.zeilen));
+    }
+    
+    fn end_kopf(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("\\midrule\n");
+        if tabelle.zeilen.len() > LATEX_LONGTABLE_AB {
+            out.push_str("\\endhead\n");
+        }
+    }
+    
+    fn end_tabelle(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("\\bottomrule\n");
+        out.push_str(if tabelle.zeilen.len() > LATEX_LONGTABLE_AB {
+            "\\end{longtable}\n"
+        } else {
+            "\\end{tabular}\n"
+        });
+    }
+    
+    fn begin_zeile(&self, zeile: &ZeilenKontext, out: &mut String) {
+        if let Some((bg, _)) = farben(zeile.zeile).filter(|_| !zeile.kopf) {
+            out.push_str(&format!("\\rowcolor[HTML]{{{}}} ", latex_farbe(bg)));
+        }
+    }
+    
+    fn end_zeile(&self, _zeile: &ZeilenKontext, out: &mut String) {
+        out.push_str(" \\\\\n");
+    }
+    
+    fn zellen_trenner(&self) -> &'static str {
+        " & "
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        let text = markiert(&zelle.zelle.text, &zelle.zelle.treffer, latex_zelle, |t| self.format(t));
+        if zelle.kopf {
+            return self.format(&text);
+        }
+        // \rowcolor only sets the background, the text color of the row goes into each cell
+        match (zelle.zelle.farbe.and_then(farben), farben(zelle.zeile)) {
+            (Some((bg, fg)), _) => format!(
+                "\\cellcolor[HTML]{{{}}}\\textcolor[HTML]{{{}}}{{{}}}",
+                latex_farbe(bg),
+                latex_farbe(fg),
+                text
+            ),
+            (None, Some((_, fg))) => {
+                format!("\\textcolor[HTML]{{{}}}{{{}}}", latex_farbe(fg), text)
+            }
+            (None, None) => text,
+        }
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!(
+            "\\multicolumn{{{}}}{{l}}{{{}}} \\\\\n",
+            tabelle.kopf.len(),
+            self.format(&latex_zelle(&gruppe.titel))
+        ));
     }
 }
 
+impl OutputSyntax for TypstSyntax {
+    fn name(&self) -> &'static str {
+        "typst"
+    }
+    
+    fn aliase(&self) -> &'static [&'static str] {
+        &["typ"]
+    }
+    
+    fn kann_farben(&self) -> bool {
+        true
+    }
+    
+    fn format(&self, text: &str) -> String {
+        format!("#strong[{}]", text)
+    }
+    
+    /// Rows are filled by number type through `fill: (x, y) => (...).at(y)`
+    fn begin_tabelle(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!("#table(\n  columns: {},\n", tabelle.kopf.len()));
+        // Group headings are rows of their own without fill
+        let fuellung: Vec<String> = tabelle
+            .zeilen
+            .iter()
+            .enumerate()
+            .flat_map(|(position, (num, _))| {
+                let koepfe = tabelle.gruppen_ab(position).map(|_| "none".to_string());
+                let zeile = match farben(*num) {
+                    Some((bg, _)) => format!("rgb(\"{}\")", bg),
+                    None => "none".to_string(),
+                };
+                koepfe.chain(std::iter::once(zeile)).collect::<Vec<_>>()
+            })
+            .collect();
+        if fuellung.iter().any(|f| f != "none") {
+            out.push_str(&format!(
+                "  fill: (x, y) => (none, {}).at(y, default: none),\n",
+                fuellung.join(", ")
+            ));
+        }
+    }
+    
+    fn end_tabelle(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(")\n");
+    }
+    
+    fn begin_zeile(&self, zeile: &ZeilenKontext, out: &mut String) {
+        out.push_str(if zeile.kopf { "  table.header(" } else { "  " });
+    }
+    
+    fn end_zeile(&self, zeile: &ZeilenKontext, out: &mut String) {
+        out.push_str(if zeile.kopf { "),\n" } else { ",\n" });
+    }
+    
+    fn zellen_trenner(&self) -> &'static str {
+        ", "
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        let text = markiert(&zelle.zelle.text, &zelle.zelle.treffer, typst_zelle, |t| self.format(t));
+        if zelle.kopf {
+            return format!("[{}]", self.format(&text));
+        }
+        // The row fill only sets the background, the text color goes into each cell
+        match (zelle.zelle.farbe.and_then(farben), farben(zelle.zeile)) {
+            (Some((bg, fg)), _) => format!(
+                "table.cell(fill: rgb(\"{}\"))[#text(fill: rgb(\"{}\"))[{}]]",
+                bg, fg, text
+            ),
+            (None, Some((_, fg))) => format!("[#text(fill: rgb(\"{}\"))[{}]]", fg, text),
+            (None, None) => format!("[{}]", text),
+        }
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!(
+            "  table.cell(colspan: {})[{}],\n",
+            tabelle.kopf.len(),
+            self.format(&typst_zelle(&gruppe.titel))
+        ));
+    }
+}
+
+impl OutputSyntax for AsciiDocSyntax {
+    fn name(&self) -> &'static str {
+        "asciidoc"
+    }
+    
+    fn aliase(&self) -> &'static [&'static str] {
+        &["adoc"]
+    }
+    
+    fn format(&self, text: &str) -> String {
+        format!("*{}*", text)
+    }
+    
+    fn begin_tabelle(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!(
+            "[cols=\"{}*\",options=\"header\"]\n|===\n",
+            tabelle.kopf.len()
+        ));
+    }
+    
+    fn end_kopf(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push('\n');
+    }
+    
+    fn end_tabelle(&self, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str("|===\n");
+    }
+    
+    fn zellen_trenner(&self) -> &'static str {
+        " "
+    }
+    
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        if zelle.zelle.treffer.is_empty() || ist_liste(&zelle.zelle.text) {
+            return markiert(&zelle.zelle.text, &zelle.zelle.treffer, asciidoc_zelle, |t| self.format(t));
+        }
+        let text = markiert(&zelle.zelle.text, &zelle.zelle.treffer, asciidoc_escape, |t| self.format(t));
+        format!("|{}", text)
+    }
+    
+    fn gruppen_kopf(&self, gruppe: &Gruppe, tabelle: &TabellenKontext, out: &mut String) {
+        let titel = asciidoc_zelle(&self.format(&gruppe.titel));
+        out.push_str(&format!("{}+{}\n", tabelle.kopf.len(), titel));
+    }
+}
+
+impl OutputSyntax for NichtsSyntax {
+    fn name(&self) -> &'static str {
+        "nichts"
+    }
+    
+    fn end_zeile(&self, _zeile: &ZeilenKontext, _out: &mut String) {}
+    
+    fn zelle(&self, _zelle: &ZellenKontext) -> String {
+        String::new()
+    }
+    
+    fn gruppen_kopf(&self, _gruppe: &Gruppe, _tabelle: &TabellenKontext, _out: &mut String) {}
+}
+
+pub fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Cell for HTML: escaped, lists as a `<ul>` of escaped items
+pub fn html_zelle(text: &str) -> String {
+    if !ist_liste(text) {
+        return html_escape(text);
+    }
+    let (davor, punkte) = listen_punkte(text);
+    let mut out = html_escape(&davor);
+    out.push_str("<ul>");
+    for punkt in punkte {
+        out.push_str(&format!("<li>{}</li>", html_escape(&punkt)));
+    }
+    out.push_str("</ul>");
+    out
+}
+
+/// Cell text with search matches marked: `escape` is applied to the pieces between and in
+/// the matches, `markieren` to the escaped matches. List cells are escaped whole, so `escape`
+/// must render lists there; matches within their items are marked, see `liste_markiert`.
+pub fn markiert(
+    text: &str,
+    treffer: &[Range<usize>],
+    escape: impl Fn(&str) -> String,
+    markieren: impl Fn(&str) -> String,
+) -> String {
+    if treffer.is_empty() {
+        return escape(text);
+    }
+    if ist_liste(text) {
+        return liste_markiert(text, treffer, escape, markieren);
+    }
+    let mut out = String::new();
+    let mut bis = 0;
+    for bereich in treffer {
+        let (Some(davor), Some(drin)) = (text.get(bis..bereich.start), text.get(bereich.clone())) else {
+            continue;
+        };
+        out.push_str(&escape(davor));
+        out.push_str(&markieren(&escape(drin)));
+        bis = bereich.end;
+    }
+    out.push_str(&escape(text.get(bis..).unwrap_or_default()));
+    out
+}
+
+/// Start and end of a match in a list cell while it is escaped, from Unicode's private use area
+const MARKE_AN: char = '\u{E000}';
+const MARKE_AUS: char = '\u{E001}';
+
+/// List markup `listen_punkte` splits a cell at
+const LISTEN_MARKEN: [&str; 7] = ["<li>", "</li>", "<ul>", "</ul>", "[*]", "[list]", "[/list]"];
+
+/// A list cell with marked matches: the matches are fenced by private use characters, the
+/// cell escaped whole, and the fenced pieces of the result marked. Matches that reach into
+/// the list markup would split an item and stay unmarked.
+fn liste_markiert(
+    text: &str,
+    treffer: &[Range<usize>],
+    escape: impl Fn(&str) -> String,
+    markieren: impl Fn(&str) -> String,
+) -> String {
+    let marken: Vec<Range<usize>> = LISTEN_MARKEN
+        .iter()
+        .flat_map(|marke| text.match_indices(marke).map(|(an, m)| an..an + m.len()))
+        .collect();
+    let mut eingezaeunt = String::with_capacity(text.len() + 2 * treffer.len());
+    let mut bis = 0;
+    for bereich in treffer {
+        let ueberlappt = marken.iter().any(|m| m.start < bereich.end && bereich.start < m.end);
+        let (Some(davor), Some(drin)) = (text.get(bis..bereich.start), text.get(bereich.clone())) else {
+            continue;
+        };
+        if ueberlappt {
+            continue;
+        }
+        eingezaeunt.push_str(davor);
+        eingezaeunt.push(MARKE_AN);
+        eingezaeunt.push_str(drin);
+        eingezaeunt.push(MARKE_AUS);
+        bis = bereich.end;
+    }
+    eingezaeunt.push_str(text.get(bis..).unwrap_or_default());
+
+    let escaped = escape(&eingezaeunt);
+    let mut teile = escaped.split(MARKE_AN);
+    let mut out = teile.next().unwrap_or_default().to_string();
+    for teil in teile {
+        match teil.split_once(MARKE_AUS) {
+            Some((drin, danach)) => {
+                out.push_str(&markieren(drin));
+                out.push_str(danach);
+            }
+            None => out.push_str(teil),
+        }
+    }
+    out.replace(MARKE_AUS, "")
+}
+
+/// Class name fragment: whitespace and anything CSS would need escaped becomes `_`
+pub fn klassen_name(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+        .collect()
+}
+
+/// HTML header classes of a column: p1_ parameter, p2_ original header text, p4_ one per ST tag
+pub fn parameter_klassen(parameter: Option<&str>, name: &str, tags: &[ST]) -> Vec<String> {
+    let mut klassen = Vec::new();
+    if let Some(parameter) = parameter {
+        klassen.push(format!("p1_{}", klassen_name(parameter)));
+    }
+    klassen.push(format!("p2_{}", klassen_name(name)));
+    klassen.extend(tags.iter().map(|tag| format!("p4_{:?}", tag)));
+    klassen
+}
+
+/// Escapes the characters LaTeX treats specially; `<br>` and line breaks become
+/// `\newline`, which `latex_begin` gives a p{} column for
+pub fn latex_escape(text: &str) -> String {
+    let text = text.replace("<br>", "\n");
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '\n' => out.push_str("\\newline{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Cell text for LaTeX: `<li>`/`[*]` lists as generated by read_concat_csv and
+/// concat_mond_exponzieren_logarithmus_typ become itemize, everything else is escaped
+pub fn latex_zelle(text: &str) -> String {
+    if !ist_liste(text) {
+        return latex_escape(text);
+    }
+    let (davor, punkte) = listen_punkte(text);
+    let punkte: Vec<String> = punkte
+        .iter()
+        .map(|punkt| format!("\\item {}", latex_escape(punkt)))
+        .collect();
+
+    let mut out = latex_escape(&davor);
+    out.push_str("\\begin{itemize}");
+    out.push_str(&punkte.join(" "));
+    out.push_str("\\end{itemize}");
+    out
+}
+
+/// Splits a `<li>`/`[*]` list into the text before it and its items
+pub(crate) fn listen_punkte(text: &str) -> (String, Vec<String>) {
+    let text = text
+        .replace("[*]", "<li>")
+        .replace("<ul>", "")
+        .replace("</ul>", "")
+        .replace("[list]", "")
+        .replace("[/list]", "")
+        .replace("</li>", "");
+    let mut teile = text.split("<li>");
+    let davor = teile.next().unwrap_or_default().trim().to_string();
+    let punkte = teile
+        .map(|punkt| punkt.trim_matches(|c: char| c.is_whitespace() || c == '|'))
+        .filter(|punkt| !punkt.is_empty())
+        .map(str::to_string)
+        .collect();
+    (davor, punkte)
+}
+
+/// Typst markup escaped so cell text is never read as markup, `<br>` as line break
+pub fn typst_escape(text: &str) -> String {
+    let text = text.replace("<br>", "\n");
+    let mut out = String::with_capacity(text.len());
+    let mut zeichen = text.chars().peekable();
+    let mut zeilen_anfang = true;
+    while let Some(c) = zeichen.next() {
+        match c {
+            '\\' | '#' | '[' | ']' | '*' | '_' | '$' | '@' | '<' | '>' | '~' | '`' => {
+                out.push('\\');
+                out.push(c);
+            }
+            // Comments, and list or heading markers at the start of a line
+            '/' if matches!(zeichen.peek(), Some('/') | Some('*')) => out.push_str("\\/"),
+            '-' | '+' | '=' if zeilen_anfang => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str(" \\\n"),
+            _ => out.push(c),
+        }
+        zeilen_anfang = c == '\n' || (zeilen_anfang && c == ' ');
+    }
+    out
+}
+
+/// Cell content for Typst: lists become Typst lists, everything else is escaped
+pub fn typst_zelle(text: &str) -> String {
+    if !ist_liste(text) {
+        return typst_escape(text);
+    }
+    let (davor, punkte) = listen_punkte(text);
+    let mut out = typst_escape(&davor);
+    for punkt in punkte {
+        out.push_str("\n- ");
+        out.push_str(&typst_escape(&punkt));
+    }
+    out.push('\n');
+    out
+}
+
+fn asciidoc_escape(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace('{', "\\{")
+        .replace("<br>", " +\n")
+}
+
+/// Cell for AsciiDoc: lists as an AsciiDoc list in an `a|` cell, other text in a `|` cell
+pub fn asciidoc_zelle(text: &str) -> String {
+    let escape = asciidoc_escape;
+    if !ist_liste(text) {
+        return format!("|{}", escape(text));
+    }
+    let (davor, punkte) = listen_punkte(text);
+    let mut out = String::from("a|");
+    if !davor.is_empty() {
+        out.push_str(&escape(&davor));
+        out.push_str("\n\n");
+    }
+    for punkt in punkte {
+        out.push_str("* ");
+        out.push_str(&escape(&punkt));
+        out.push('\n');
+    }
+    out
+}
+
+/// Widest column an Org width cookie asks for; longer cells are shown shortened in Emacs
+pub const ORG_BREITE_MAX: usize = 50;
+
+/// Org-mode table: `#+NAME`/`#+CAPTION`, a column group and width cookie row, a header
+/// separator and row colors as CSS for the HTML export. Cells with lists cannot be shown
+/// by Org tables, so such tables are exported as HTML and the Org table stays as a comment.
+/// Without a title, name and class come from the header and the row numbers, so that
+/// several tables in one Org file keep apart for links and row colors.
+pub fn org_tabelle(
+    kopf: &[Zelle],
+    zeilen: &[(i32, Vec<Zelle>)],
+    breiten: &[usize],
+    titel: Option<&str>,
+) -> String {
+    let name = titel.map_or_else(|| org_name_ohne_titel(kopf, zeilen), org_name);
+    let klasse = format!("{}-tabelle", name);
+    // Without HTML output, list cells stay in the Org table
+    let mit_listen = cfg!(feature = "html_output")
+        && zeilen
+            .iter()
+            .any(|(_, zellen)| zellen.iter().any(|z| ist_liste(&z.text)));
+
+    let mut out = String::new();
+    #[cfg(feature = "html_output")]
+    if mit_listen {
+        out.push_str("#+BEGIN_EXPORT html\n");
+        out.push_str(&rendern(&HtmlSyntax, &TabellenKontext::new(kopf, zeilen)));
+        out.push_str("#+END_EXPORT\n#+BEGIN_COMMENT\n");
+    }
+
+    let farben: Vec<String> = zeilen
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (num, _))| {
+            farben(*num).map(|(bg, fg)| {
+                format!(
+                    ".{} tbody tr:nth-child({}) {{ background-color:{}; color:{}; }}",
+                    klasse,
+                    i + 1,
+                    bg,
+                    fg
+                )
+            })
+        })
+        .collect();
+    if !farben.is_empty() {
+        out.push_str(&format!("#+HTML_HEAD_EXTRA: <style>{}</style>\n", farben.join(" ")));
+    }
+    out.push_str(&format!("#+NAME: {}\n", name));
+    if let Some(titel) = titel {
+        out.push_str(&format!("#+CAPTION: {}\n", titel));
+    }
+    out.push_str(&format!("#+ATTR_HTML: :class {}\n", klasse));
+
+    // The first column only holds the group marker "/" and is not exported
+    let rechts = |i: usize| {
+        !zeilen.is_empty()
+            && zeilen.iter().all(|(_, zellen)| {
+                zellen
+                    .get(i)
+                    .is_some_and(|z| z.text.trim().parse::<f64>().is_ok())
+            })
+    };
+    let gruppen: Vec<&str> = (0..kopf.len())
+        .map(|i| match (i, kopf.len()) {
+            (0, _) => "<>",
+            (_, 2) => "<>",
+            (1, _) => "<",
+            (i, n) if i + 1 == n => ">",
+            _ => "",
+        })
+        .collect();
+    let rechtsbuendig: Vec<bool> = (0..kopf.len()).map(rechts).collect();
+    let cookies: Vec<String> = (0..kopf.len())
+        .map(|i| {
+            let breite = breiten.get(i).copied().unwrap_or(0).clamp(1, ORG_BREITE_MAX);
+            format!("<{}{}>", if rechtsbuendig[i] { "r" } else { "l" }, breite)
+        })
+        .collect();
+
+    let kopf: Vec<String> = kopf.iter().map(|z| org_zelle(&z.text)).collect();
+    let koerper: Vec<Vec<String>> = zeilen
+        .iter()
+        .map(|(_, zellen)| {
+            zellen
+                .iter()
+                .map(|z| if z.treffer.is_empty() || ist_liste(&z.text) {
+                    markiert(&z.text, &z.treffer, org_zelle, |t| format!("*{}*", t))
+                } else {
+                    markiert(&z.text, &z.treffer, org_escape, |t| format!("*{}*", t))
+                })
+                .collect()
+        })
+        .collect();
+
+    // Pad like Emacs' own realignment so the file reads well without C-c C-c
+    let mut spalten_breiten: Vec<usize> = cookies.iter().map(|c| anzeige_breite(c)).collect();
+    for zellen in std::iter::once(&kopf).chain(&koerper) {
+        for (i, zelle) in zellen.iter().enumerate() {
+            if let Some(breite) = spalten_breiten.get_mut(i) {
+                *breite = (*breite).max(anzeige_breite(zelle));
+            }
+        }
+    }
+    let zeile = |marker: &str, zellen: &[String], ausrichten: bool| {
+        let zellen: Vec<String> = zellen
+            .iter()
+            .enumerate()
+            .map(|(i, zelle)| {
+                let breite = spalten_breiten.get(i).copied().unwrap_or(0);
+                let luecke = " ".repeat(breite.saturating_sub(anzeige_breite(zelle)));
+                if ausrichten && rechtsbuendig.get(i) == Some(&true) {
+                    format!("{}{}", luecke, zelle)
+                } else {
+                    format!("{}{}", zelle, luecke)
+                }
+            })
+            .collect();
+        format!("| {:1} | {} |\n", marker, zellen.join(" | "))
+    };
+    let trenner: Vec<String> = std::iter::once(3)
+        .chain(spalten_breiten.iter().map(|b| b + 2))
+        .map(|b| "-".repeat(b))
+        .collect();
+
+    let gruppen: Vec<String> = gruppen.iter().map(|g| g.to_string()).collect();
+    out.push_str(&zeile("/", &gruppen, false));
+    out.push_str(&zeile("", &cookies, false));
+    out.push_str(&zeile("", &kopf, false));
+    out.push_str(&format!("|{}|\n", trenner.join("+")));
+    for zellen in &koerper {
+        out.push_str(&zeile("", zellen, true));
+    }
+    if mit_listen {
+        out.push_str("#+END_COMMENT\n");
+    }
+    out
+}
+
+/// Org cell text: no pipes, lists flattened, line breaks kept for the HTML export
+pub(crate) fn org_zelle(text: &str) -> String {
+    let text = if ist_liste(text) {
+        text.replace("[*]", "<li>")
+            .split("<li>")
+            .map(|punkt| {
+                punkt
+                    .replace("<ul>", "")
+                    .replace("</ul>", "")
+                    .replace("</li>", "")
+                    .replace("[list]", "")
+                    .replace("[/list]", "")
+            })
+            .map(|punkt| punkt.trim_matches(|c: char| c.is_whitespace() || c == '|').to_string())
+            .filter(|punkt| !punkt.is_empty())
+            .collect::<Vec<_>>()
+            .join("; ")
+    } else {
+        text.to_string()
+    };
+    text.replace('|', "\\vert{}")
+        .replace("<br>", "\n")
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" @@html:<br>@@ ")
+}
+
+/// Org text of a piece of a cell with search matches; unlike `org_zelle` keeps its spaces
+fn org_escape(text: &str) -> String {
+    text.replace('|', "\\vert{}")
+        .replace("<br>", " @@html:<br>@@ ")
+        .replace('\n', " @@html:<br>@@ ")
+}
+
+/// "reta-<first row>-<last row>-<hash>", the hash over header texts and row numbers
+fn org_name_ohne_titel(kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> String {
+    let mut hash = Fnv1a::new();
+    for zelle in kopf {
+        hash.schreiben(zelle.text.as_bytes());
+        hash.schreiben(&[0]);
+    }
+    for (zahl, _) in zeilen {
+        hash.schreiben(&zahl.to_le_bytes());
+    }
+    let bereich = match (zeilen.first(), zeilen.last()) {
+        (Some((erste, _)), Some((letzte, _))) => format!("{}-{}-", erste, letzte),
+        _ => String::new(),
+    };
+    format!("reta-{}{:08x}", bereich, hash.wert() as u32)
+}
+
+/// A caption as Org name: lowercase letters, digits and dashes
+fn org_name(titel: &str) -> String {
+    let name: String = titel
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let name = name.split('-').filter(|t| !t.is_empty()).collect::<Vec<_>>().join("-");
+    if name.is_empty() {
+        "reta".to_string()
+    } else {
+        name
+    }
+}
+
+pub(crate) fn ist_liste(text: &str) -> bool {
+    text.contains("<li>") || text.contains("[*]")
+}
+
+/// "#66ff66" to the "66FF66" expected by xcolor's HTML model
+fn latex_farbe(farbe: &str) -> String {
+    farbe.trim_start_matches('#').to_uppercase()
+}
+
+/// Opening of a booktabs table; columns holding lists get a paragraph column for itemize
+fn ist_mehrzeilig(text: &str) -> bool {
+    text.contains("<br>") || text.contains('\n')
+}
+
+fn latex_begin(kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> String {
+    let spalten: String = (0..kopf.len())
+        .map(|i| {
+            // Lists and \newline only work in paragraph columns
+            let absatz = zeilen.iter().any(|(_, zellen)| {
+                zellen
+                    .get(i)
+                    .is_some_and(|z| ist_liste(&z.text) || ist_mehrzeilig(&z.text))
+            }) || ist_mehrzeilig(&kopf[i].text);
+            if absatz {
+                "p{0.3\\linewidth}"
+            } else {
+                "l"
+            }
+        })
+        .collect();
+    let umgebung = if zeilen.len() > LATEX_LONGTABLE_AB {
+        "longtable"
+    } else {
+        "tabular"
+    };
+    format!("\\begin{{{}}}{{{}}}\n\\toprule\n", umgebung, spalten)
+}
+
 pub fn could_be_prime_number_primzahlkreuz(n: i32) -> bool {
     n > 1 && (2..=(n as f64).sqrt() as i32).all(|i| n % i != 0)
 }
@@ -53,36 +1102,96 @@ pub fn could_be_prime_number_primzahlkreuz_fuer_innen(n: i32) -> bool {
     could_be_prime_number_primzahlkreuz(n) && n % 4 == 1
 }
 
+/// All divisors of n in ascending order, found in pairs up to sqrt(n)
 pub fn divisor_generator(n: i32) -> Vec<i32> {
-    let mut divisors = Vec::new();
-    for i in 1..=n {
+    let mut kleine = Vec::new();
+    let mut grosse = Vec::new();
+    let mut i = 1;
+    while n > 0 && i <= n / i {
         if n % i == 0 {
-            divisors.push(i);
+            kleine.push(i);
+            if i != n / i {
+                grosse.push(n / i);
+            }
         }
+        i += 1;
     }
-    divisors
+    kleine.extend(grosse.into_iter().rev());
+    kleine
 }
 
 pub fn is_prim_multiple(n: i32) -> bool {
     n > 1 && (2..n).any(|i| n % i == 0 && could_be_prime_number_primzahlkreuz(i))
 }
 
+/// Moon roots of a number: all bases b with b^e == n, together with e - 2.
+/// Only exponents up to log2(n) can have a base of at least 2.
 pub fn moon_number(n: i32) -> (Vec<i32>, Vec<i32>) {
-    // Simplified implementation
-    let bases = Vec::new();
-    let exponents = Vec::new();
+    let mut bases = Vec::new();
+    let mut exponents = Vec::new();
+    if n < 4 {
+        return (bases, exponents);
+    }
+
+    let log2 = 31 - n.leading_zeros();
+    for i in 2..=log2 {
+        // The float root is only a guess, the neighbours are checked exactly
+        let schaetzung = (n as f64).powf(1.0 / i as f64).round() as i32;
+        let basis = (schaetzung.max(3) - 1..=schaetzung + 1)
+            .find(|b| b.checked_pow(i) == Some(n));
+        if let Some(basis) = basis {
+            bases.push(basis);
+            exponents.push(i as i32 - 2);
+        }
+    }
+
     (bases, exponents)
 }
 
+/// Exponent e with basis^e == potenz, if there is a whole one
+pub fn get_logarithm_only_as_pure_int(potenz: i32, basis: i32) -> Option<i32> {
+    if basis < 2 || potenz < 1 {
+        return None;
+    }
+    let mut exponent = 0;
+    let mut wert = 1;
+    while wert < potenz {
+        wert = wert.checked_mul(basis)?;
+        exponent += 1;
+    }
+    (wert == potenz).then_some(exponent)
+}
+
+/// 0 for 1, 1 for primes, 3 for moon numbers whose prime exponents share a
+/// divisor greater than 1 (e.g. 25, 64, 36), 2 for all other prime multiples
 pub fn prim_creativity(n: i32) -> i32 {
-    if n == 1 {
-        0
-    } else if could_be_prime_number_primzahlkreuz(n) {
-        1
-    } else if is_prim_multiple(n) {
-        2
-    } else {
-        3
+    let fak = prim_repeat(prim_fak(n));
+
+    if fak.is_empty() {
+        return 0;
+    }
+    if fak.len() == 1 {
+        return if fak[0].1 == 1 { 1 } else { 3 };
+    }
+
+    let mut schnittmenge: Option<Vec<i32>> = None;
+    for &(_, anzahl) in &fak {
+        let teiler: Vec<i32> = divisor_generator(anzahl)
+            .into_iter()
+            .filter(|&d| d != 1)
+            .collect();
+        schnittmenge = Some(match schnittmenge {
+            Some(mut menge) => {
+                menge.retain(|d| teiler.contains(d));
+                menge
+            }
+            None => teiler,
+        });
+    }
+
+    match schnittmenge {
+        Some(menge) if !menge.is_empty() => 3,
+        _ => 2,
     }
 }
 
@@ -100,17 +1209,16 @@ pub fn prim_multiple(n: i32) -> Vec<(i32, i32)> {
     result
 }
 
+/// Sorted prime factors grouped into (prime, exponent) pairs, in factor order
 pub fn prim_repeat(factors: Vec<i32>) -> Vec<(i32, i32)> {
-    let mut result = Vec::new();
-    let mut count_map = std::collections::HashMap::new();
-    
-    for &factor in &factors {
-        *count_map.entry(factor).or_insert(0) += 1;
-    }
-    
-    for (&factor, &count) in &count_map {
-        result.push((factor, count));
+    let mut result: Vec<(i32, i32)> = Vec::new();
+
+    for factor in factors {
+        match result.last_mut() {
+            Some((letzter, anzahl)) if *letzter == factor => *anzahl += 1,
+            _ => result.push((factor, 1)),
+        }
     }
-    
+
     result
 }
@@ -0,0 +1,86 @@
+//! lib4tables_enum module - equivalent to Python lib4tables_Enum module
+
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ST {
+    SternPolygon,
+    Galaxie,
+    GleichfoermigesPolygon,
+    Universum,
+    GebrRat,
+    KeinPolygon,
+    KeinParaOdMetaP,
+}
+
+impl ST {
+    pub fn all() -> HashSet<Self> {
+        vec![
+            Self::SternPolygon,
+            Self::Galaxie,
+            Self::GleichfoermigesPolygon,
+            Self::Universum,
+            Self::GebrRat,
+            Self::KeinPolygon,
+            Self::KeinParaOdMetaP,
+        ].into_iter().collect()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SternPolygon => "SternPolygon",
+            Self::Galaxie => "Galaxie",
+            Self::GleichfoermigesPolygon => "GleichfoermigesPolygon",
+            Self::Universum => "Universum",
+            Self::GebrRat => "GebrRat",
+            Self::KeinPolygon => "KeinPolygon",
+            Self::KeinParaOdMetaP => "KeinParaOdMetaP",
+        }
+    }
+
+    /// Parses a tag name ignoring case, as in `--spalten=@galaxie`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|tag| tag.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Kinds of columns, as the former spaltenTypNaming: the `typ` of a column in spalten.json
+/// and, by its position, the slot of `Tables::data_dict`. The first names of the
+/// registry, "religion", "generiert" and "kombi", are still read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpaltenTypKey {
+    /// A column of religion.csv
+    #[serde(alias = "religion")]
+    Ordinary,
+    /// Computed by a generator from other columns
+    #[serde(alias = "generiert")]
+    Generated1,
+    /// Read from prim.csv
+    Concat1,
+    /// A column of kombi13.csv or kombi15.csv
+    #[serde(alias = "kombi")]
+    Kombi1,
+    BoolAndTupleSet1,
+    /// Read from bruch15.csv
+    GebroUni1,
+    /// Read from bruch13.csv
+    GebrGal1,
+    Generated2,
+    Kombi2,
+    /// Read from bruch7.csv
+    GebrEmo1,
+    /// Read from bruchStrukGroesse.csv
+    GebrGroe1,
+    MetaKonkret,
+}
+
+impl SpaltenTypKey {
+    /// Slot of `Tables::data_dict`
+    pub fn schluessel(self) -> usize {
+        self as usize
+    }
+}
@@ -0,0 +1,588 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use lib4tables_concat::cache::MetadatenCache;
+use lib4tables_concat::center::csv_lesen;
+use lib4tables_concat::classifier::klassifikatoren;
+use lib4tables_concat::datenquelle::{Datenquelle, Konfiguration, DATEN_VARIABLE, KONFIG_VARIABLE};
+use lib4tables_concat::diagramm::{Diagramm, DiagrammArt};
+use lib4tables_concat::errors::{ConcatError, Result};
+#[cfg(feature = "html_output")]
+use lib4tables_concat::html_dokument::{HtmlDokumentSyntax, Thema};
+use lib4tables_concat::lib4tables::Zelle;
+use lib4tables_concat::modulo::{ModuloTabelle, STANDARD_BASEN};
+use lib4tables_concat::pager;
+use lib4tables_concat::pivot::{Anordnung, Pivot};
+use lib4tables_concat::potenz::PotenzTabelle;
+use lib4tables_concat::pruefung::quelle_pruefen;
+use lib4tables_concat::query::{Daten, Kombi, Query, Spalte, RELIGION_CSV};
+use lib4tables_concat::sortierung::{Schluessel, Sortierung};
+use lib4tables_concat::spaltenwahl::SpaltenAuswahl;
+use lib4tables_concat::suche::Suche;
+use lib4tables_concat::syntax::{syntax_fuer_art, syntax_namen, OutputSyntax};
+use lib4tables_concat::tabellenkalkulation::{Arbeitsblatt, Tabellenkalkulation};
+use lib4tables_concat::types::{Table, HOECHSTE_ZEILE};
+use lib4tables_concat::zahl::{ProfilFormat, Zaehlungen, ZahlProfil};
+use lib4tables_concat::zeilen::Zeilenbedingung;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().collect();
+
+    match args.get(1).map(String::as_str) {
+        Some("zahl") => zahl(&args[2..]),
+        Some("modulo") => modulo(&args[2..]),
+        Some("potenz") => potenz(&args[2..]),
+        Some("diagramm") => diagramm(&args[2..]),
+        Some("tabelle") => tabelle(&args[2..]),
+        Some("check-data") => check_data(&args[2..]),
+        _ => {
+            print_help();
+            Ok(())
+        }
+    }
+}
+
+/// `reta zahl <n> [--art=text|json|html] [--kein-cache] [--daten=...] [--profil=...]`
+fn zahl(args: &[String]) -> Result<()> {
+    let mut zahl = None;
+    let mut format = ProfilFormat::Text;
+    let mut cache = true;
+    let mut wahl = DatenWahl::default();
+
+    for arg in args {
+        if wahl.lesen(arg) {
+            continue;
+        } else if let Some(art) = arg.strip_prefix("--art=") {
+            format = art.parse()?;
+        } else if arg == "--kein-cache" {
+            cache = false;
+        } else {
+            zahl = Some(
+                arg.parse::<i32>()
+                    .map_err(|_| ConcatError::Parse(format!("not a number: {}", arg)))?,
+            );
+        }
+    }
+
+    let zahl = zahl.ok_or_else(|| ConcatError::Parse("reta zahl <n>".to_string()))?;
+    let profil = if cache {
+        ZahlProfil::aus_cache(zahl, &metadaten(&wahl.quelle()?)?)?
+    } else {
+        // Nothing counted, so the profile derives the counting of just this number
+        ZahlProfil::new(zahl, &Zaehlungen::default())?
+    };
+    print!("{}", profil.ausgeben(format)?);
+    Ok(())
+}
+
+/// The metadata cache of a data source, read from its cache file or computed and written
+fn metadaten(quelle: &Datenquelle) -> Result<MetadatenCache> {
+    let daten: Vec<PathBuf> = quelle.csv_dateien()?.into_values().collect();
+    MetadatenCache::laden_oder_berechnen(&quelle.cache_datei(), &daten, HOECHSTE_ZEILE)
+}
+
+/// religion.csv of a data source, whose row n belongs to number n
+fn religion(quelle: &Datenquelle) -> Result<Table> {
+    csv_lesen(&quelle.pfad(RELIGION_CSV))
+}
+
+fn spalten_nummer(nummer: &str) -> Result<usize> {
+    nummer
+        .parse::<usize>()
+        .map_err(|_| ConcatError::Parse(format!("not a column: {}", nummer)))
+}
+
+/// `reta modulo <zahlen> [--basen=2-25] [--typ=...] [--klassen=...] [--spalte=n] [--art=...] [--farben] [--daten=...] [--profil=...]`
+fn modulo(args: &[String]) -> Result<()> {
+    let mut zahlen = Vec::new();
+    let mut basen = STANDARD_BASEN.to_string();
+    let mut typen = String::new();
+    let mut klassen = String::new();
+    let mut spalte = None;
+    let mut wahl = DatenWahl::default();
+    let mut syntax = art_syntax("shell")?;
+    let mut thema = None;
+    let mut ods = None;
+    let mut farben = false;
+    let mut endlos = false;
+
+    for arg in args {
+        if let Some(bereich) = arg.strip_prefix("--basen=") {
+            basen = bereich.to_string();
+        } else if let Some(typ) = arg.strip_prefix("--typ=") {
+            typen = typ.to_string();
+        } else if let Some(namen) = arg.strip_prefix("--klassen=") {
+            klassen = namen.to_string();
+        } else if let Some(nummer) = arg.strip_prefix("--spalte=") {
+            spalte = Some(spalten_nummer(nummer)?);
+        } else if let Some(art) = arg.strip_prefix("--art=") {
+            syntax = art_syntax(art)?;
+        } else if let Some(pfad) = arg.strip_prefix("--ods=") {
+            ods = Some(PathBuf::from(pfad));
+        } else if let Some(name) = arg.strip_prefix("--thema=") {
+            thema = Some(name.to_string());
+        } else if arg == "--farben" {
+            farben = true;
+        } else if arg == "--endless" || arg == "--endlessscreen" {
+            endlos = true;
+        } else if !wahl.lesen(arg) {
+            zahlen.push(arg.clone());
+        }
+    }
+
+    let syntax = mit_thema(syntax, thema.as_deref())?;
+    let mut tabelle = ModuloTabelle::new(&zahlen.join(","), &basen, HOECHSTE_ZEILE)?;
+    tabelle.typen_filtern(&typen)?;
+    tabelle.klassifikatoren_setzen(&klassen)?;
+    if let Some(spalte) = spalte {
+        tabelle.texte_setzen(&religion(&wahl.quelle()?)?, spalte)?;
+    }
+    if let Some(pfad) = ods {
+        let (kopf, zeilen) = tabelle.zeilen(farben, false);
+        return ods_speichern(&pfad, "modulo", &kopf, &zeilen);
+    }
+    if seitenweise(syntax.as_ref(), endlos) {
+        let (kopf, zeilen) = tabelle.zeilen(farben, false);
+        return pager::anzeigen(&kopf, &zeilen, &[]);
+    }
+    print!("{}", tabelle.ausgeben(syntax.as_ref(), farben)?);
+    Ok(())
+}
+
+/// `reta potenz <zahlen> [--spalte=n] [--art=...] [--farben] [--daten=...] [--profil=...]`
+fn potenz(args: &[String]) -> Result<()> {
+    let mut zahlen = Vec::new();
+    let mut spalte = None;
+    let mut wahl = DatenWahl::default();
+    let mut syntax = art_syntax("shell")?;
+    let mut thema = None;
+    let mut ods = None;
+    let mut farben = false;
+    let mut endlos = false;
+
+    for arg in args {
+        if let Some(nummer) = arg.strip_prefix("--spalte=") {
+            spalte = Some(spalten_nummer(nummer)?);
+        } else if let Some(art) = arg.strip_prefix("--art=") {
+            syntax = art_syntax(art)?;
+        } else if let Some(pfad) = arg.strip_prefix("--ods=") {
+            ods = Some(PathBuf::from(pfad));
+        } else if let Some(name) = arg.strip_prefix("--thema=") {
+            thema = Some(name.to_string());
+        } else if arg == "--farben" {
+            farben = true;
+        } else if arg == "--endless" || arg == "--endlessscreen" {
+            endlos = true;
+        } else if !wahl.lesen(arg) {
+            zahlen.push(arg.clone());
+        }
+    }
+
+    let syntax = mit_thema(syntax, thema.as_deref())?;
+    let mut tabelle = PotenzTabelle::new(&zahlen.join(","), HOECHSTE_ZEILE)?;
+    if let Some(spalte) = spalte {
+        tabelle.texte_setzen(&religion(&wahl.quelle()?)?, spalte)?;
+    }
+    if let Some(pfad) = ods {
+        let (kopf, zeilen) = tabelle.zeilen(farben);
+        return ods_speichern(&pfad, "potenz", &kopf, &zeilen);
+    }
+    if seitenweise(syntax.as_ref(), endlos) {
+        let (kopf, zeilen) = tabelle.zeilen(farben);
+        return pager::anzeigen(&kopf, &zeilen, &[]);
+    }
+    print!("{}", tabelle.ausgeben(syntax.as_ref(), farben)?);
+    Ok(())
+}
+
+/// `reta diagramm <zahlen> [--typ=...] [--diagramm=zaehlungen|primfaktoren|streifen] [--svg=datei] [--kein-cache] [--daten=...] [--profil=...]`
+fn diagramm(args: &[String]) -> Result<()> {
+    let mut zahlen = Vec::new();
+    let mut typen = String::new();
+    let mut art = DiagrammArt::Zaehlungen;
+    let mut svg = None;
+    let mut cache = true;
+    let mut wahl = DatenWahl::default();
+
+    for arg in args {
+        if let Some(typ) = arg.strip_prefix("--typ=") {
+            typen = typ.to_string();
+        } else if let Some(name) = arg.strip_prefix("--diagramm=") {
+            art = DiagrammArt::from_name(name)
+                .ok_or_else(|| ConcatError::Parse(format!("unknown chart: {}", name)))?;
+        } else if let Some(pfad) = arg.strip_prefix("--svg=") {
+            svg = Some(PathBuf::from(pfad));
+        } else if arg == "--kein-cache" {
+            cache = false;
+        } else if !wahl.lesen(arg) {
+            zahlen.push(arg.clone());
+        }
+    }
+
+    let mut diagramm = Diagramm::new(&zahlen.join(","), HOECHSTE_ZEILE)?;
+    if cache {
+        diagramm = diagramm.mit_cache(Arc::new(metadaten(&wahl.quelle()?)?));
+    }
+    diagramm.typen_filtern(&typen)?;
+    match svg {
+        Some(pfad) => diagramm.speichern(art, &pfad),
+        None => {
+            print!("{}", diagramm.svg(art));
+            Ok(())
+        }
+    }
+}
+
+/// `reta tabelle <zahlen> [--spalten=...] [--spalten-explain] [--galaxie=...] [--universum=...] [--typ=...] [--klassen=...] [--suche=...] [--art=...] [--kein-cache]`
+fn tabelle(args: &[String]) -> Result<()> {
+    let mut zahlen = Vec::new();
+    let mut query = Query::rows("");
+    let mut thema = None;
+    let mut ods = None;
+    let mut endlos = false;
+    let mut erklaeren = false;
+    let mut speichern = None;
+    let mut wahl = DatenWahl::default();
+    let mut suchen = Vec::new();
+    let mut such_spalten = Vec::new();
+    let mut hervorheben = false;
+    let mut cache = true;
+    let mut gruppe = None;
+
+    for arg in args {
+        if wahl.lesen(arg) {
+            continue;
+        } else if let Some(name) = arg.strip_prefix('-').filter(|n| ALTE_GRUPPEN.contains(n)) {
+            gruppe = Some(name);
+        } else if gruppe == Some("zeilen") && arg.starts_with("--") {
+            query = query.bedingungen(Zeilenbedingung::parse(arg)?);
+        } else if gruppe == Some("spalten") && arg.starts_with("--") && !arg.starts_with("--breite=") {
+            query.spalten.erweitern(&alte_spalten(arg))?;
+        } else if gruppe == Some("ausgabe") && ALTE_ENDLOS.contains(&arg.as_str()) {
+            endlos = true;
+        } else if gruppe == Some("ausgabe") && ALTE_AUSGABE_OHNE.contains(&arg.as_str()) {
+            return Err(ConcatError::Parse(format!("-ausgabe {} is not supported", arg)));
+        } else if arg == "--spalten-explain" {
+            erklaeren = true;
+        } else if let Some(liste) = arg.strip_prefix("--spalten=") {
+            query = query.auswahl(SpaltenAuswahl::parse(liste)?);
+        } else if let Some(liste) = arg.strip_prefix("--umbenennen=") {
+            for paar in liste.split(',').filter(|p| !p.trim().is_empty()) {
+                let (spalte, name) = paar.split_once(':').ok_or_else(|| {
+                    ConcatError::Parse(format!("expected Spalte:Name, got {}", paar))
+                })?;
+                query = query.umbenennen(spalte.trim(), name.trim());
+            }
+        } else if let Some(sprache) = arg.strip_prefix("--sprache=") {
+            query = query.sprache(sprache);
+        } else if let Some(breite) = arg.strip_prefix("--breite=") {
+            query = query.breite(breite.parse().map_err(|_| {
+                ConcatError::Parse(format!("not a width: {}", breite))
+            })?);
+        } else if let Some(name) = arg.strip_prefix("--vorlage=") {
+            query = query.vorlage(Konfiguration::laden_standard()?.vorlage(name)?)?;
+        } else if let Some(name) = arg.strip_prefix("--vorlage-speichern=") {
+            speichern = Some(name.to_string());
+        } else if let Some((name, liste)) = arg
+            .strip_prefix("--")
+            .and_then(|a| a.split_once('='))
+            .and_then(|(name, liste)| Kombi::from_name(name).map(|k| (k, liste)))
+        {
+            query = query.kombi(name, spalten(liste));
+        } else if let Some(typ) = arg.strip_prefix("--typ=") {
+            query = query.typen(typ.split(','));
+        } else if let Some(namen) = arg.strip_prefix("--klassen=") {
+            query = query.klassen(namen.split(',').map(str::trim).filter(|n| !n.is_empty()));
+        } else if let Some(text) = arg.strip_prefix("--suche=") {
+            suchen.push(Suche::text(text));
+        } else if let Some(muster) = arg.strip_prefix("--regex=") {
+            suchen.push(Suche::regex(muster)?);
+        } else if let Some(liste) = arg.strip_prefix("--suche-spalten=") {
+            such_spalten = spalten(liste);
+        } else if arg == "--hervorheben" {
+            hervorheben = true;
+        } else if let Some(liste) = arg.strip_prefix("--sortiere=") {
+            query = query.sortiere(Sortierung::parse_liste(liste)?);
+        } else if let Some(schluessel) = arg.strip_prefix("--gruppiere=") {
+            query = query.gruppiere(Schluessel::parse(schluessel)?);
+        } else if arg == "--transponiert" {
+            query = query.anordnung(Anordnung::Transponiert);
+        } else if let Some(pivot) = arg.strip_prefix("--pivot=") {
+            query = query.anordnung(Anordnung::Pivot(Pivot::parse(pivot)?));
+        } else if let Some(art) = arg.strip_prefix("--art=") {
+            query = query.output(art_syntax(art)?);
+        } else if let Some(pfad) = arg.strip_prefix("--ods=") {
+            ods = Some(PathBuf::from(pfad));
+        } else if let Some(name) = arg.strip_prefix("--thema=") {
+            thema = Some(name.to_string());
+        } else if arg == "--endless" || arg == "--endlessscreen" {
+            endlos = true;
+        } else if arg == "--kein-cache" {
+            cache = false;
+        } else {
+            zahlen.push(arg.clone());
+        }
+    }
+    for suche in suchen {
+        query = query.suche(suche.spalten(such_spalten.clone()).hervorheben(hervorheben));
+    }
+
+    if let Some(name) = speichern {
+        let pfad = Konfiguration::standard_datei()
+            .ok_or_else(|| ConcatError::Config("no config file: set $RETA_KONFIG".to_string()))?;
+        Konfiguration::vorlage_speichern(&pfad, &name, &query.als_vorlage())?;
+        println!("Vorlage {} in {} gespeichert", name, pfad.display());
+        return Ok(());
+    }
+    query.zeilen = zahlen.join(",").into();
+    query.ausgabe = mit_thema(query.ausgabe, thema.as_deref())?;
+    let quelle = wahl.quelle()?;
+    let daten = Daten::aus_quelle(&quelle)?;
+    if erklaeren {
+        print!("{}", query.erklaeren(&daten)?.rendern(&daten.religion, query.ausgabe.as_ref())?);
+        return Ok(());
+    }
+    if cache {
+        query = query.cache(Arc::new(metadaten(&quelle)?));
+    }
+    let tabelle = query.ausfuehren(&daten)?;
+    if let Some(pfad) = ods {
+        return Tabellenkalkulation::aus_tabelle(&tabelle).speichern(&pfad);
+    }
+    if seitenweise(query.ausgabe.as_ref(), endlos) {
+        return pager::anzeigen(&tabelle.kopf, &tabelle.zeilen, &tabelle.gruppen);
+    }
+    print!("{}", tabelle.rendern(query.ausgabe.as_ref())?);
+    Ok(())
+}
+
+/// Parameter groups of the former reta: `-zeilen` takes row conditions, `-spalten` columns,
+/// `-kombination` the kombi columns and `-ausgabe` the output options after it
+const ALTE_GRUPPEN: [&str; 4] = ["zeilen", "spalten", "kombination", "ausgabe"];
+
+/// Former `-ausgabe` options for one long table, meaning `--endless`
+const ALTE_ENDLOS: [&str; 2] = ["--dontwrap", "--onetable"];
+
+/// Former `-ausgabe` options without a counterpart
+const ALTE_AUSGABE_OHNE: [&str; 5] = [
+    "--keineueberschriften",
+    "--keinenummerierung",
+    "--keineleereninhalte",
+    "--nocolor",
+    "--justtext",
+];
+
+/// A former `-spalten` option as `--spalten=` terms: `--name=a,-b` selects a and removes b,
+/// `--name` selects the column name, `--alles` every column
+fn alte_spalten(arg: &str) -> String {
+    let text = arg.trim_start_matches('-');
+    match text.split_once('=') {
+        Some((_, werte)) => werte.to_string(),
+        None if text == "alles" => "*".to_string(),
+        None => text.to_string(),
+    }
+}
+
+/// A list like "1,Religion,3": numbers are column numbers, anything else a heading
+fn spalten(liste: &str) -> Vec<Spalte> {
+    liste
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().map_or_else(|_| Spalte::from(s), Spalte::Index))
+        .collect()
+}
+
+/// `--ods=datei` writes a spreadsheet instead of printing
+fn ods_speichern(
+    pfad: &Path,
+    blatt: &str,
+    kopf: &[Zelle],
+    zeilen: &[(i32, Vec<Zelle>)],
+) -> Result<()> {
+    let mut dokument = Tabellenkalkulation::new();
+    dokument.blatt_hinzufuegen(Arbeitsblatt::aus_zellen(blatt, kopf, zeilen));
+    dokument.speichern(pfad)
+}
+
+/// Shell output is paged unless `--endless`; the pager itself checks for a terminal
+fn seitenweise(syntax: &dyn OutputSyntax, endlos: bool) -> bool {
+    !endlos && syntax.name() == "shell"
+}
+
+/// Looks up `--art=` in the shared syntax registry
+fn art_syntax(art: &str) -> Result<Arc<dyn OutputSyntax>> {
+    syntax_fuer_art(art).ok_or_else(|| ConcatError::Parse(format!("unknown output format: {}", art)))
+}
+
+/// `--thema=` only applies to `--art=html-dokument`
+#[cfg(feature = "html_output")]
+fn mit_thema(syntax: Arc<dyn OutputSyntax>, thema: Option<&str>) -> Result<Arc<dyn OutputSyntax>> {
+    let Some(name) = thema else {
+        return Ok(syntax);
+    };
+    let thema = Thema::from_name(name)
+        .ok_or_else(|| ConcatError::Parse(format!("unknown theme: {}", name)))?;
+    if syntax.name() == "html-dokument" {
+        Ok(Arc::new(HtmlDokumentSyntax::new(thema)))
+    } else {
+        Ok(syntax)
+    }
+}
+
+/// Built without `html_output` there are no themes
+#[cfg(not(feature = "html_output"))]
+fn mit_thema(syntax: Arc<dyn OutputSyntax>, thema: Option<&str>) -> Result<Arc<dyn OutputSyntax>> {
+    match thema {
+        Some(name) => Err(ConcatError::Parse(format!("unknown theme: {}", name))),
+        None => Ok(syntax),
+    }
+}
+
+/// `reta check-data [--daten=...] [--profil=...]`: lists every problem as file:line:column
+/// and exits with 1 if there is any
+fn check_data(args: &[String]) -> Result<()> {
+    let mut wahl = DatenWahl::default();
+    for arg in args {
+        if !wahl.lesen(arg) {
+            return Err(ConcatError::Parse(format!("unknown option: {}", arg)));
+        }
+    }
+
+    let quelle = wahl.quelle()?;
+    let befunde = quelle_pruefen(&quelle)?;
+    for befund in &befunde {
+        println!("{}", befund);
+    }
+    if !befunde.is_empty() {
+        eprintln!("{} Fehler in {}", befunde.len(), quelle.ordner.display());
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// `--daten=` and `--profil=`, shared by every command reading the CSV files
+#[derive(Default)]
+struct DatenWahl {
+    daten: Option<PathBuf>,
+    profil: Option<String>,
+}
+
+impl DatenWahl {
+    /// Takes the argument if it is one of the two options
+    fn lesen(&mut self, arg: &str) -> bool {
+        if let Some(pfad) = arg.strip_prefix("--daten=") {
+            self.daten = Some(PathBuf::from(pfad));
+        } else if let Some(name) = arg.strip_prefix("--profil=") {
+            self.profil = Some(name.to_string());
+        } else {
+            return false;
+        }
+        true
+    }
+
+    fn quelle(&self) -> Result<Datenquelle> {
+        Konfiguration::laden_standard()?.datenquelle(self.daten.as_deref(), self.profil.as_deref())
+    }
+}
+
+fn print_help() {
+    println!("RETA - Tabellenverarbeitungsprogramm");
+    println!();
+    println!("Verwendung: reta BEFEHL [OPTIONEN]");
+    println!();
+    println!("Befehle:");
+    println!("  zahl <n>         Alles, was reta über eine Zahl weiß");
+    println!("  modulo <zahlen>  Reste der Zahlen modulo mehrerer Basen");
+    println!("  potenz <zahlen>  Alle a^b = c mit a, b und c in den Zahlen");
+    println!("  diagramm <zahlen> SVG-Diagramm der Zahlen");
+    println!("  tabelle <zahlen> Zeilen der religion.csv mit gewählten Spalten");
+    println!("  check-data       CSV-Dateien des Datenordners prüfen");
+    println!();
+    println!("Optionen für zahl:");
+    println!("  --art=text       Text-Ausgabe (default)");
+    println!("  --art=json       JSON-Ausgabe");
+    println!("  --art=html       HTML-Ausgabe");
+    println!("  --kein-cache     Zahlendaten neu berechnen statt den Cache des Datenordners in ~/.cache/reta (oder neben $RETA_CACHE) zu lesen");
+    println!("  --daten=PFAD     Datenordner, siehe unten");
+    println!("  --profil=NAME    Datensatz-Profil aus der Konfigurationsdatei");
+    println!();
+    println!("Optionen für modulo:");
+    println!("  --basen=2-25     Basen als Zeilenangabe, z.B. 2-12,24");
+    println!("  --typ=sonne,mond Nur Zahlen dieser Klassen, z.B. primzahl, planet, innen");
+    println!("  --klassen=NAMEN  Spalten je Klassifikator: {}", klassifikatoren().namen().join(", "));
+    println!("  --art=shell      {}", syntax_namen().join(", "));
+    println!("  --farben         Reste nach Zahlentyp färben bzw. beschriften");
+    println!("  --spalte=1       Text dieser Spalte der religion.csv neben jeder Zahl");
+    println!("  --ods=DATEI      Als OpenDocument-Tabelle speichern");
+    println!("  --endless        Ohne Pager ausgeben (auch --endlessscreen)");
+    println!("  --daten=PFAD, --profil=NAME  wie bei zahl, für die religion.csv von --spalte=");
+    println!();
+    println!("Optionen für potenz:");
+    println!("  --spalte=1       Spalte der religion.csv des Datenordners, deren Text in jeder Zelle steht");
+    println!("  --daten=PFAD, --profil=NAME  wie bei zahl");
+    println!("  --art=shell      {}", syntax_namen().join(", "));
+    println!("  --farben         Zellen nach Zahlentyp färben");
+    println!("  --ods=DATEI      Als OpenDocument-Tabelle speichern");
+    println!("  --endless        Ohne Pager ausgeben (auch --endlessscreen)");
+    println!();
+    println!("Optionen für diagramm:");
+    println!("  --typ=sonne,mond Nur Zahlen dieser Klassen");
+    println!("  --diagramm=zaehlungen  Balken: Anzahl der Zahlen je Zählung (default)");
+    println!("  --diagramm=primfaktoren  Punkte: n gegen die Anzahl seiner Primfaktoren");
+    println!("  --diagramm=streifen  Streifen nach Primkreativität und Mondzahlen");
+    println!("  --svg=DATEI      In eine Datei schreiben statt auszugeben");
+    println!("  --kein-cache, --daten=PFAD, --profil=NAME  wie bei zahl");
+    println!();
+    println!("Optionen für tabelle:");
+    println!("  --spalten=1,Religion  Spalten der religion.csv nach Nummer oder Überschrift (default: alle)");
+    println!("                   -Spalte nimmt weg, @Galaxie wählt alle Spalten mit dem Tag, * alle:");
+    println!("                   --spalten=@Galaxie,-Liebe sind alle Galaxie-Spalten außer Liebe");
+    println!("                   Spalten auch nach Kennung oder Kurzname aus spalten.json, z.B. SG, Krea");
+    println!("  --spalten-explain Statt der Tabelle zeigen, welcher Parameter welche Spalte dazu- oder wegnahm");
+    println!("  --umbenennen=Krea:Kreativität,3:Drei  Überschriften für diese Ausgabe umbenennen");
+    println!("  --sprache=en     Überschriften der bekannten Spalten in dieser Sprache");
+    println!("  --breite=40      Zellen für --art=json und jsonl in Zeilen dieser Breite umbrechen");
+    println!("  --vorlage=NAME   Gespeicherte Spalten, Überschriften und Sprache aus der Konfigurationsdatei");
+    println!("  --vorlage-speichern=NAME  Spalten, Überschriften und Sprache als Vorlage speichern");
+    println!("  --galaxie=SPALTEN     Spalten der kombi13.csv dazu (leer: alle)");
+    println!("  --universum=SPALTEN   Spalten der kombi15.csv dazu (leer: alle)");
+    println!("  --typ=sonne,mond Nur Zahlen dieser Klassen");
+    println!("  --klassen=NAMEN  Spalten je Klassifikator");
+    println!("  --suche=TEXT     Nur Zahlen, in deren Zellen der Text vorkommt (Groß-/Kleinschreibung egal)");
+    println!("  --regex=MUSTER   Nur Zahlen, in deren Zellen der reguläre Ausdruck passt, z.B. --regex='(?i)lieb(e|t)'");
+    println!("                   Mehrere Suchen müssen alle passen, zusammen mit Bereich und --typ");
+    println!("  --suche-spalten=Religion,3  Nur in diesen Spalten suchen (default: alle gezeigten)");
+    println!("  --hervorheben    Treffer in der Ausgabe markieren");
+    println!("  --sortiere=-primfaktoren,Religion  Zeilen sortieren nach primfaktoren, mondexponent, zaehlung,");
+    println!("                   einem Klassifikator (z.B. primkreativitaet) oder dem Text einer Spalte; - absteigend");
+    println!("  --gruppiere=primkreativitaet  Zeilen mit demselben Wert unter einer Zwischenüberschrift");
+    println!("  --transponiert   Eigenschaften als Zeilen, je Zahl eine Spalte");
+    println!("  --pivot=gestirn  Eigenschaften als Zeilen, je Gruppe von Zahlen eine Spalte; gruppiert nach");
+    println!("                   zaehlung oder einem Klassifikator, :verketten (default) oder :zaehlen,");
+    println!("                   z.B. --pivot=primkreativitaet:zaehlen");
+    println!("  --art=shell      {}", syntax_namen().join(", "));
+    println!("  --ods=DATEI      Als OpenDocument-Tabelle speichern, mit einem Blatt je kombi13 und kombi15");
+    println!("  --endless        Ohne Pager ausgeben (auch --endlessscreen)");
+    println!("  --kein-cache, --daten=PFAD, --profil=NAME  wie bei zahl");
+    println!("  -zeilen, -spalten, -kombination, -ausgabe  Parametergruppen des alten reta; nach -zeilen");
+    println!("                   sind alle --Parameter Zeilenbedingungen, nach -spalten Spalten wie bei");
+    println!("                   --spalten= (--name=a,-b nimmt a dazu und b weg, --alles alle), nach");
+    println!("                   -kombination --galaxie= und --universum=, nach -ausgabe --art=, --breite=");
+    println!("                   und --onetable, --dontwrap oder --endlessscreen für --endless.");
+    println!("                   Zeilenbedingungen:");
+    println!("                   --alles, --vorhervonausschnitt=1-50, --vorhervonausschnittteiler=12,");
+    println!("                   --vielfachevonzahlen=3, --zeit=heute,gestern,morgen, --zaehlung=2,");
+    println!("                   --typ=sonne,mond,planet,schwarzesonne,sonnemitmondanteil,");
+    println!("                   --primzahlen=aussenerste,innenerste,aussenalle,innenalle,");
+    println!("                   --primzahlvielfache=2, --potenzenvonzahlen=2,3, --invertieren,");
+    println!("                   --nachtraeglichneuabzaehlung=1-3, --nachtraeglichneuabzaehlungvielfache=2");
+    println!();
+    println!("Optionen für check-data:");
+    println!("  --daten=PFAD, --profil=NAME  wie bei zahl");
+    println!();
+    println!("Datenordner: --daten=, sonst der Ordner des mit --profil= gewählten Profils,");
+    println!("sonst ${}, sonst die Konfigurationsdatei (${} oder ~/.config/reta/reta.json),", DATEN_VARIABLE, KONFIG_VARIABLE);
+    println!("sonst csv/ neben dem Programm, sonst csv/ im Arbeitsverzeichnis.");
+}
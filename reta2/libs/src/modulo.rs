@@ -0,0 +1,142 @@
+//! Modulo module - residue tables, equivalent to Python's center.moduloA
+
+use crate::center::{bereich_to_numbers2, i18n};
+use crate::classifier::{klassifikatoren, Classifier, PrimCreativity};
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::Zelle;
+use crate::syntax::OutputSyntax;
+use crate::types::{OrderedDict, Table};
+
+/// Bases used when no `--basen=` range is given, as in moduloA
+pub const STANDARD_BASEN: &str = "2-25";
+
+/// Classification of a residue, shown next to it when colors are not available
+pub fn rest_klasse(rest: i32) -> String {
+    match rest {
+        0 => "teilbar".to_string(),
+        _ => match PrimCreativity.labels(rest).first().copied() {
+            Some("eins") => i18n::krea_zahl("0. Primzahl 1"),
+            Some("primzahl") => i18n::krea_zahl("1. Primzahl und Sonnenzahl"),
+            Some("primvielfaches") => i18n::krea_zahl("2. Sonnenzahl, aber keine Primzahl"),
+            _ => i18n::krea_zahl("3. Mondzahl"),
+        },
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuloTabelle {
+    pub zahlen: Vec<i32>,
+    pub basen: Vec<i32>,
+    /// Classifiers shown as generated columns after the number
+    pub klassifikatoren: Vec<String>,
+    /// Text of the numbers' rows, shown next to the number
+    pub texte: OrderedDict<i32, String>,
+}
+
+impl ModuloTabelle {
+    /// Both arguments are range expressions like "1-10,v7"
+    pub fn new(zahlen: &str, basen: &str, max_zahl: i32) -> Result<Self> {
+        let zahlen: Vec<i32> = bereich_to_numbers2(zahlen, false, max_zahl).into_iter().collect();
+        let basen: Vec<i32> = bereich_to_numbers2(basen, false, max_zahl)
+            .into_iter()
+            .filter(|&b| b > 1)
+            .collect();
+
+        if zahlen.is_empty() {
+            return Err(ConcatError::Parse("no numbers for modulo table".to_string()));
+        }
+        if basen.is_empty() {
+            return Err(ConcatError::Parse("no bases greater than 1".to_string()));
+        }
+
+        Ok(Self {
+            zahlen,
+            basen,
+            klassifikatoren: Vec::new(),
+            texte: OrderedDict::new(),
+        })
+    }
+
+    /// Keeps only numbers with one of the `--typ=` labels
+    pub fn typen_filtern(&mut self, typen: &str) -> Result<()> {
+        let registry = klassifikatoren();
+        let typen = registry.typen(typen)?;
+        self.zahlen.retain(|&zahl| registry.passt(zahl, &typen));
+        Ok(())
+    }
+
+    /// Adds generated columns, e.g. "gestirn,primzahlkreuz"
+    pub fn klassifikatoren_setzen(&mut self, namen: &str) -> Result<()> {
+        let registry = klassifikatoren();
+        for name in namen.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            if registry.get(name).is_none() {
+                return Err(ConcatError::Parse(format!("unknown classifier: {}", name)));
+            }
+            self.klassifikatoren.push(name.to_string());
+        }
+        Ok(())
+    }
+
+    /// Takes the cell texts of one column, where row n of `relitable` belongs to number n
+    pub fn texte_setzen(&mut self, relitable: &Table, spalte: usize) -> Result<()> {
+        if relitable.first().is_none_or(|kopf| spalte >= kopf.len()) {
+            return Err(ConcatError::InvalidColumnIndex(spalte));
+        }
+
+        for &zahl in &self.zahlen {
+            if let Some(text) = relitable.get(zahl as usize).and_then(|row| row.get(spalte)) {
+                let text = text.trim();
+                if !text.is_empty() {
+                    self.texte.insert(zahl, text.to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// One row per number, one column per base; each cell shows `n % b` and `b - n % b`
+    pub fn zeilen(&self, farben: bool, klassen: bool) -> (Vec<Zelle>, Vec<(i32, Vec<Zelle>)>) {
+        let registry = klassifikatoren();
+        let mut kopf = vec![Zelle::new("n")];
+        kopf.extend(self.klassifikatoren.iter().map(Zelle::new));
+        kopf.extend(self.basen.iter().map(|b| Zelle::new(format!("mod {}", b))));
+
+        let zeilen = self
+            .zahlen
+            .iter()
+            .map(|&zahl| {
+                let text = match self.texte.get(&zahl) {
+                    Some(text) => format!("{}: {}", zahl, text),
+                    None => zahl.to_string(),
+                };
+                let mut zellen = vec![Zelle::farbig(text, zahl)];
+                zellen.extend(self.klassifikatoren.iter().map(|name| {
+                    Zelle::new(registry.zelle(name, zahl).unwrap_or_default())
+                }));
+                zellen.extend(self.basen.iter().map(|&basis| {
+                    let rest = zahl % basis;
+                    let text = if klassen {
+                        format!("{} {} ({})", rest, rest_klasse(rest), basis - rest)
+                    } else {
+                        format!("{} ({})", rest, basis - rest)
+                    };
+                    if farben {
+                        Zelle::farbig(text, rest)
+                    } else {
+                        Zelle::new(text)
+                    }
+                }));
+                (zahl, zellen)
+            })
+            .collect();
+
+        (kopf, zeilen)
+    }
+
+    /// Formats that cannot color cells get the residue classes as text instead
+    pub fn ausgeben(&self, syntax: &dyn OutputSyntax, farben: bool) -> Result<String> {
+        let kann_farben = syntax.kann_farben();
+        let (kopf, zeilen) = self.zeilen(farben && kann_farben, farben && !kann_farben);
+        syntax.format_table(&kopf, &zeilen)
+    }
+}
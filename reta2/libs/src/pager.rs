@@ -0,0 +1,522 @@
+//! Pager module - interactive terminal output with frozen headers, scrolling, search and row jumps
+
+#[cfg(feature = "pager")]
+use std::io::{self, IsTerminal, Write as _};
+
+#[cfg(feature = "pager")]
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+#[cfg(feature = "pager")]
+use crossterm::{cursor, execute, queue, terminal};
+
+use crate::breite::{abschneiden, auffuellen};
+use crate::errors::Result;
+use crate::lib4tables::{shell_farben, ShellSyntax, Zelle};
+use crate::syntax::{Gruppe, OutputSyntax, TabellenKontext};
+
+/// Widest column in the pager; longer cells are cut and end in "…"
+pub const PAGER_SPALTE_MAX: usize = 40;
+
+/// `onetable`: all columns side by side, or split into tables that fit the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ansicht {
+    EineTabelle,
+    Geteilt,
+}
+
+/// Keys the pager reacts to, independent of the terminal library
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Taste {
+    Zeichen(char),
+    Hoch,
+    Runter,
+    Links,
+    Rechts,
+    BildHoch,
+    BildRunter,
+    Anfang,
+    Ende,
+    Enter,
+    Escape,
+    Loeschen,
+    /// Ctrl-C or Ctrl-D: quits, also while typing a search or a number
+    Abbrechen,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Eingabe {
+    Keine,
+    Suche(String),
+    Sprung(String),
+}
+
+/// One line below the frozen header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Linie {
+    /// Row `zeile` of the columns of split table `teil`
+    Zeile { teil: usize, zeile: usize },
+    /// Space between two split tables
+    Leer { teil: usize },
+    /// Heading of group `gruppe` of split table `teil`, see `--gruppiere=`
+    Gruppe { teil: usize, gruppe: usize },
+}
+
+impl Linie {
+    fn teil(&self) -> usize {
+        match self {
+            Self::Zeile { teil, .. } | Self::Leer { teil } | Self::Gruppe { teil, .. } => *teil,
+        }
+    }
+}
+
+/// The pager state: what is shown and where, without any terminal access
+#[derive(Debug, Clone)]
+pub struct Pager {
+    kopf: Vec<Zelle>,
+    zeilen: Vec<(i32, Vec<Zelle>)>,
+    gruppen: Vec<Gruppe>,
+    breiten: Vec<usize>,
+    pub ansicht: Ansicht,
+    /// First line below the header
+    pub oben: usize,
+    /// First column after the frozen first column, in the one table view
+    pub links: usize,
+    pub suche: Option<String>,
+    eingabe: Eingabe,
+    pub breite: usize,
+    pub hoehe: usize,
+}
+
+impl Pager {
+    pub fn new(kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)], breite: usize, hoehe: usize) -> Self {
+        let breiten = TabellenKontext::new(kopf, zeilen)
+            .breiten
+            .into_iter()
+            .map(|b| b.clamp(1, PAGER_SPALTE_MAX))
+            .collect();
+        Self {
+            kopf: kopf.to_vec(),
+            zeilen: zeilen.to_vec(),
+            gruppen: Vec::new(),
+            breiten,
+            ansicht: Ansicht::EineTabelle,
+            oben: 0,
+            links: 1,
+            suche: None,
+            eingabe: Eingabe::Keine,
+            breite,
+            hoehe,
+        }
+    }
+
+    /// Group headings shown above the rows they start at
+    pub fn mit_gruppen(mut self, gruppen: &[Gruppe]) -> Self {
+        self.gruppen = gruppen.to_vec();
+        self
+    }
+
+    /// Whether the plain table fits the terminal, so that no pager is needed
+    pub fn passt(&self) -> bool {
+        let breite: usize = self.breiten.iter().map(|b| b + 1).sum();
+        breite <= self.breite && self.zeilen.len() + self.gruppen.len() + 1 < self.hoehe
+    }
+
+    pub fn groesse(&mut self, breite: usize, hoehe: usize) {
+        self.breite = breite;
+        self.hoehe = hoehe;
+        self.begrenzen();
+    }
+
+    /// Lines between header and status line
+    fn sichtbar(&self) -> usize {
+        self.hoehe.saturating_sub(2).max(1)
+    }
+
+    /// The columns of each split table; column 0 starts every one of them
+    fn teile(&self) -> Vec<Vec<usize>> {
+        let n = self.breiten.len();
+        if self.ansicht == Ansicht::EineTabelle || n <= 1 {
+            return vec![(0..n).collect()];
+        }
+        let mut teile = Vec::new();
+        let mut teil = vec![0];
+        let mut belegt = self.breiten[0] + 1;
+        for spalte in 1..n {
+            let breite = self.breiten[spalte] + 1;
+            if teil.len() > 1 && belegt + breite > self.breite {
+                teile.push(std::mem::replace(&mut teil, vec![0]));
+                belegt = self.breiten[0] + 1;
+            }
+            teil.push(spalte);
+            belegt += breite;
+        }
+        teile.push(teil);
+        teile
+    }
+
+    fn linien(&self) -> Vec<Linie> {
+        let mut linien = Vec::new();
+        for teil in 0..self.teile().len() {
+            if teil > 0 {
+                linien.push(Linie::Leer { teil });
+            }
+            for zeile in 0..self.zeilen.len() {
+                linien.extend(
+                    self.gruppen
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, g)| g.ab == zeile)
+                        .map(|(gruppe, _)| Linie::Gruppe { teil, gruppe }),
+                );
+                linien.push(Linie::Zeile { teil, zeile });
+            }
+        }
+        linien
+    }
+
+    fn begrenzen(&mut self) {
+        let linien = self.linien().len();
+        self.oben = self.oben.min(linien.saturating_sub(self.sichtbar()));
+        self.links = self.links.clamp(1, self.breiten.len().saturating_sub(1).max(1));
+    }
+
+    /// Row index of the first visible data row
+    fn aktuelle_zeile(&self) -> Option<usize> {
+        self.linien().into_iter().skip(self.oben).find_map(|linie| match linie {
+            Linie::Zeile { zeile, .. } => Some(zeile),
+            Linie::Leer { .. } | Linie::Gruppe { .. } => None,
+        })
+    }
+
+    fn aktueller_teil(&self) -> usize {
+        self.linien().get(self.oben).map_or(0, Linie::teil)
+    }
+
+    fn zu_zeile(&mut self, teil: usize, zeile: usize) {
+        if let Some(position) = self
+            .linien()
+            .iter()
+            .position(|l| *l == Linie::Zeile { teil, zeile })
+        {
+            self.oben = position;
+            self.begrenzen();
+        }
+    }
+
+    fn trifft(&self, zeile: usize, suche: &str) -> bool {
+        let suche = suche.to_lowercase();
+        self.zeilen[zeile]
+            .1
+            .iter()
+            .any(|zelle| zelle.text.to_lowercase().contains(&suche))
+    }
+
+    /// Moves to the next row matching the search, starting at `ab`
+    fn treffer(&mut self, ab: usize, vorwaerts: bool) -> bool {
+        let Some(suche) = self.suche.clone().filter(|s| !s.is_empty()) else {
+            return false;
+        };
+        let teil = self.aktueller_teil();
+        let n = self.zeilen.len();
+        let gefunden = (0..n)
+            .map(|i| if vorwaerts { (ab + i) % n } else { (ab + n - i) % n })
+            .find(|&zeile| self.trifft(zeile, &suche));
+        if let Some(zeile) = gefunden {
+            self.zu_zeile(teil, zeile);
+        }
+        gefunden.is_some()
+    }
+
+    /// Jumps to the row of number `zahl`
+    pub fn springen(&mut self, zahl: i32) -> bool {
+        let teil = self.aktueller_teil();
+        match self.zeilen.iter().position(|(num, _)| *num == zahl) {
+            Some(zeile) => {
+                self.zu_zeile(teil, zeile);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn ansicht_wechseln(&mut self) {
+        let zeile = self.aktuelle_zeile();
+        self.ansicht = match self.ansicht {
+            Ansicht::EineTabelle => Ansicht::Geteilt,
+            Ansicht::Geteilt => Ansicht::EineTabelle,
+        };
+        self.oben = 0;
+        if let Some(zeile) = zeile {
+            self.zu_zeile(0, zeile);
+        }
+    }
+
+    /// Handles a key; false means quit
+    pub fn taste(&mut self, taste: Taste) -> bool {
+        if taste == Taste::Abbrechen {
+            return false;
+        }
+        match std::mem::replace(&mut self.eingabe, Eingabe::Keine) {
+            Eingabe::Suche(mut text) => {
+                match taste {
+                    Taste::Zeichen(c) => text.push(c),
+                    Taste::Loeschen => {
+                        text.pop();
+                    }
+                    Taste::Enter => return true,
+                    Taste::Escape => {
+                        self.suche = None;
+                        return true;
+                    }
+                    _ => {}
+                }
+                // Incremental: every keystroke searches again from the current row
+                self.suche = Some(text.clone());
+                let ab = self.aktuelle_zeile().unwrap_or(0);
+                self.treffer(ab, true);
+                self.eingabe = Eingabe::Suche(text);
+                return true;
+            }
+            Eingabe::Sprung(mut text) => {
+                match taste {
+                    Taste::Zeichen(c) if c.is_ascii_digit() => text.push(c),
+                    Taste::Loeschen => {
+                        text.pop();
+                    }
+                    Taste::Enter => {
+                        if let Ok(zahl) = text.parse() {
+                            self.springen(zahl);
+                        }
+                        return true;
+                    }
+                    Taste::Escape => return true,
+                    _ => {}
+                }
+                self.eingabe = Eingabe::Sprung(text);
+                return true;
+            }
+            Eingabe::Keine => {}
+        }
+
+        let seite = self.sichtbar();
+        match taste {
+            Taste::Zeichen('q') | Taste::Escape => return false,
+            Taste::Runter | Taste::Zeichen('j') | Taste::Enter => self.oben += 1,
+            Taste::Hoch | Taste::Zeichen('k') => self.oben = self.oben.saturating_sub(1),
+            Taste::BildRunter | Taste::Zeichen(' ') => self.oben += seite,
+            Taste::BildHoch | Taste::Zeichen('b') => self.oben = self.oben.saturating_sub(seite),
+            Taste::Anfang | Taste::Zeichen('g') => self.oben = 0,
+            Taste::Ende | Taste::Zeichen('G') => self.oben = usize::MAX,
+            Taste::Rechts | Taste::Zeichen('l') => self.seitwaerts(true),
+            Taste::Links | Taste::Zeichen('h') => self.seitwaerts(false),
+            Taste::Zeichen('t') => self.ansicht_wechseln(),
+            Taste::Zeichen('/') => self.eingabe = Eingabe::Suche(String::new()),
+            Taste::Zeichen(':') => self.eingabe = Eingabe::Sprung(String::new()),
+            Taste::Zeichen(c) if c.is_ascii_digit() => self.eingabe = Eingabe::Sprung(c.to_string()),
+            Taste::Zeichen('n') => {
+                let ab = self.aktuelle_zeile().map_or(0, |z| z + 1);
+                self.treffer(ab, true);
+            }
+            Taste::Zeichen('N') => {
+                let ab = self.aktuelle_zeile().map_or(0, |z| z + self.zeilen.len() - 1);
+                self.treffer(ab, false);
+            }
+            _ => {}
+        }
+        self.begrenzen();
+        true
+    }
+
+    /// One column in the one table view, one split table in the split view
+    fn seitwaerts(&mut self, rechts: bool) {
+        match self.ansicht {
+            Ansicht::EineTabelle if rechts => self.links += 1,
+            Ansicht::EineTabelle => self.links = self.links.saturating_sub(1),
+            Ansicht::Geteilt => {
+                let teil = self.aktueller_teil();
+                let teil = if rechts {
+                    (teil + 1).min(self.teile().len() - 1)
+                } else {
+                    teil.saturating_sub(1)
+                };
+                let zeile = self.aktuelle_zeile().unwrap_or(0);
+                self.zu_zeile(teil, zeile);
+            }
+        }
+    }
+
+    /// Columns shown for split table `teil`, scrolled in the one table view
+    fn spalten(&self, teil: usize) -> Vec<usize> {
+        let mut spalten = self.teile().swap_remove(teil.min(self.teile().len() - 1));
+        if self.ansicht == Ansicht::EineTabelle && spalten.len() > 1 {
+            spalten.drain(1..self.links.min(spalten.len()));
+        }
+        spalten
+    }
+
+    /// A row as terminal text: padded, cut to the terminal width, colored and with search hits
+    fn zeile_text(&self, zellen: &[Zelle], spalten: &[usize], kopf: bool) -> String {
+        let mut out = String::new();
+        let mut rest = self.breite;
+        for &spalte in spalten {
+            if rest == 0 {
+                break;
+            }
+            let breite = self.breiten[spalte].min(rest);
+            let text = zellen.get(spalte).map_or(String::new(), |z| z.text.replace("<br>", " "));
+            let sichtbar = auffuellen(&abschneiden(&text, breite), breite);
+            let treffer = !kopf
+                && self
+                    .suche
+                    .as_deref()
+                    .is_some_and(|s| !s.is_empty() && text.to_lowercase().contains(&s.to_lowercase()));
+
+            if kopf {
+                out.push_str("\x1b[1m");
+            }
+            if treffer {
+                out.push_str("\x1b[7m");
+            }
+            if let Some(num) = zellen.get(spalte).and_then(|z| z.farbe).filter(|_| !kopf) {
+                out.push_str(shell_farben(num));
+            }
+            out.push_str(&sichtbar);
+            out.push_str("\x1b[0m");
+            rest -= breite;
+            if rest > 0 {
+                out.push(' ');
+                rest -= 1;
+            }
+        }
+        out
+    }
+
+    fn status(&self) -> String {
+        match &self.eingabe {
+            Eingabe::Suche(text) => format!("/{}", text),
+            Eingabe::Sprung(text) => format!(":{}", text),
+            Eingabe::Keine => {
+                let linien = self.linien().len();
+                let bis = (self.oben + self.sichtbar()).min(linien);
+                let ansicht = match self.ansicht {
+                    Ansicht::EineTabelle => "eine Tabelle",
+                    Ansicht::Geteilt => "geteilt",
+                };
+                let text = format!(
+                    "Zeilen {}-{} von {} | {} | q Ende, / Suche, : Zeile, t Ansicht",
+                    (self.oben + 1).min(bis),
+                    bis,
+                    linien,
+                    ansicht
+                );
+                abschneiden(&text, self.breite)
+            }
+        }
+    }
+
+    /// The screen: frozen header, visible lines and the status line
+    pub fn bildschirm(&self) -> Vec<String> {
+        let linien = self.linien();
+        let teil = self.aktueller_teil();
+        let mut bild = vec![self.zeile_text(&self.kopf, &self.spalten(teil), true)];
+        for linie in linien.iter().skip(self.oben).take(self.sichtbar()) {
+            bild.push(match *linie {
+                Linie::Zeile { teil, zeile } => {
+                    self.zeile_text(&self.zeilen[zeile].1, &self.spalten(teil), false)
+                }
+                Linie::Gruppe { gruppe, .. } => {
+                    let mut kopf = String::new();
+                    let tabelle = TabellenKontext::new(&self.kopf, &self.zeilen);
+                    ShellSyntax.gruppen_kopf(&self.gruppen[gruppe], &tabelle, &mut kopf);
+                    abschneiden(kopf.trim_end_matches('\n'), self.breite)
+                }
+                Linie::Leer { teil } => format!(
+                    "\x1b[2m{}\x1b[0m",
+                    "─".repeat(self.breite.min(
+                        self.spalten(teil).iter().map(|&s| self.breiten[s] + 1).sum()
+                    ))
+                ),
+            });
+        }
+        bild.push(format!("\x1b[7m{}\x1b[0m", self.status()));
+        bild
+    }
+}
+
+#[cfg(feature = "pager")]
+fn taste(key: KeyEvent) -> Option<Taste> {
+    // Raw mode delivers Ctrl-C as a key instead of SIGINT
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::Char('c' | 'd') => Some(Taste::Abbrechen),
+            _ => None,
+        };
+    }
+    Some(match key.code {
+        KeyCode::Char(c) => Taste::Zeichen(c),
+        KeyCode::Up => Taste::Hoch,
+        KeyCode::Down => Taste::Runter,
+        KeyCode::Left => Taste::Links,
+        KeyCode::Right => Taste::Rechts,
+        KeyCode::PageUp => Taste::BildHoch,
+        KeyCode::PageDown => Taste::BildRunter,
+        KeyCode::Home => Taste::Anfang,
+        KeyCode::End => Taste::Ende,
+        KeyCode::Enter => Taste::Enter,
+        KeyCode::Esc => Taste::Escape,
+        KeyCode::Backspace => Taste::Loeschen,
+        _ => return None,
+    })
+}
+
+/// Shows the table in the pager if stdout is a terminal and the table does not fit,
+/// otherwise prints it as the shell syntax does
+#[cfg(feature = "pager")]
+pub fn anzeigen(kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)], gruppen: &[Gruppe]) -> Result<()> {
+    let mut stdout = io::stdout();
+    let groesse = terminal::size().ok().filter(|_| stdout.is_terminal());
+    let Some((breite, hoehe)) = groesse else {
+        print!("{}", ShellSyntax.format_gruppiert(kopf, zeilen, gruppen)?);
+        return Ok(());
+    };
+    let mut pager = Pager::new(kopf, zeilen, breite as usize, hoehe as usize).mit_gruppen(gruppen);
+    if pager.passt() {
+        print!("{}", ShellSyntax.format_gruppiert(kopf, zeilen, gruppen)?);
+        return Ok(());
+    }
+
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let ergebnis = (|| -> Result<()> {
+        loop {
+            queue!(stdout, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0))?;
+            for (i, zeile) in pager.bildschirm().iter().enumerate() {
+                queue!(stdout, cursor::MoveTo(0, i as u16))?;
+                stdout.write_all(zeile.as_bytes())?;
+            }
+            stdout.flush()?;
+
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    if let Some(taste) = taste(key) {
+                        if !pager.taste(taste) {
+                            return Ok(());
+                        }
+                    }
+                }
+                Event::Resize(breite, hoehe) => pager.groesse(breite as usize, hoehe as usize),
+                _ => {}
+            }
+        }
+    })();
+    // Restore the terminal even if drawing failed
+    let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+    ergebnis
+}
+
+/// Built without the `pager` feature: prints the table as the shell syntax does
+#[cfg(not(feature = "pager"))]
+pub fn anzeigen(kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)], gruppen: &[Gruppe]) -> Result<()> {
+    print!("{}", ShellSyntax.format_gruppiert(kopf, zeilen, gruppen)?);
+    Ok(())
+}
@@ -0,0 +1,265 @@
+//! Pivot module - transposed and pivoted views of a TableData: properties as rows and
+//! numbers, or groups of numbers, as columns
+//!
+//! `--transponiert` compares a few numbers across many properties; `--pivot=gestirn:zaehlen`
+//! counts per property how many moons, suns and planets have a text in it.
+
+use crate::cache::{zaehlungen_bis, MetadatenCache};
+use crate::classifier::klassifikatoren;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::Zelle;
+use crate::query::{SpaltenInfo, SpaltenQuelle, TableData};
+use crate::types::{OrderedDict, Transpose};
+
+/// What the numbers of a pivot are grouped by; `--gruppiere=` takes the same names,
+/// see `Schluessel::Gruppierung`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Gruppierung {
+    /// Labels of a classifier of the ClassifierRegistry; a number may be in several groups
+    Klassifikator(String),
+    /// The zaehlung a number belongs to, see Zaehlungen
+    Zaehlung,
+}
+
+impl Gruppierung {
+    /// "zaehlung" or the name of a classifier, like "primkreativitaet" or "gestirn"
+    pub fn parse(name: &str) -> Result<Self> {
+        Self::finden(name)
+            .ok_or_else(|| ConcatError::Parse(format!("unknown grouping: {}", name.trim())))
+    }
+
+    /// Like `parse`, None for names that are no grouping
+    pub fn finden(name: &str) -> Option<Self> {
+        let name = name.trim();
+        if name == "zaehlung" {
+            return Some(Self::Zaehlung);
+        }
+        klassifikatoren()
+            .get(name)
+            .map(|_| Self::Klassifikator(name.to_string()))
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Klassifikator(name) => name,
+            Self::Zaehlung => "zaehlung",
+        }
+    }
+
+    /// The numbers of each group; groups in the classifier's label order or by zaehlung
+    pub fn gruppen(&self, zahlen: &[i32]) -> Result<OrderedDict<String, Vec<i32>>> {
+        self.gruppen_aus_cache(zahlen, None)
+    }
+
+    /// Like `gruppen`, taking the countings from the cache when there is one
+    pub fn gruppen_aus_cache(
+        &self,
+        zahlen: &[i32],
+        cache: Option<&MetadatenCache>,
+    ) -> Result<OrderedDict<String, Vec<i32>>> {
+        let mut gruppen: OrderedDict<String, Vec<i32>> = OrderedDict::new();
+        match self {
+            Self::Klassifikator(name) => {
+                let registry = klassifikatoren();
+                let klassifikator = registry
+                    .get(name)
+                    .ok_or_else(|| ConcatError::Parse(format!("unknown classifier: {}", name)))?;
+                for label in klassifikator.alle_labels() {
+                    let drin: Vec<i32> = zahlen
+                        .iter()
+                        .copied()
+                        .filter(|&zahl| klassifikator.labels(zahl).contains(&label))
+                        .collect();
+                    if !drin.is_empty() {
+                        gruppen.insert(label.to_string(), drin);
+                    }
+                }
+            }
+            Self::Zaehlung => {
+                let zaehlungen = zaehlungen_bis(cache, zahlen.iter().copied().max().unwrap_or(0));
+                let mut nach_zaehlung: OrderedDict<i32, Vec<i32>> = OrderedDict::new();
+                for &zahl in zahlen {
+                    if let Some(zaehlung) = zaehlungen.zeile_which_zaehlung(zahl) {
+                        nach_zaehlung.entry(zaehlung).or_default().push(zahl);
+                    }
+                }
+                nach_zaehlung.sort_keys();
+                gruppen.extend(nach_zaehlung.into_iter().map(|(z, n)| (z.to_string(), n)));
+            }
+        }
+        Ok(gruppen)
+    }
+}
+
+/// What a pivot cell shows of the texts of its group
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Zusammenfassung {
+    /// Each distinct text once, followed by the numbers it belongs to
+    #[default]
+    Verketten,
+    /// How many numbers of the group have a text
+    Zaehlen,
+}
+
+impl Zusammenfassung {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.trim() {
+            "verketten" => Ok(Self::Verketten),
+            "zaehlen" => Ok(Self::Zaehlen),
+            _ => Err(ConcatError::Parse(format!("unknown summary: {}", name))),
+        }
+    }
+
+    /// Cell text from the non-empty texts of a group and their numbers
+    fn zelle(&self, texte: &[(i32, &str)]) -> String {
+        match self {
+            Self::Zaehlen => texte.len().to_string(),
+            Self::Verketten => {
+                let mut gleiche: OrderedDict<&str, Vec<String>> = OrderedDict::new();
+                for (zahl, text) in texte {
+                    gleiche.entry(text).or_default().push(zahl.to_string());
+                }
+                gleiche
+                    .iter()
+                    .map(|(text, zahlen)| format!("{} ({})", text, zahlen.join(", ")))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            }
+        }
+    }
+}
+
+/// Grouping and summary of `--pivot=gestirn:zaehlen`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pivot {
+    pub gruppierung: Gruppierung,
+    pub zusammenfassung: Zusammenfassung,
+}
+
+impl Pivot {
+    /// "gestirn", "zaehlung:verketten" or "primkreativitaet:zaehlen"
+    pub fn parse(text: &str) -> Result<Self> {
+        let (gruppierung, zusammenfassung) = match text.split_once(':') {
+            Some((gruppierung, zusammenfassung)) => {
+                (gruppierung, Zusammenfassung::parse(zusammenfassung)?)
+            }
+            None => (text, Zusammenfassung::default()),
+        };
+        Ok(Self {
+            gruppierung: Gruppierung::parse(gruppierung)?,
+            zusammenfassung,
+        })
+    }
+}
+
+/// How a query lays out its result
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Anordnung {
+    /// Numbers as rows, properties as columns
+    #[default]
+    Zeilen,
+    /// Properties as rows, one column per number
+    Transponiert,
+    /// Properties as rows, one column per group of numbers
+    Pivot(Pivot),
+}
+
+impl Anordnung {
+    pub fn anwenden(&self, tabelle: TableData) -> Result<TableData> {
+        match self {
+            Self::Zeilen => Ok(tabelle),
+            Self::Transponiert => Ok(tabelle.transponiert()),
+            Self::Pivot(pivot) => tabelle.pivotieren(pivot),
+        }
+    }
+}
+
+impl TableData {
+    /// Properties as rows: the header becomes the first column, each number a column.
+    /// Rows are numbered by the column they were.
+    pub fn transponiert(&self) -> TableData {
+        let mut matrix = vec![self.kopf.clone()];
+        matrix.extend(self.zeilen.iter().map(|(_, zellen)| zellen.clone()));
+        let mut zeilen = Transpose::transpose(&matrix).into_iter().enumerate();
+        let kopf = zeilen.next().map(|(_, kopf)| kopf).unwrap_or_default();
+        let spalten = kopf
+            .iter()
+            .enumerate()
+            .map(|(index, zelle)| SpaltenInfo {
+                name: zelle.text.clone(),
+                quelle: match index {
+                    0 => SpaltenQuelle::Eigenschaft,
+                    _ => SpaltenQuelle::Nummer(self.zeilen[index - 1].0),
+                },
+            })
+            .collect();
+        TableData {
+            kopf,
+            zeilen: zeilen.map(|(index, z)| (index as i32, z)).collect(),
+            spalten,
+            gruppen: Vec::new(),
+            cache: self.cache.clone(),
+            sprache: self.sprache.clone(),
+            breite: self.breite,
+        }
+    }
+
+    /// Properties as rows and one column per group of numbers, the cells summarized.
+    /// The row of the number column lists (or counts) the numbers of each group.
+    pub fn pivotieren(&self, pivot: &Pivot) -> Result<TableData> {
+        let gruppen = pivot
+            .gruppierung
+            .gruppen_aus_cache(&self.zahlen(), self.cache.as_deref())?;
+        let zeilen_von: OrderedDict<i32, &Vec<Zelle>> =
+            self.zeilen.iter().map(|(zahl, z)| (*zahl, z)).collect();
+
+        let zeilen = self
+            .kopf
+            .iter()
+            .enumerate()
+            .map(|(spalte, titel)| {
+                let nummern = matches!(
+                    self.spalten.get(spalte).map(|s| &s.quelle),
+                    Some(SpaltenQuelle::Zahl)
+                );
+                let mut zellen = vec![titel.clone()];
+                zellen.extend(gruppen.values().map(|zahlen| {
+                    let texte: Vec<(i32, &str)> = zahlen
+                        .iter()
+                        .filter_map(|zahl| {
+                            let text = zeilen_von.get(zahl)?.get(spalte)?.text.trim();
+                            Some((*zahl, text)).filter(|(_, t)| !t.is_empty())
+                        })
+                        .collect();
+                    match (nummern, pivot.zusammenfassung) {
+                        (true, Zusammenfassung::Verketten) => {
+                            Zelle::new(texte.iter().map(|(_, t)| *t).collect::<Vec<_>>().join(", "))
+                        }
+                        _ => Zelle::new(pivot.zusammenfassung.zelle(&texte)),
+                    }
+                }));
+                (spalte as i32, zellen)
+            })
+            .collect();
+
+        let mut kopf = vec![Zelle::new(pivot.gruppierung.name())];
+        kopf.extend(gruppen.keys().map(Zelle::new));
+        let mut spalten = vec![SpaltenInfo {
+            name: pivot.gruppierung.name().to_string(),
+            quelle: SpaltenQuelle::Eigenschaft,
+        }];
+        spalten.extend(gruppen.keys().map(|name| SpaltenInfo {
+            name: name.clone(),
+            quelle: SpaltenQuelle::Gruppe(name.clone()),
+        }));
+        Ok(TableData {
+            kopf,
+            zeilen,
+            spalten,
+            gruppen: Vec::new(),
+            cache: self.cache.clone(),
+            sprache: self.sprache.clone(),
+            breite: self.breite,
+        })
+    }
+}
@@ -0,0 +1,128 @@
+//! Potenz module - tables of a^b = c relations, generalizing Concat.concat_mond_exponzieren_logarithmus_typ
+//!
+//! Base, exponent and result are all taken from the selected numbers, so `reta potenz 1-64`
+//! is the complete matrix of powers within 1 to 64, one row per cell of it that is a power.
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use crate::center::bereich_to_numbers2;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::Zelle;
+use crate::syntax::OutputSyntax;
+use crate::types::{OrderedDict, Table};
+
+/// One relation basis^exponent == ergebnis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Potenz {
+    pub basis: i32,
+    pub exponent: i32,
+    pub ergebnis: i32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PotenzTabelle {
+    pub zahlen: Vec<i32>,
+    pub beziehungen: Vec<Potenz>,
+    /// Text of the referenced rows, by number
+    pub texte: OrderedDict<i32, String>,
+}
+
+impl PotenzTabelle {
+    /// All a^b = c with a, b > 1 and a, b and c in the range expression, sorted by c and a
+    pub fn new(zahlen: &str, max_zahl: i32) -> Result<Self> {
+        let zahlen: Vec<i32> = bereich_to_numbers2(zahlen, false, max_zahl).into_iter().collect();
+        if zahlen.is_empty() {
+            return Err(ConcatError::Parse("no numbers for power table".to_string()));
+        }
+
+        let menge: BTreeSet<i32> = zahlen.iter().copied().collect();
+        let hoechste = zahlen.iter().copied().max().unwrap_or(0);
+        let mut beziehungen: Vec<Potenz> = Vec::new();
+        for &basis in menge.range(2..) {
+            // Exponents ascending, until the power leaves the selection
+            for &exponent in menge.range(2..) {
+                match basis.checked_pow(exponent as u32) {
+                    Some(ergebnis) if ergebnis <= hoechste => {
+                        if menge.contains(&ergebnis) {
+                            beziehungen.push(Potenz {
+                                basis,
+                                exponent,
+                                ergebnis,
+                            });
+                        }
+                    }
+                    _ => break,
+                }
+            }
+        }
+        beziehungen.sort_by_key(|p| (p.ergebnis, p.basis));
+
+        Ok(Self {
+            zahlen,
+            beziehungen,
+            texte: OrderedDict::new(),
+        })
+    }
+
+    /// Takes the cell texts of one column, where row n of `relitable` belongs to number n
+    pub fn texte_setzen(&mut self, relitable: &Table, spalte: usize) -> Result<()> {
+        if relitable.first().is_none_or(|kopf| spalte >= kopf.len()) {
+            return Err(ConcatError::InvalidColumnIndex(spalte));
+        }
+
+        for potenz in &self.beziehungen {
+            for zahl in [potenz.basis, potenz.exponent, potenz.ergebnis] {
+                if let Some(text) = relitable.get(zahl as usize).and_then(|row| row.get(spalte)) {
+                    let text = text.trim();
+                    if !text.is_empty() {
+                        self.texte.insert(zahl, text.to_string());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn zelle(&self, zahl: i32, farben: bool) -> Zelle {
+        let text = match self.texte.get(&zahl) {
+            Some(text) => format!("{}: {}", zahl, text),
+            None => zahl.to_string(),
+        };
+        if farben {
+            Zelle::farbig(text, zahl)
+        } else {
+            Zelle::new(text)
+        }
+    }
+
+    /// One row per relation: basis, exponent and result, each with its row text
+    pub fn zeilen(&self, farben: bool) -> (Vec<Zelle>, Vec<(i32, Vec<Zelle>)>) {
+        let kopf = vec![
+            Zelle::new("Basis"),
+            Zelle::new("Exponent"),
+            Zelle::new("Ergebnis"),
+        ];
+        let zeilen = self
+            .beziehungen
+            .iter()
+            .map(|potenz| {
+                (
+                    potenz.ergebnis,
+                    vec![
+                        self.zelle(potenz.basis, farben),
+                        self.zelle(potenz.exponent, farben),
+                        self.zelle(potenz.ergebnis, farben),
+                    ],
+                )
+            })
+            .collect();
+        (kopf, zeilen)
+    }
+
+    pub fn ausgeben(&self, syntax: &dyn OutputSyntax, farben: bool) -> Result<String> {
+        let (kopf, zeilen) = self.zeilen(farben);
+        syntax.format_table(&kopf, &zeilen)
+    }
+}
@@ -0,0 +1,249 @@
+//! Pruefung module - schema checks of the hand-edited reta CSV files, behind `reta check-data`
+//!
+//! Besides the rows of each file, the column registry is checked against the headers:
+//! every `spalte` of spalten.json must exist in its CSV and carry one of the column's names.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use num_rational::Ratio;
+
+use crate::datenquelle::Datenquelle;
+use crate::errors::Result;
+use crate::query::{kombi_zahlen, RELIGION_CSV};
+use crate::spalten::{SpaltenRegistry, SPALTEN_JSON};
+use crate::types::OrderedDict;
+
+/// What the first cell of every row below the header must be
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErsteSpalte {
+    /// A positive whole number
+    Zahl,
+    /// Numbers as in kombi13.csv, like "3/5", "(4)" or "-2"
+    Kombi,
+    /// A whole number or a fraction like "3/5"
+    Bruch,
+}
+
+/// Rules for one data file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schema {
+    pub erste_spalte: ErsteSpalte,
+    /// Row n must hold number n, as religion.csv rows are looked up by number
+    pub fortlaufend: bool,
+}
+
+impl Schema {
+    /// The schema of a file of the data directory by its name, None for files reta does not read
+    pub fn fuer_datei(name: &str) -> Option<Self> {
+        let schema = |erste_spalte, fortlaufend| Self {
+            erste_spalte,
+            fortlaufend,
+        };
+        match name {
+            RELIGION_CSV => Some(schema(ErsteSpalte::Zahl, true)),
+            "prim.csv" => Some(schema(ErsteSpalte::Zahl, false)),
+            "kombi13.csv" | "kombi15.csv" => Some(schema(ErsteSpalte::Kombi, false)),
+            _ if name.starts_with("bruch") && name.ends_with(".csv") => {
+                Some(schema(ErsteSpalte::Bruch, false))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// One problem in a data file; line and column count from 1, 0 means the whole file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Befund {
+    pub datei: PathBuf,
+    pub zeile: u64,
+    pub spalte: usize,
+    pub meldung: String,
+}
+
+impl fmt::Display for Befund {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.datei.display(),
+            self.zeile,
+            self.spalte,
+            self.meldung
+        )
+    }
+}
+
+/// Parses "3/5" or "4"; a zero denominator is an error
+pub fn bruch_lesen(text: &str) -> std::result::Result<Ratio<i32>, String> {
+    text.trim()
+        .parse::<Ratio<i32>>()
+        .map_err(|e| format!("invalid fraction {:?}: {}", text.trim(), e))
+}
+
+/// Checks one file against a schema. Reading errors of single rows are findings,
+/// only a file that cannot be opened is an error.
+pub fn datei_pruefen(pfad: &Path, schema: Schema) -> Result<Vec<Befund>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .has_headers(false)
+        .flexible(true)
+        .from_path(pfad)?;
+    let befund = |zeile: u64, spalte: usize, meldung: String| Befund {
+        datei: pfad.to_path_buf(),
+        zeile,
+        spalte,
+        meldung,
+    };
+
+    let mut befunde = Vec::new();
+    let mut breite = None;
+    let mut nummer = 0;
+    for ergebnis in reader.records() {
+        let record = match ergebnis {
+            Ok(record) => record,
+            Err(fehler) => {
+                let zeile = fehler.position().map_or(0, |p| p.line());
+                befunde.push(befund(zeile, 0, fehler.to_string()));
+                continue;
+            }
+        };
+        let zeile = record.position().map_or(0, |p| p.line());
+        // The header sets the column count
+        let Some(erwartet) = breite else {
+            breite = Some(record.len());
+            continue;
+        };
+        nummer += 1;
+        if record.len() != erwartet {
+            befunde.push(befund(
+                zeile,
+                record.len().min(erwartet) + 1,
+                format!("expected {} columns, found {}", erwartet, record.len()),
+            ));
+        }
+
+        let erste = record.get(0).unwrap_or_default();
+        let fehler = match schema.erste_spalte {
+            ErsteSpalte::Zahl => match erste.trim().parse::<u32>() {
+                Ok(zahl) if schema.fortlaufend && zahl != nummer => Some(format!(
+                    "row {} holds number {}, expected {}",
+                    nummer, zahl, nummer
+                )),
+                Ok(_) => None,
+                Err(_) => Some(format!("not a number: {:?}", erste.trim())),
+            },
+            ErsteSpalte::Kombi => kombi_zahlen(erste).err().map(|e| e.to_string()),
+            ErsteSpalte::Bruch => bruch_lesen(erste).err(),
+        };
+        if let Some(meldung) = fehler {
+            befunde.push(befund(zeile, 1, meldung));
+        }
+    }
+    if breite.is_none() {
+        befunde.push(befund(0, 0, "empty file".to_string()));
+    }
+    Ok(befunde)
+}
+
+/// Checks each column of the registry with a source file and index against that file's
+/// header: the index must be within it and the header one of the column's id, names or aliases
+pub fn spalten_pruefen(registry: &SpaltenRegistry, quelle: &Datenquelle) -> Result<Vec<Befund>> {
+    let mut koepfe: OrderedDict<String, Option<csv::StringRecord>> = OrderedDict::new();
+    let mut befunde = Vec::new();
+    for definition in registry.definitionen() {
+        let (Some(datei), Some(nummer)) = (&definition.quelle, definition.spalte) else {
+            continue;
+        };
+        let pfad = quelle.pfad(datei);
+        if !koepfe.contains_key(datei) {
+            koepfe.insert(datei.clone(), kopf_lesen(&pfad)?);
+        }
+        // Missing files and unreadable headers are findings of their own
+        let Some(Some(kopf)) = koepfe.get(datei) else {
+            continue;
+        };
+        let befund = |spalte: usize, meldung: String| Befund {
+            datei: pfad.clone(),
+            zeile: 1,
+            spalte,
+            meldung,
+        };
+        match kopf.get(nummer).map(str::trim) {
+            None => befunde.push(befund(
+                0,
+                format!(
+                    "column {} is number {}, the header has {} columns",
+                    definition.id,
+                    nummer,
+                    kopf.len()
+                ),
+            )),
+            Some(text) => {
+                let passt = std::iter::once(&definition.id)
+                    .chain(definition.namen.values())
+                    .chain(&definition.aliase)
+                    .any(|name| name.trim().to_lowercase() == text.to_lowercase());
+                if !passt {
+                    befunde.push(befund(
+                        nummer + 1,
+                        format!("column {} has the header {:?}", definition.id, text),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(befunde)
+}
+
+/// The first row of a file, None if it is missing or the row cannot be read
+fn kopf_lesen(pfad: &Path) -> Result<Option<csv::StringRecord>> {
+    if !pfad.is_file() {
+        return Ok(None);
+    }
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .has_headers(false)
+        .flexible(true)
+        .from_path(pfad)?;
+    Ok(reader.records().next().and_then(|kopf| kopf.ok()))
+}
+
+/// Checks every file of the data directory reta reads; religion.csv must exist
+pub fn ordner_pruefen(datenordner: &Path) -> Result<Vec<Befund>> {
+    quelle_pruefen(&Datenquelle::ordner(datenordner))
+}
+
+/// The same with the files a profile swaps in, checked by the name reta asks for
+pub fn quelle_pruefen(quelle: &Datenquelle) -> Result<Vec<Befund>> {
+    let mut dateien = quelle.csv_dateien()?;
+    dateien
+        .entry(RELIGION_CSV.to_string())
+        .or_insert_with(|| quelle.pfad(RELIGION_CSV));
+    let mut befunde = Vec::new();
+    for (name, pfad) in dateien {
+        let Some(schema) = Schema::fuer_datei(&name) else {
+            continue;
+        };
+        if pfad.is_file() {
+            befunde.extend(datei_pruefen(&pfad, schema)?);
+        } else {
+            befunde.push(Befund {
+                datei: pfad,
+                zeile: 0,
+                spalte: 0,
+                meldung: "file missing".to_string(),
+            });
+        }
+    }
+    match quelle.spalten_registry() {
+        Ok(registry) => befunde.extend(spalten_pruefen(&registry, quelle)?),
+        Err(fehler) => befunde.push(Befund {
+            datei: quelle.pfad(SPALTEN_JSON),
+            zeile: 0,
+            spalte: 0,
+            meldung: fehler.to_string(),
+        }),
+    }
+    Ok(befunde)
+}
@@ -0,0 +1,890 @@
+//! Query module - typed queries against the reta CSV data, executed to TableData before rendering
+
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cache::{zaehlungen_bis, MetadatenCache};
+use crate::center::{bereich_to_numbers2, csv_lesen};
+use crate::classifier::klassifikatoren;
+use crate::concat::Concat;
+use crate::datenquelle::Datenquelle;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{ShellSyntax, Zelle};
+use crate::lib4tables_enum::{SpaltenTypKey, ST};
+use crate::pivot::Anordnung;
+use crate::sortierung::{sortieren, Schluessel, Sortierung};
+use crate::spalten::{SpaltenDefinition, SpaltenRegistry};
+use crate::spaltenwahl::{Auswahl, Erklaerung, SpaltenAuswahl, Term, Vorlage};
+use crate::strukturiert::StrukturierteTabelle;
+use crate::suche::{zusammenfassen, Suche};
+use crate::syntax::{Gruppe, OutputSyntax};
+use crate::types::{OrderedDict, Table, Tables, HOECHSTE_ZEILE};
+use crate::zeilen::{zeilen_filtern, Zeilenbedingung};
+
+/// Main table of the data directory; row n belongs to number n
+pub const RELIGION_CSV: &str = "religion.csv";
+
+/// Concat generators appending columns, in the order the former Program ran them
+const GENERATOREN: [&str; 5] = [
+    "concat_love_polygon",
+    "concat_gleichheit_freiheit_dominieren",
+    "concat_geist_emotion_energie_materie_topologie",
+    "concat_prim_creativity_type",
+    "concat_mond_exponzieren_logarithmus_typ",
+];
+
+/// A row range like "1-10,v7,-5", see `bereich_to_numbers2`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeExpr(pub String);
+
+impl RangeExpr {
+    pub fn zahlen(&self, max_zahl: i32) -> BTreeSet<i32> {
+        bereich_to_numbers2(&self.0, false, max_zahl)
+    }
+}
+
+impl From<&str> for RangeExpr {
+    fn from(bereich: &str) -> Self {
+        Self(bereich.to_string())
+    }
+}
+
+impl From<String> for RangeExpr {
+    fn from(bereich: String) -> Self {
+        Self(bereich)
+    }
+}
+
+/// A column of a CSV, by number or by its heading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Spalte {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for Spalte {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<&str> for Spalte {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<String> for Spalte {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl Spalte {
+    /// Column number in a table whose row 0 holds the headings
+    pub fn aufloesen(&self, tabelle: &Table) -> Result<usize> {
+        let kopf = tabelle.first().map(Vec::as_slice).unwrap_or_default();
+        match self {
+            Self::Index(index) if *index < kopf.len() => Ok(*index),
+            Self::Index(index) => Err(ConcatError::InvalidColumnIndex(*index)),
+            Self::Name(name) => kopf
+                .iter()
+                .position(|k| k.trim() == name)
+                .or_else(|| {
+                    kopf.iter()
+                        .position(|k| k.trim().eq_ignore_ascii_case(name))
+                })
+                .ok_or_else(|| ConcatError::Parse(format!("unknown column: {}", name))),
+        }
+    }
+}
+
+/// The kombi tables joined onto religion.csv
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kombi {
+    /// kombi13.csv, `--galaxie=`
+    Galaxie,
+    /// kombi15.csv, `--universum=`
+    Universum,
+}
+
+impl Kombi {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "galaxie" => Some(Self::Galaxie),
+            "universum" => Some(Self::Universum),
+            _ => None,
+        }
+    }
+
+    pub fn datei(&self) -> &'static str {
+        match self {
+            Self::Galaxie => "kombi13.csv",
+            Self::Universum => "kombi15.csv",
+        }
+    }
+}
+
+/// Numbers of the first column of a kombi CSV, like "3/5", "(4)" or "-2";
+/// equivalent to kombi_numbers_correct_test_and_set
+pub fn kombi_zahlen(text: &str) -> Result<Vec<i32>> {
+    let text = text.trim();
+    if let Some(innen) = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        return kombi_zahlen(innen);
+    }
+    if let Ok(zahl) = text.parse::<i32>() {
+        return Ok(vec![zahl.abs()]);
+    }
+    if text.contains('/') {
+        let mut zahlen = Vec::new();
+        for teil in text.split('/') {
+            zahlen.extend(kombi_zahlen(teil)?);
+        }
+        return Ok(zahlen);
+    }
+    Err(ConcatError::Parse(format!(
+        "invalid kombi number: {}",
+        text
+    )))
+}
+
+/// The CSV tables a query reads, with the column registry describing them
+#[derive(Debug, Clone, Default)]
+pub struct Daten {
+    pub religion: Table,
+    pub kombis: OrderedDict<Kombi, Table>,
+    pub spalten: SpaltenRegistry,
+    /// Where the data was read from; None for tables built in memory
+    pub quelle: Option<Datenquelle>,
+}
+
+impl Daten {
+    /// With the built-in column registry
+    pub fn new(religion: Table) -> Self {
+        Self {
+            religion,
+            kombis: OrderedDict::new(),
+            spalten: SpaltenRegistry::standard().clone(),
+            quelle: None,
+        }
+    }
+
+    /// religion.csv and, where present, kombi13.csv and kombi15.csv of a data directory
+    pub fn laden(datenordner: &Path) -> Result<Self> {
+        Self::aus_quelle(&Datenquelle::ordner(datenordner))
+    }
+
+    /// The same through a resolved data directory, with the files of its profile
+    pub fn aus_quelle(quelle: &Datenquelle) -> Result<Self> {
+        let mut daten = Self::new(csv_lesen(&quelle.pfad(RELIGION_CSV))?);
+        daten.spalten = quelle.spalten_registry()?;
+        daten.quelle = Some(quelle.clone());
+        for kombi in [Kombi::Galaxie, Kombi::Universum] {
+            let pfad = quelle.pfad(kombi.datei());
+            if pfad.exists() {
+                daten.kombis.insert(kombi, csv_lesen(&pfad)?);
+            }
+        }
+        Ok(daten)
+    }
+
+    pub fn kombi_setzen(&mut self, kombi: Kombi, tabelle: Table) {
+        self.kombis.insert(kombi, tabelle);
+    }
+}
+
+/// Where a column of the result comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpaltenQuelle {
+    /// The row number
+    Zahl,
+    /// Generated by a classifier of the ClassifierRegistry
+    Klassifikator(String),
+    /// Column of religion.csv
+    Religion(usize),
+    /// Column appended by a Concat generator, by its registry id
+    Generiert(String),
+    /// Column of a kombi CSV
+    Kombi(Kombi, usize),
+    /// Headers of the columns a transposed or pivoted table was made of
+    Eigenschaft,
+    /// One number of a transposed table
+    Nummer(i32),
+    /// One group of numbers of a pivoted table
+    Gruppe(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpaltenInfo {
+    pub name: String,
+    pub quelle: SpaltenQuelle,
+}
+
+/// Result of a query: header, numbered rows and where each column comes from
+#[derive(Debug, Clone)]
+pub struct TableData {
+    pub kopf: Vec<Zelle>,
+    pub zeilen: Vec<(i32, Vec<Zelle>)>,
+    pub spalten: Vec<SpaltenInfo>,
+    /// Headings of row groups, from `--gruppiere=`
+    pub gruppen: Vec<Gruppe>,
+    /// Metadata cache of the query, for the countings of the rows
+    pub cache: Option<Arc<MetadatenCache>>,
+    /// Language of the headers, from `--sprache=`; None is German
+    pub sprache: Option<String>,
+    /// Width the structured outputs wrap cell lines at, from `--breite=`
+    pub breite: Option<usize>,
+}
+
+impl TableData {
+    pub fn rendern(&self, syntax: &dyn OutputSyntax) -> Result<String> {
+        syntax.format_daten(self)
+    }
+
+    pub fn strukturiert(&self) -> StrukturierteTabelle {
+        StrukturierteTabelle::aus_cache(&self.kopf, &self.zeilen, self.cache.as_deref(), self.breite)
+            .mit_gruppen(&self.gruppen)
+    }
+
+    pub fn zahlen(&self) -> Vec<i32> {
+        self.zeilen.iter().map(|(zahl, _)| *zahl).collect()
+    }
+}
+
+/// Which rows, columns and kombi columns to show, and how.
+/// `Query::rows("1-10").columns(["Religion"]).kombi(Kombi::Galaxie, [1]).output(syntax)`
+#[derive(Clone)]
+pub struct Query {
+    pub zeilen: RangeExpr,
+    /// Conditions of the `-zeilen` group; with any, the range is one more of them
+    pub bedingungen: Vec<Zeilenbedingung>,
+    pub spalten: SpaltenAuswahl,
+    pub kombis: Vec<(Kombi, Vec<Spalte>)>,
+    pub typen: Vec<String>,
+    pub klassen: Vec<String>,
+    /// New header by column, as `--umbenennen=`
+    pub namen: OrderedDict<String, String>,
+    /// Language of the headers of registry columns, as `--sprache=`
+    pub sprache: Option<String>,
+    /// Wrap width of the cell lines in structured output, as `--breite=`
+    pub breite: Option<usize>,
+    pub anordnung: Anordnung,
+    pub sortierung: Vec<Sortierung>,
+    pub gruppierung: Option<Schluessel>,
+    /// Row filters by cell text; a row must pass every one
+    pub suchen: Vec<Suche>,
+    pub ausgabe: Arc<dyn OutputSyntax>,
+    pub hoechste_zeile: i32,
+    /// Countings and number metadata computed once, see `MetadatenCache`
+    pub cache: Option<Arc<MetadatenCache>>,
+}
+
+impl Query {
+    pub fn rows(zeilen: impl Into<RangeExpr>) -> Self {
+        Self {
+            zeilen: zeilen.into(),
+            bedingungen: Vec::new(),
+            spalten: SpaltenAuswahl::default(),
+            kombis: Vec::new(),
+            typen: Vec::new(),
+            klassen: Vec::new(),
+            namen: OrderedDict::new(),
+            sprache: None,
+            breite: None,
+            anordnung: Anordnung::Zeilen,
+            sortierung: Vec::new(),
+            gruppierung: None,
+            suchen: Vec::new(),
+            ausgabe: Arc::new(ShellSyntax),
+            hoechste_zeile: HOECHSTE_ZEILE,
+            cache: None,
+        }
+    }
+
+    /// Row conditions as of `-zeilen`, see `zeilen_filtern`
+    pub fn bedingungen(mut self, bedingungen: impl IntoIterator<Item = Zeilenbedingung>) -> Self {
+        self.bedingungen.extend(bedingungen);
+        self
+    }
+
+    /// Columns of religion.csv; without any, all but the number column 0
+    pub fn columns<S: Into<Spalte>>(mut self, spalten: impl IntoIterator<Item = S>) -> Self {
+        let terme = spalten.into_iter().map(|s| Term::from(s.into()));
+        self.spalten.terme.extend(terme);
+        self
+    }
+
+    /// Removes columns from the selection, whichever parameter added them
+    pub fn ohne<S: Into<Spalte>>(mut self, spalten: impl IntoIterator<Item = S>) -> Self {
+        let terme = spalten.into_iter().map(|s| Term {
+            weg: true,
+            ..Term::from(s.into())
+        });
+        self.spalten.terme.extend(terme);
+        self
+    }
+
+    /// Adds every religion.csv column of the registry carrying the tag
+    pub fn gruppe(mut self, tag: ST) -> Self {
+        self.spalten.terme.push(Term {
+            auswahl: Auswahl::Gruppe(tag),
+            weg: false,
+        });
+        self
+    }
+
+    /// Removes every column carrying the tag
+    pub fn ohne_gruppe(mut self, tag: ST) -> Self {
+        self.spalten.terme.push(Term {
+            auswahl: Auswahl::Gruppe(tag),
+            weg: true,
+        });
+        self
+    }
+
+    /// Appends the terms of a parsed `--spalten=` list
+    pub fn auswahl(mut self, auswahl: SpaltenAuswahl) -> Self {
+        self.spalten.terme.extend(auswahl.terme);
+        self
+    }
+
+    /// Shows a column under another header; the column is named as in `--spalten=`
+    pub fn umbenennen(mut self, spalte: impl Into<String>, name: impl Into<String>) -> Self {
+        self.namen.insert(spalte.into(), name.into());
+        self
+    }
+
+    /// Headers of registry columns in this language, as far as the registry has them
+    pub fn sprache(mut self, sprache: impl Into<String>) -> Self {
+        self.sprache = Some(sprache.into());
+        self
+    }
+
+    /// Wraps the cell lines of JSON output at this display width, as the shell would
+    pub fn breite(mut self, breite: usize) -> Self {
+        self.breite = Some(breite);
+        self
+    }
+
+    /// Applies a saved layout: its columns are appended, its headers and language win
+    pub fn vorlage(mut self, vorlage: &Vorlage) -> Result<Self> {
+        self.spalten.erweitern(&vorlage.spalten)?;
+        for (spalte, name) in &vorlage.namen {
+            self.namen.insert(spalte.clone(), name.clone());
+        }
+        if vorlage.sprache.is_some() {
+            self.sprache = vorlage.sprache.clone();
+        }
+        Ok(self)
+    }
+
+    /// The query's columns and headers as a layout to save
+    pub fn als_vorlage(&self) -> Vorlage {
+        Vorlage {
+            spalten: self
+                .spalten
+                .terme
+                .iter()
+                .map(Term::text)
+                .collect::<Vec<_>>()
+                .join(","),
+            namen: self.namen.clone(),
+            sprache: self.sprache.clone(),
+        }
+    }
+
+    /// Which parameter added or removed each religion.csv column, as `--spalten-explain`
+    pub fn erklaeren(&self, daten: &Daten) -> Result<Erklaerung> {
+        self.spalten.erklaeren(daten)
+    }
+
+    /// Columns of a kombi CSV; without any, all of them
+    pub fn kombi<S: Into<Spalte>>(
+        mut self,
+        kombi: Kombi,
+        spalten: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.kombis
+            .push((kombi, spalten.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Keeps only numbers with one of these labels, as `--typ=`
+    pub fn typen<S: Into<String>>(mut self, typen: impl IntoIterator<Item = S>) -> Self {
+        self.typen.extend(typen.into_iter().map(Into::into));
+        self
+    }
+
+    /// Generated classifier columns, as `--klassen=`
+    pub fn klassen<S: Into<String>>(mut self, namen: impl IntoIterator<Item = S>) -> Self {
+        self.klassen.extend(namen.into_iter().map(Into::into));
+        self
+    }
+
+    /// Transposed or pivoted instead of numbers as rows, as `--transponiert` and `--pivot=`
+    pub fn anordnung(mut self, anordnung: Anordnung) -> Self {
+        self.anordnung = anordnung;
+        self
+    }
+
+    /// Row order by keys, as `--sortiere=`; the numbers break ties
+    pub fn sortiere(mut self, sortierung: impl IntoIterator<Item = Sortierung>) -> Self {
+        self.sortierung.extend(sortierung);
+        self
+    }
+
+    /// Rows grouped under headings by a key, as `--gruppiere=`
+    pub fn gruppiere(mut self, schluessel: Schluessel) -> Self {
+        self.gruppierung = Some(schluessel);
+        self
+    }
+
+    /// Keeps the rows whose cell text matches, as `--suche=` and `--regex=`
+    pub fn suche(mut self, suche: Suche) -> Self {
+        self.suchen.push(suche);
+        self
+    }
+
+    pub fn output(mut self, syntax: Arc<dyn OutputSyntax>) -> Self {
+        self.ausgabe = syntax;
+        self
+    }
+
+    pub fn hoechste_zeile(mut self, hoechste_zeile: i32) -> Self {
+        self.hoechste_zeile = hoechste_zeile;
+        self
+    }
+
+    /// Sorting, grouping and the row countings take their countings from the cache
+    pub fn cache(mut self, cache: Arc<MetadatenCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn ausfuehren(&self, daten: &Daten) -> Result<TableData> {
+        let registry = klassifikatoren();
+        let typen = registry.typen(&self.typen.join(","))?;
+        for name in &self.klassen {
+            if registry.get(name).is_none() {
+                return Err(ConcatError::Parse(format!("unknown classifier: {}", name)));
+            }
+        }
+
+        let mut spalten = vec![SpaltenInfo {
+            name: "n".to_string(),
+            quelle: SpaltenQuelle::Zahl,
+        }];
+        spalten.extend(self.klassen.iter().map(|name| SpaltenInfo {
+            name: name.clone(),
+            quelle: SpaltenQuelle::Klassifikator(name.clone()),
+        }));
+        let nummern = self.spalten.nummern(daten)?;
+        let (religion, generiert) = generieren(daten, &nummern, self.cache.as_ref())?;
+        spalten.extend(nummern.into_iter().map(|nummer| SpaltenInfo {
+            name: kopf_text(&daten.religion, nummer),
+            quelle: SpaltenQuelle::Religion(nummer),
+        }));
+        spalten.extend(generiert.iter().map(|(id, &nummer)| SpaltenInfo {
+            name: kopf_text(&religion, nummer),
+            quelle: SpaltenQuelle::Generiert(id.clone()),
+        }));
+
+        // Texts of every kombi column by number
+        let mut kombi_zellen: OrderedDict<(Kombi, usize), OrderedDict<i32, Vec<String>>> =
+            OrderedDict::new();
+        for (kombi, kombi_spalten) in &self.kombis {
+            let tabelle = daten.kombis.get(kombi).ok_or_else(|| {
+                ConcatError::Parse(format!("kombi table not loaded: {}", kombi.datei()))
+            })?;
+            let nummern = spalten_nummern(tabelle, kombi_spalten)?;
+            for zeile in tabelle.iter().skip(1) {
+                let Some(erste) = zeile.first().map(|e| e.trim()).filter(|e| !e.is_empty()) else {
+                    continue;
+                };
+                for zahl in kombi_zahlen(erste)? {
+                    for &nummer in &nummern {
+                        if let Some(text) = zeile
+                            .get(nummer)
+                            .map(|t| t.trim())
+                            .filter(|t| !t.is_empty())
+                        {
+                            kombi_zellen
+                                .entry((*kombi, nummer))
+                                .or_default()
+                                .entry(zahl)
+                                .or_default()
+                                .push(format!("({}) {} ({})", erste, text, erste));
+                        }
+                    }
+                }
+            }
+            spalten.extend(nummern.into_iter().map(|nummer| SpaltenInfo {
+                name: kopf_text(tabelle, nummer),
+                quelle: SpaltenQuelle::Kombi(*kombi, nummer),
+            }));
+        }
+
+        let mut zeilen: Vec<(i32, Vec<Zelle>)> = self
+            .zeilen_zahlen()
+            .into_iter()
+            .filter(|&zahl| (zahl as usize) < daten.religion.len())
+            .filter(|&zahl| registry.passt(zahl, &typen))
+            .map(|zahl| {
+                let zellen = spalten
+                    .iter()
+                    .map(|spalte| match &spalte.quelle {
+                        SpaltenQuelle::Zahl => Zelle::farbig(zahl.to_string(), zahl),
+                        SpaltenQuelle::Klassifikator(name) => {
+                            Zelle::new(registry.zelle(name, zahl).unwrap_or_default())
+                        }
+                        SpaltenQuelle::Religion(nummer) => Zelle::new(
+                            religion[zahl as usize]
+                                .get(*nummer)
+                                .map(|t| t.trim())
+                                .unwrap_or_default(),
+                        ),
+                        SpaltenQuelle::Generiert(id) => Zelle::new(
+                            generiert
+                                .get(id)
+                                .and_then(|&nummer| religion[zahl as usize].get(nummer))
+                                .map(|t| t.trim())
+                                .unwrap_or_default(),
+                        ),
+                        SpaltenQuelle::Kombi(kombi, nummer) => Zelle::new(
+                            kombi_zellen
+                                .get(&(*kombi, *nummer))
+                                .and_then(|texte| texte.get(&zahl))
+                                .map(|texte| texte.join("; "))
+                                .unwrap_or_default(),
+                        ),
+                        // Only in transposed and pivoted tables
+                        SpaltenQuelle::Eigenschaft
+                        | SpaltenQuelle::Nummer(_)
+                        | SpaltenQuelle::Gruppe(_) => Zelle::default(),
+                    })
+                    .collect();
+                (zahl, zellen)
+            })
+            .collect();
+        // Sorting and grouping lock the registry again; holding this guard meanwhile
+        // deadlocks as soon as a classifier_registrieren waits for the write lock
+        drop(registry);
+
+        self.filtern(daten, &spalten, &mut zeilen)?;
+        let gruppen = self.sortieren(daten, &spalten, &mut zeilen)?;
+        self.anordnung.anwenden(TableData {
+            kopf: self.kopf(daten, &spalten)?,
+            zeilen,
+            spalten,
+            gruppen,
+            cache: self.cache.clone(),
+            sprache: self.sprache.clone(),
+            breite: self.breite,
+        })
+    }
+
+    /// Numbers of the range, or of the row conditions with the range as one of them
+    fn zeilen_zahlen(&self) -> BTreeSet<i32> {
+        if self.bedingungen.is_empty() {
+            return self.zeilen.zahlen(self.hoechste_zeile);
+        }
+        let mut bedingungen = self.bedingungen.clone();
+        if !self.zeilen.0.trim().is_empty() {
+            bedingungen.push(Zeilenbedingung::Bereich(self.zeilen.0.clone()));
+        }
+        let zaehlungen = zaehlungen_bis(self.cache.as_deref(), self.hoechste_zeile);
+        zeilen_filtern(&bedingungen, self.hoechste_zeile, &zaehlungen)
+    }
+
+    /// Applies the searches on the rows left by range and types. Columns of the result are
+    /// searched in their cells; religion.csv columns not shown are searched in the CSV.
+    fn filtern(
+        &self,
+        daten: &Daten,
+        spalten: &[SpaltenInfo],
+        zeilen: &mut Vec<(i32, Vec<Zelle>)>,
+    ) -> Result<()> {
+        // Each searched column: its position in the result, else its religion.csv column
+        let gesucht = self
+            .suchen
+            .iter()
+            .map(|suche| -> Result<Vec<(Option<usize>, Option<usize>)>> {
+                if suche.spalten.is_empty() {
+                    return Ok(spalten
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, s)| s.quelle != SpaltenQuelle::Zahl)
+                        .map(|(position, _)| (Some(position), None))
+                        .collect());
+                }
+                suche
+                    .spalten
+                    .iter()
+                    .map(|spalte| match spalten_position(daten, spalten, spalte) {
+                        Some(position) => Ok((Some(position), None)),
+                        None => religion_nummer(daten, spalte)
+                            .map(|nummer| (None, Some(nummer))),
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        zeilen.retain_mut(|(zahl, zellen)| {
+            let mut markiert: Vec<(usize, Vec<Range<usize>>)> = Vec::new();
+            for (suche, gesucht) in self.suchen.iter().zip(&gesucht) {
+                let mut gefunden = false;
+                for &(position, nummer) in gesucht {
+                    let text = match (position, nummer) {
+                        (Some(position), _) => zellen[position].text.as_str(),
+                        (None, Some(nummer)) => daten.religion[*zahl as usize]
+                            .get(nummer)
+                            .map(|t| t.trim())
+                            .unwrap_or_default(),
+                        (None, None) => "",
+                    };
+                    if !suche.passt(text) {
+                        continue;
+                    }
+                    gefunden = true;
+                    if let (Some(position), true) = (position, suche.hervorheben) {
+                        markiert.push((position, suche.treffer(text)));
+                    }
+                }
+                if !gefunden {
+                    return false;
+                }
+            }
+            for (position, treffer) in markiert {
+                let zelle = &mut zellen[position];
+                zelle.treffer.extend(treffer);
+                zelle.treffer = zusammenfassen(std::mem::take(&mut zelle.treffer));
+            }
+            true
+        });
+        Ok(())
+    }
+
+    /// Applies `--gruppiere=` and `--sortiere=`; rows stay in numeric order without them
+    fn sortieren(
+        &self,
+        daten: &Daten,
+        spalten: &[SpaltenInfo],
+        zeilen: &mut Vec<(i32, Vec<Zelle>)>,
+    ) -> Result<Vec<Gruppe>> {
+        if self.gruppierung.is_none() && self.sortierung.is_empty() {
+            return Ok(Vec::new());
+        }
+        // Column keys go by their position in the result and are named by its header
+        let aufloesen = |schluessel: &Schluessel| -> Result<(Schluessel, Option<usize>)> {
+            let Schluessel::Spalte(spalte) = schluessel else {
+                return Ok((schluessel.clone(), None));
+            };
+            let position = spalten_position(daten, spalten, spalte).ok_or_else(|| {
+                ConcatError::Parse(format!("sort column not in the table: {}", schluessel.name()))
+            })?;
+            let name = Spalte::Name(spalten[position].name.clone());
+            Ok((Schluessel::Spalte(name), Some(position)))
+        };
+        let gruppierung = self.gruppierung.as_ref().map(aufloesen).transpose()?;
+        let sortierung = self
+            .sortierung
+            .iter()
+            .map(|s| {
+                let (schluessel, position) = aufloesen(&s.schluessel)?;
+                Ok((
+                    Sortierung {
+                        schluessel,
+                        absteigend: s.absteigend,
+                    },
+                    position,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sortierung: Vec<_> = sortierung.iter().map(|(s, p)| (s, *p)).collect();
+        Ok(sortieren(
+            zeilen,
+            gruppierung.as_ref().map(|(s, p)| (s, *p)),
+            &sortierung,
+            self.cache.as_deref(),
+        ))
+    }
+
+    /// Header cells: renamed, else in the chosen language, else as in the CSV. Each keeps
+    /// the registry id (or the CSV header) and tags of its column as HTML classes.
+    fn kopf(&self, daten: &Daten, spalten: &[SpaltenInfo]) -> Result<Vec<Zelle>> {
+        let registry = &daten.spalten;
+        let mut namen: Vec<Option<&str>> = vec![None; spalten.len()];
+        for (spalte, name) in &self.namen {
+            let gemeint = spalte
+                .parse::<usize>()
+                .map_or_else(|_| Spalte::from(spalte.as_str()), Spalte::Index);
+            let position = spalten_position(daten, spalten, &gemeint).ok_or_else(|| {
+                ConcatError::Parse(format!("renamed column not in the table: {}", spalte))
+            })?;
+            namen[position] = Some(name);
+        }
+        Ok(spalten
+            .iter()
+            .zip(namen)
+            .map(|(spalte, name)| {
+                let definition = match &spalte.quelle {
+                    SpaltenQuelle::Religion(nummer) => registry
+                        .vom_typ(SpaltenTypKey::Ordinary)
+                        .into_iter()
+                        .find(|d| d.spalte == Some(*nummer)),
+                    SpaltenQuelle::Generiert(id) => registry.get(id),
+                    _ => None,
+                };
+                let text = match (name, definition, &self.sprache) {
+                    (Some(name), _, _) => name.to_string(),
+                    (None, Some(d), Some(sprache)) => d.name(sprache).to_string(),
+                    _ => spalte.name.clone(),
+                };
+                let zelle = Zelle::new(spalte.name.clone());
+                match definition {
+                    Some(d) => zelle.mit_parameter(d.id.clone(), d.tags.clone()),
+                    None => zelle.mit_parameter(spalte.name.clone(), Vec::new()),
+                }
+                .umbenannt(text)
+            })
+            .collect())
+    }
+
+    /// Executes and renders through the query's output format
+    pub fn rendern(&self, daten: &Daten) -> Result<String> {
+        self.ausfuehren(daten)?.rendern(self.ausgabe.as_ref())
+    }
+}
+
+/// Position in the result of a column named by header, or by number, id or alias of religion.csv
+fn spalten_position(daten: &Daten, spalten: &[SpaltenInfo], gemeint: &Spalte) -> Option<usize> {
+    if let Spalte::Name(name) = gemeint {
+        if let Some(position) = spalten.iter().position(|s| &s.name == name) {
+            return Some(position);
+        }
+    }
+    let nummer = religion_nummer(daten, gemeint).ok()?;
+    spalten
+        .iter()
+        .position(|s| s.quelle == SpaltenQuelle::Religion(nummer))
+}
+
+/// Selected columns of a table; none selected means all but column 0
+fn spalten_nummern(tabelle: &Table, spalten: &[Spalte]) -> Result<Vec<usize>> {
+    if spalten.is_empty() {
+        let breite = tabelle.first().map_or(0, Vec::len);
+        return Ok((1..breite).collect());
+    }
+    spalten
+        .iter()
+        .map(|spalte| spalte.aufloesen(tabelle))
+        .collect()
+}
+
+/// A column of religion.csv; names not in the header are also looked up in the column registry,
+/// where a generated column stands for the column whose selection generates it
+pub(crate) fn religion_nummer(daten: &Daten, spalte: &Spalte) -> Result<usize> {
+    let breite = daten.religion.first().map_or(0, Vec::len);
+    spalte.aufloesen(&daten.religion).or_else(|fehler| match spalte {
+        Spalte::Name(name) => daten
+            .spalten
+            .finden(name)
+            .and_then(|d| match (d.typ, &d.parameter) {
+                (SpaltenTypKey::Generated1, Some(parameter)) => daten.spalten.get(parameter),
+                _ => Some(d),
+            })
+            .filter(|d| d.typ == SpaltenTypKey::Ordinary)
+            .and_then(|d| d.spalte)
+            .filter(|&nummer| nummer < breite)
+            .ok_or(fehler),
+        Spalte::Index(_) => Err(fehler),
+    })
+}
+
+/// religion.csv after the Concat generators of the selected columns: "vervielfache_zeile"
+/// columns get the texts of their divisors' rows, and each generated column whose
+/// `parameter` is selected is appended. Returns the registry id and index of each appended
+/// column; without any generator to run the table is borrowed as it is.
+fn generieren<'a>(
+    daten: &'a Daten,
+    nummern: &[usize],
+    cache: Option<&Arc<MetadatenCache>>,
+) -> Result<(Cow<'a, Table>, OrderedDict<String, usize>)> {
+    let registry = &daten.spalten;
+    let gewaehlt = |id: &str| registry.nummer(id).is_ok_and(|n| nummern.contains(&n));
+    let vervielfachen = registry
+        .mit_generator("vervielfache_zeile")
+        .iter()
+        .any(|d| gewaehlt(&d.id));
+    let erzeugt: Vec<(&str, Vec<&SpaltenDefinition>)> = GENERATOREN
+        .iter()
+        .map(|&generator| {
+            let definitionen = registry
+                .mit_generator(generator)
+                .into_iter()
+                .filter(|d| d.typ == SpaltenTypKey::Generated1)
+                .filter(|d| d.parameter.as_deref().is_some_and(gewaehlt))
+                .collect::<Vec<_>>();
+            (generator, definitionen)
+        })
+        .filter(|(_, definitionen)| !definitionen.is_empty())
+        .collect();
+    if !vervielfachen && erzeugt.is_empty() {
+        return Ok((Cow::Borrowed(&daten.religion), OrderedDict::new()));
+    }
+
+    // Generators append to the end of each row, so short rows are filled up to the header
+    let breite = |tabelle: &Table| tabelle.first().map_or(0, Vec::len);
+    let mut tabelle = daten.religion.clone();
+    let vanilla = breite(&tabelle);
+    for zeile in &mut tabelle {
+        zeile.resize(vanilla.max(zeile.len()), String::new());
+    }
+    let tables = Tables {
+        last_line_number: tabelle.len().saturating_sub(1),
+        spalten_vanilla_amount: vanilla,
+        ..Tables::default()
+    };
+    // These generators read no data files, so tables built in memory need no directory
+    let quelle = daten.quelle.clone().unwrap_or_else(|| Datenquelle::ordner(Path::new("")));
+    let mut concat = Concat::mit_spalten(tables, quelle, registry.clone());
+    if let Some(cache) = cache {
+        concat = concat.mit_metadaten(Arc::clone(cache));
+    }
+    let mut spalten: HashSet<usize> = nummern.iter().copied().collect();
+    if vervielfachen {
+        concat.concat_vervielfache_zeile(&mut tabelle, &mut spalten)?;
+    }
+    let mut generiert = OrderedDict::new();
+    for (generator, definitionen) in erzeugt {
+        let vorher = breite(&tabelle);
+        match generator {
+            "concat_love_polygon" => concat.concat_love_polygon(&mut tabelle, &mut spalten)?,
+            "concat_gleichheit_freiheit_dominieren" => {
+                concat.concat_gleichheit_freiheit_dominieren(&mut tabelle, &mut spalten)?
+            }
+            "concat_geist_emotion_energie_materie_topologie" => {
+                concat.concat_geist_emotion_energie_materie_topologie(&mut tabelle, &mut spalten)?
+            }
+            "concat_prim_creativity_type" => {
+                concat.concat_prim_creativity_type(&mut tabelle, &mut spalten)?
+            }
+            _ => concat.concat_mond_exponzieren_logarithmus_typ(&mut tabelle, &mut spalten)?,
+        }
+        // One new column per definition, in registry order
+        let ids = definitionen.into_iter().map(|d| d.id.clone());
+        generiert.extend(ids.zip(vorher..breite(&tabelle)));
+    }
+    Ok((Cow::Owned(tabelle), generiert))
+}
+
+fn kopf_text(tabelle: &Table, nummer: usize) -> String {
+    tabelle
+        .first()
+        .and_then(|kopf| kopf.get(nummer))
+        .map(|t| t.trim().to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,228 @@
+//! Sortierung module - row order and row groups by computed keys: number of prime factors,
+//! moon exponent, zaehlung, the label of a classifier like primkreativitaet, or a column's text
+//!
+//! `--gruppiere=primkreativitaet --sortiere=-primfaktoren` lists all primes, then all prime
+//! multiples, each group with the numbers of most prime factors first.
+
+use std::cmp::Ordering;
+
+use crate::cache::{zaehlungen_bis, MetadatenCache};
+use crate::center::primfaktoren;
+use crate::classifier::klassifikatoren;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{moon_number, Zelle};
+use crate::pivot::Gruppierung;
+use crate::query::Spalte;
+use crate::syntax::Gruppe;
+use crate::zahl::Zaehlungen;
+
+/// What rows are sorted or grouped by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schluessel {
+    /// Number of prime factors, counted with multiplicity
+    Primfaktoren,
+    /// Largest exponent a number is a power with; numbers that are no moon come last
+    MondExponent,
+    /// The zaehlung, or the first label of a classifier in the classifier's label order,
+    /// like "primkreativitaet" (prim_creativity) or "gestirn"; parsed as for `--pivot=`
+    Gruppierung(Gruppierung),
+    /// Text of a column of the table; empty cells come last
+    Spalte(Spalte),
+}
+
+/// Sort value of one row; its text is the heading of the row's group
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Wert {
+    pub leer: bool,
+    pub rang: i64,
+    pub text: String,
+}
+
+impl Wert {
+    fn zahl(rang: i64) -> Self {
+        Self {
+            leer: false,
+            rang,
+            text: rang.to_string(),
+        }
+    }
+
+    fn keiner() -> Self {
+        Self {
+            leer: true,
+            rang: 0,
+            text: "-".to_string(),
+        }
+    }
+}
+
+impl Schluessel {
+    /// "primfaktoren", "mondexponent", a `Gruppierung` ("zaehlung" or a classifier name),
+    /// else a column by number or name as in `--spalten=`
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        Ok(match text {
+            "" => return Err(ConcatError::Parse("empty sort key".to_string())),
+            "primfaktoren" => Self::Primfaktoren,
+            "mondexponent" => Self::MondExponent,
+            _ => match Gruppierung::finden(text) {
+                Some(gruppierung) => Self::Gruppierung(gruppierung),
+                None => Self::Spalte(
+                    text.parse::<usize>()
+                        .map_or_else(|_| Spalte::from(text), Spalte::Index),
+                ),
+            },
+        })
+    }
+
+    /// Name in group headings; columns go by their header
+    pub fn name(&self) -> String {
+        match self {
+            Self::Primfaktoren => "primfaktoren".to_string(),
+            Self::MondExponent => "mondexponent".to_string(),
+            Self::Gruppierung(gruppierung) => gruppierung.name().to_string(),
+            Self::Spalte(Spalte::Name(name)) => name.clone(),
+            Self::Spalte(Spalte::Index(index)) => index.to_string(),
+        }
+    }
+
+    /// The value of one row; `spalte` is the table position of a `Spalte` key
+    pub fn wert(
+        &self,
+        zahl: i32,
+        zellen: &[Zelle],
+        spalte: Option<usize>,
+        zaehlungen: &Zaehlungen,
+    ) -> Wert {
+        match self {
+            Self::Primfaktoren if zahl > 0 => Wert::zahl(primfaktoren(zahl).len() as i64),
+            Self::MondExponent => moon_number(zahl)
+                .1
+                .iter()
+                .max()
+                .map_or_else(Wert::keiner, |&e| Wert::zahl(e as i64 + 2)),
+            Self::Gruppierung(Gruppierung::Zaehlung) => zaehlungen
+                .zeile_which_zaehlung(zahl)
+                .map_or_else(Wert::keiner, |z| Wert::zahl(z as i64)),
+            Self::Gruppierung(Gruppierung::Klassifikator(name)) => {
+                let registry = klassifikatoren();
+                let Some(klassifikator) = registry.get(name) else {
+                    return Wert::keiner();
+                };
+                let alle = klassifikator.alle_labels();
+                klassifikator
+                    .labels(zahl)
+                    .first()
+                    .and_then(|label| {
+                        let rang = alle.iter().position(|l| l == label)?;
+                        Some(Wert {
+                            leer: false,
+                            rang: rang as i64,
+                            text: label.to_string(),
+                        })
+                    })
+                    .unwrap_or_else(Wert::keiner)
+            }
+            Self::Spalte(_) => {
+                let text = spalte
+                    .and_then(|s| zellen.get(s))
+                    .map(|z| z.text.trim())
+                    .unwrap_or_default();
+                match text {
+                    "" => Wert::keiner(),
+                    _ => Wert {
+                        leer: false,
+                        rang: 0,
+                        text: text.to_string(),
+                    },
+                }
+            }
+            Self::Primfaktoren => Wert::keiner(),
+        }
+    }
+}
+
+/// One key of `--sortiere=`; `-primfaktoren` sorts descending
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sortierung {
+    pub schluessel: Schluessel,
+    pub absteigend: bool,
+}
+
+impl Sortierung {
+    /// A comma separated list of keys, each optionally prefixed by `-`
+    pub fn parse_liste(text: &str) -> Result<Vec<Self>> {
+        text.split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| {
+                let (absteigend, schluessel) = match t.strip_prefix('-') {
+                    Some(rest) => (true, rest),
+                    None => (false, t),
+                };
+                Ok(Self {
+                    schluessel: Schluessel::parse(schluessel)?,
+                    absteigend,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Sorts rows stably, first by the group key, then by each sort key; rows without a
+/// value stay last either way. Returns the groups when a group key is given.
+/// The countings come from the cache when there is one.
+pub fn sortieren(
+    zeilen: &mut Vec<(i32, Vec<Zelle>)>,
+    gruppierung: Option<(&Schluessel, Option<usize>)>,
+    sortierung: &[(&Sortierung, Option<usize>)],
+    cache: Option<&MetadatenCache>,
+) -> Vec<Gruppe> {
+    let bis = zeilen.iter().map(|(zahl, _)| *zahl).max().unwrap_or(0);
+    let zaehlungen = zaehlungen_bis(cache, bis);
+    let werte = |zahl: i32, zellen: &[Zelle]| {
+        let gruppe = gruppierung.map(|(s, spalte)| s.wert(zahl, zellen, spalte, &zaehlungen));
+        let sortiert: Vec<(Wert, bool)> = sortierung
+            .iter()
+            .map(|(s, spalte)| {
+                let wert = s.schluessel.wert(zahl, zellen, *spalte, &zaehlungen);
+                (wert, s.absteigend)
+            })
+            .collect();
+        (gruppe, sortiert)
+    };
+
+    let mut bewertet: Vec<_> = zeilen
+        .drain(..)
+        .map(|(zahl, zellen)| (werte(zahl, &zellen), (zahl, zellen)))
+        .collect();
+    bewertet.sort_by(|((ga, sa), _), ((gb, sb), _)| {
+        ga.cmp(gb).then_with(|| {
+            sa.iter()
+                .zip(sb)
+                .map(
+                    |((a, absteigend), (b, _))| match (a.leer, b.leer, absteigend) {
+                        (false, false, true) => b.cmp(a),
+                        _ => a.cmp(b),
+                    },
+                )
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    });
+
+    let mut gruppen: Vec<Gruppe> = Vec::new();
+    for (position, ((gruppe, _), zeile)) in bewertet.into_iter().enumerate() {
+        if let (Some(wert), Some((schluessel, _))) = (gruppe, gruppierung) {
+            let titel = format!("{}: {}", schluessel.name(), wert.text);
+            if gruppen.last().map(|g| &g.titel) != Some(&titel) {
+                gruppen.push(Gruppe {
+                    ab: position,
+                    titel,
+                });
+            }
+        }
+        zeilen.push(zeile);
+    }
+    gruppen
+}
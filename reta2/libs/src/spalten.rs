@@ -0,0 +1,186 @@
+//! Spalten module - the column registry: ids, names, categories, tags and sources of all known columns
+//!
+//! A data directory or profile may bring its own `spalten.json` next to religion.csv, see
+//! `Datenquelle::spalten_registry`; the copy at the crate root is built in as the fallback.
+//! When religion.csv gains a column, only the `spalte` numbers there change; code looks
+//! columns up by id, and `reta check-data` compares them with the CSV headers.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables_enum::{SpaltenTypKey, ST};
+use crate::types::OrderedDict;
+
+/// File name of the registry in a data directory
+pub const SPALTEN_JSON: &str = "spalten.json";
+
+/// One entry of the registry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpaltenDefinition {
+    pub id: String,
+    /// Header by language, "de" and "en"
+    #[serde(default)]
+    pub namen: OrderedDict<String, String>,
+    /// Further names the column is found by, like short forms
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliase: Vec<String>,
+    pub typ: SpaltenTypKey,
+    #[serde(default)]
+    pub tags: Vec<ST>,
+    /// CSV file the column is read from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quelle: Option<String>,
+    /// Index in `quelle`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spalte: Option<usize>,
+    /// Concat method producing or rewriting the column
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator: Option<String>,
+    /// Id of the column whose selection asks for this generated one and whose header it takes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+    /// Id of the column the generated text is taken from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aus: Option<String>,
+}
+
+impl SpaltenDefinition {
+    /// Header in a language, falling back to German, then to the id
+    pub fn name(&self, sprache: &str) -> &str {
+        self.namen
+            .get(sprache)
+            .or_else(|| self.namen.get("de"))
+            .map_or(self.id.as_str(), String::as_str)
+    }
+
+    pub fn tag_menge(&self) -> HashSet<ST> {
+        self.tags.iter().copied().collect()
+    }
+}
+
+/// Column definitions by id, in file order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpaltenRegistry {
+    spalten: OrderedDict<String, SpaltenDefinition>,
+}
+
+static STANDARD: Lazy<SpaltenRegistry> = Lazy::new(|| {
+    SpaltenRegistry::aus_json(include_str!("../spalten.json"))
+        .expect("spalten.json is a valid column registry")
+});
+
+impl SpaltenRegistry {
+    /// An empty registry, see `standard` for the built-in columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The shared registry read from the built-in spalten.json
+    pub fn standard() -> &'static Self {
+        &STANDARD
+    }
+
+    /// Parses a JSON array of column definitions, rejecting duplicate ids
+    /// and references to ids that are not defined
+    pub fn aus_json(text: &str) -> Result<Self> {
+        let definitionen: Vec<SpaltenDefinition> = serde_json::from_str(text)
+            .map_err(|e| ConcatError::Parse(format!("column registry: {}", e)))?;
+        let mut registry = Self::new();
+        for definition in definitionen {
+            if registry.spalten.contains_key(&definition.id) {
+                return Err(ConcatError::Config(format!(
+                    "duplicate column id: {}",
+                    definition.id
+                )));
+            }
+            registry.register(definition);
+        }
+        for definition in registry.spalten.values() {
+            for verweis in [&definition.parameter, &definition.aus]
+                .into_iter()
+                .flatten()
+            {
+                if !registry.spalten.contains_key(verweis) {
+                    return Err(ConcatError::Config(format!(
+                        "column {} refers to unknown column {}",
+                        definition.id, verweis
+                    )));
+                }
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Reads a registry file like spalten.json
+    pub fn laden(pfad: &Path) -> Result<Self> {
+        if !pfad.exists() {
+            return Err(ConcatError::FileNotFound(pfad.to_path_buf()));
+        }
+        Self::aus_json(&std::fs::read_to_string(pfad)?)
+    }
+
+    /// Adds a column, replacing one with the same id
+    pub fn register(&mut self, definition: SpaltenDefinition) {
+        self.spalten.insert(definition.id.clone(), definition);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&SpaltenDefinition> {
+        self.spalten.get(id)
+    }
+
+    pub fn namen(&self) -> Vec<&str> {
+        self.spalten.keys().map(String::as_str).collect()
+    }
+
+    pub fn definitionen(&self) -> impl Iterator<Item = &SpaltenDefinition> {
+        self.spalten.values()
+    }
+
+    /// Looks up an id, or a header in any language or an alias, ignoring case
+    pub fn finden(&self, name: &str) -> Option<&SpaltenDefinition> {
+        let name = name.trim();
+        self.get(name).or_else(|| {
+            self.spalten.values().find(|d| {
+                d.id.eq_ignore_ascii_case(name)
+                    || d.namen
+                        .values()
+                        .chain(&d.aliase)
+                        .any(|n| n.to_lowercase() == name.to_lowercase())
+            })
+        })
+    }
+
+    /// The definition of an id, or an error naming it
+    pub fn definition(&self, id: &str) -> Result<&SpaltenDefinition> {
+        self.get(id)
+            .ok_or_else(|| ConcatError::Parse(format!("unknown column: {}", id)))
+    }
+
+    /// Index of a column in its source CSV
+    pub fn nummer(&self, id: &str) -> Result<usize> {
+        self.definition(id)?
+            .spalte
+            .ok_or_else(|| ConcatError::Config(format!("column {} has no index", id)))
+    }
+
+    pub fn tags(&self, id: &str) -> Result<HashSet<ST>> {
+        Ok(self.definition(id)?.tag_menge())
+    }
+
+    /// All columns made or rewritten by a generator, in file order
+    pub fn mit_generator(&self, generator: &str) -> Vec<&SpaltenDefinition> {
+        self.spalten
+            .values()
+            .filter(|d| d.generator.as_deref() == Some(generator))
+            .collect()
+    }
+
+    /// Columns of one category
+    pub fn vom_typ(&self, typ: SpaltenTypKey) -> Vec<&SpaltenDefinition> {
+        self.spalten.values().filter(|d| d.typ == typ).collect()
+    }
+}
@@ -0,0 +1,237 @@
+//! Spaltenwahl module - religion.csv column selection as set algebra: union of the positive
+//! parameters minus the negative ones, with groups by ST tag and an explanation per column
+//!
+//! `--spalten=@Galaxie,-Liebe,3` is every column tagged Galaxie and column 3, without Liebe.
+//! With only negative parameters, all columns are the start. A `Vorlage` saves such a
+//! selection together with renamed headers as a named layout in the config file.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::Zelle;
+use crate::lib4tables_enum::{SpaltenTypKey, ST};
+use crate::query::{religion_nummer, Daten, Spalte};
+use crate::syntax::OutputSyntax;
+use crate::types::{OrderedDict, Table};
+
+/// What one parameter selects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Auswahl {
+    Spalte(Spalte),
+    /// Every religion.csv column of the registry carrying the tag, `@Galaxie`
+    Gruppe(ST),
+    /// Every column but the number column, `*`
+    Alle,
+}
+
+/// One parameter, adding or (with `-`) removing its columns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+    pub auswahl: Auswahl,
+    pub weg: bool,
+}
+
+impl Term {
+    /// Parses "Religion", "3", "-Motiv", "@Galaxie", "-@Universum" or "*"
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        let (weg, rest) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest.trim()),
+            None => (false, text),
+        };
+        let auswahl = if rest == "*" {
+            Auswahl::Alle
+        } else if let Some(tag) = rest.strip_prefix('@') {
+            Auswahl::Gruppe(
+                ST::from_name(tag)
+                    .ok_or_else(|| ConcatError::Parse(format!("unknown column group: {}", tag)))?,
+            )
+        } else if let Ok(index) = rest.parse::<usize>() {
+            Auswahl::Spalte(Spalte::Index(index))
+        } else if rest.is_empty() {
+            return Err(ConcatError::Parse(format!("empty column: {:?}", text)));
+        } else {
+            Auswahl::Spalte(Spalte::Name(rest.to_string()))
+        };
+        Ok(Self { auswahl, weg })
+    }
+
+    /// The parameter as written on the command line
+    pub fn text(&self) -> String {
+        let auswahl = match &self.auswahl {
+            Auswahl::Spalte(Spalte::Index(index)) => index.to_string(),
+            Auswahl::Spalte(Spalte::Name(name)) => name.clone(),
+            Auswahl::Gruppe(tag) => format!("@{}", tag.name()),
+            Auswahl::Alle => "*".to_string(),
+        };
+        if self.weg {
+            format!("-{}", auswahl)
+        } else {
+            auswahl
+        }
+    }
+
+    /// Columns of the table this parameter stands for, in column order for groups
+    fn spalten(&self, daten: &Daten) -> Result<Vec<usize>> {
+        let breite = daten.religion.first().map_or(0, Vec::len);
+        Ok(match &self.auswahl {
+            Auswahl::Spalte(spalte) => vec![religion_nummer(daten, spalte)?],
+            Auswahl::Alle => (1..breite).collect(),
+            Auswahl::Gruppe(tag) => {
+                let mut nummern: Vec<usize> = daten
+                    .spalten
+                    .vom_typ(SpaltenTypKey::Ordinary)
+                    .into_iter()
+                    .filter(|d| d.tags.contains(tag))
+                    .filter_map(|d| d.spalte)
+                    .filter(|&nummer| nummer < breite)
+                    .collect();
+                nummern.sort_unstable();
+                nummern.dedup();
+                nummern
+            }
+        })
+    }
+}
+
+impl From<Spalte> for Term {
+    fn from(spalte: Spalte) -> Self {
+        Self {
+            auswahl: Auswahl::Spalte(spalte),
+            weg: false,
+        }
+    }
+}
+
+/// Parameters that added and removed one column
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Beitrag {
+    pub dazu: Vec<String>,
+    pub weg: Vec<String>,
+}
+
+impl Beitrag {
+    pub fn gewaehlt(&self) -> bool {
+        !self.dazu.is_empty() && self.weg.is_empty()
+    }
+}
+
+/// Every column any parameter touched, in the order they were first named
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Erklaerung {
+    pub spalten: OrderedDict<usize, Beitrag>,
+}
+
+impl Erklaerung {
+    /// The selected columns, in order
+    pub fn nummern(&self) -> Vec<usize> {
+        self.spalten
+            .iter()
+            .filter(|(_, beitrag)| beitrag.gewaehlt())
+            .map(|(&nummer, _)| nummer)
+            .collect()
+    }
+
+    /// One row per column: number, header, parameters adding and removing it, and the outcome
+    pub fn rendern(&self, tabelle: &Table, syntax: &dyn OutputSyntax) -> Result<String> {
+        let kopf: Vec<Zelle> = ["Nr", "Spalte", "dazu", "weg", "gewählt"]
+            .into_iter()
+            .map(Zelle::new)
+            .collect();
+        let zeilen: Vec<(i32, Vec<Zelle>)> = self
+            .spalten
+            .iter()
+            .map(|(&nummer, beitrag)| {
+                let name = tabelle
+                    .first()
+                    .and_then(|kopf| kopf.get(nummer))
+                    .map(|t| t.trim())
+                    .unwrap_or_default();
+                let zellen = vec![
+                    Zelle::new(nummer.to_string()),
+                    Zelle::new(name),
+                    Zelle::new(beitrag.dazu.join(", ")),
+                    Zelle::new(beitrag.weg.join(", ")),
+                    Zelle::new(if beitrag.gewaehlt() { "ja" } else { "nein" }),
+                ];
+                (nummer as i32, zellen)
+            })
+            .collect();
+        syntax.format_table(&kopf, &zeilen)
+    }
+}
+
+/// The terms of `--spalten=`, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpaltenAuswahl {
+    pub terme: Vec<Term>,
+}
+
+impl SpaltenAuswahl {
+    /// Parses a comma separated list of terms
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut auswahl = Self::default();
+        auswahl.erweitern(text)?;
+        Ok(auswahl)
+    }
+
+    pub fn erweitern(&mut self, text: &str) -> Result<()> {
+        for teil in text.split(',').filter(|t| !t.trim().is_empty()) {
+            self.terme.push(Term::parse(teil)?);
+        }
+        Ok(())
+    }
+
+    pub fn ist_leer(&self) -> bool {
+        self.terme.is_empty()
+    }
+
+    /// Which parameter added or removed each column. Without positive terms all
+    /// columns are the start, so "-Motiv" means everything but Motiv.
+    pub fn erklaeren(&self, daten: &Daten) -> Result<Erklaerung> {
+        let mut erklaerung = Erklaerung::default();
+        if self.terme.iter().all(|t| t.weg) {
+            let alle = Term {
+                auswahl: Auswahl::Alle,
+                weg: false,
+            };
+            for nummer in alle.spalten(daten)? {
+                erklaerung
+                    .spalten
+                    .entry(nummer)
+                    .or_default()
+                    .dazu
+                    .push(alle.text());
+            }
+        }
+        for term in &self.terme {
+            for nummer in term.spalten(daten)? {
+                let beitrag = erklaerung.spalten.entry(nummer).or_default();
+                if term.weg {
+                    beitrag.weg.push(term.text());
+                } else {
+                    beitrag.dazu.push(term.text());
+                }
+            }
+        }
+        Ok(erklaerung)
+    }
+
+    /// Union of the positive terms minus the negative ones
+    pub fn nummern(&self, daten: &Daten) -> Result<Vec<usize>> {
+        Ok(self.erklaeren(daten)?.nummern())
+    }
+}
+
+/// A reusable layout: the `--spalten=` list, headers to rename and the header language
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vorlage {
+    #[serde(default)]
+    pub spalten: String,
+    /// New header by column: header, registry id, alias or number as in `--spalten=`
+    #[serde(default)]
+    pub namen: OrderedDict<String, String>,
+    /// Language of the registry headers, like "en"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprache: Option<String>,
+}
@@ -0,0 +1,224 @@
+//! Strukturiert module - tables as JSON or JSON Lines with row and column metadata
+
+use serde::Serialize;
+
+use crate::breite::anzeige_breite;
+use crate::cache::{zaehlungen_bis, MetadatenCache};
+use crate::center::get_text_wrap_things;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::Zelle;
+use crate::lib4tables_enum::ST;
+use crate::query::TableData;
+use crate::syntax::{Gruppe, OutputSyntax, ZellenKontext};
+use crate::zahl::Zaehlungen;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSpalte {
+    pub index: usize,
+    pub name: String,
+    /// Main and sub parameter of the column, as in `generated_spalten_parameter`:
+    /// the parameter its header cell carries, see `Zelle::mit_parameter`, and the header in the data
+    pub parameter: Option<(String, String)>,
+    pub tags: Vec<ST>,
+    /// Header as in the data, when `name` is a renamed one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonZelle {
+    pub text: String,
+    /// The text as wrapped for display
+    pub zeilen: Vec<String>,
+    /// Byte ranges `[start, end)` of search matches in `text`, see `--hervorheben`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub treffer: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonZeile {
+    pub zeile: i32,
+    pub zaehlung: Option<i32>,
+    /// Heading of the group the row is in, see `--gruppiere=`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gruppe: Option<String>,
+    pub zellen: Vec<JsonZelle>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StrukturierteTabelle {
+    pub spalten: Vec<JsonSpalte>,
+    pub zeilen: Vec<JsonZeile>,
+}
+
+/// Splits at line breaks and, with a width, wraps each line as the shell output does
+pub fn umgebrochen(text: &str, breite: Option<usize>) -> Vec<String> {
+    text.replace("<br>", "\n")
+        .lines()
+        .flat_map(|zeile| match breite {
+            Some(breite) if breite > 0 && anzeige_breite(zeile) > breite => {
+                get_text_wrap_things(zeile, breite)
+            }
+            _ => vec![zeile.to_string()],
+        })
+        .collect()
+}
+
+impl StrukturierteTabelle {
+    pub fn new(
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        zaehlungen: &Zaehlungen,
+        breite: Option<usize>,
+    ) -> Self {
+        let spalten = kopf
+            .iter()
+            .enumerate()
+            .map(|(index, zelle)| JsonSpalte {
+                index,
+                name: zelle.text.clone(),
+                parameter: zelle.parameter.clone().map(|parameter| {
+                    (parameter, zelle.original.clone().unwrap_or_else(|| zelle.text.clone()))
+                }),
+                tags: zelle.tags.clone(),
+                original: zelle.original.clone(),
+            })
+            .collect();
+        let zeilen = zeilen
+            .iter()
+            .map(|(zeile, zellen)| JsonZeile {
+                zeile: *zeile,
+                zaehlung: zaehlungen.zeile_which_zaehlung(*zeile),
+                gruppe: None,
+                zellen: zellen
+                    .iter()
+                    .map(|zelle| JsonZelle {
+                        text: zelle.text.clone(),
+                        zeilen: umgebrochen(&zelle.text, breite),
+                        treffer: zelle.treffer.iter().map(|t| (t.start, t.end)).collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self { spalten, zeilen }
+    }
+
+    /// Counts the rows itself, for callers that have no Zaehlungen at hand
+    pub fn gezaehlt(kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)], breite: Option<usize>) -> Self {
+        Self::aus_cache(kopf, zeilen, None, breite)
+    }
+
+    /// Takes the countings from the metadata cache when it covers the rows
+    pub fn aus_cache(
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        cache: Option<&MetadatenCache>,
+        breite: Option<usize>,
+    ) -> Self {
+        let bis = zeilen.iter().map(|(zeile, _)| *zeile).max().unwrap_or(0);
+        Self::new(kopf, zeilen, &zaehlungen_bis(cache, bis), breite)
+    }
+
+    /// Puts each row into the last group starting at or before it
+    pub fn mit_gruppen(mut self, gruppen: &[Gruppe]) -> Self {
+        for (position, zeile) in self.zeilen.iter_mut().enumerate() {
+            zeile.gruppe = gruppen
+                .iter()
+                .rfind(|g| g.ab <= position)
+                .map(|g| g.titel.clone());
+        }
+        self
+    }
+
+    /// One JSON document with columns and rows
+    pub fn json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ConcatError::Parse(e.to_string()))
+    }
+
+    /// JSON Lines: the columns first, then one row per line
+    pub fn json_lines(&self) -> Result<String> {
+        let mut out = serde_json::to_string(&serde_json::json!({ "spalten": self.spalten }))
+            .map_err(|e| ConcatError::Parse(e.to_string()))?;
+        out.push('\n');
+        for zeile in &self.zeilen {
+            let json = serde_json::to_string(zeile).map_err(|e| ConcatError::Parse(e.to_string()))?;
+            out.push_str(&json);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+/// One JSON document, see StrukturierteTabelle
+pub struct JsonSyntax;
+/// JSON Lines: the columns, then one row per line
+pub struct JsonLinesSyntax;
+
+impl OutputSyntax for JsonSyntax {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        zelle.zelle.text.clone()
+    }
+
+    fn format_table(&self, kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> Result<String> {
+        StrukturierteTabelle::gezaehlt(kopf, zeilen, None)
+            .json()
+            .map(|json| json + "\n")
+    }
+
+    fn format_gruppiert(
+        &self,
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        gruppen: &[Gruppe],
+    ) -> Result<String> {
+        StrukturierteTabelle::gezaehlt(kopf, zeilen, None)
+            .mit_gruppen(gruppen)
+            .json()
+            .map(|json| json + "\n")
+    }
+
+    fn format_daten(&self, tabelle: &TableData) -> Result<String> {
+        tabelle
+            .strukturiert()
+            .json()
+            .map(|json| json + "\n")
+    }
+}
+
+impl OutputSyntax for JsonLinesSyntax {
+    fn name(&self) -> &'static str {
+        "jsonl"
+    }
+
+    fn aliase(&self) -> &'static [&'static str] {
+        &["jsonlines"]
+    }
+
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        zelle.zelle.text.clone()
+    }
+
+    fn format_table(&self, kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> Result<String> {
+        StrukturierteTabelle::gezaehlt(kopf, zeilen, None)
+            .json_lines()
+    }
+
+    fn format_gruppiert(
+        &self,
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        gruppen: &[Gruppe],
+    ) -> Result<String> {
+        StrukturierteTabelle::gezaehlt(kopf, zeilen, None)
+            .mit_gruppen(gruppen)
+            .json_lines()
+    }
+
+    fn format_daten(&self, tabelle: &TableData) -> Result<String> {
+        tabelle.strukturiert().json_lines()
+    }
+}
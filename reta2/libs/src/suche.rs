@@ -0,0 +1,85 @@
+//! Suche module - row filter by the text of cells: a substring or a regex in chosen columns,
+//! with the matches kept on the cells for highlighting
+//!
+//! `--suche=Liebe --suche-spalten=Religion` keeps the numbers whose Religion column mentions
+//! love; `--hervorheben` marks the matches in each output format.
+
+use std::ops::Range;
+
+use regex::Regex;
+
+use crate::errors::{ConcatError, Result};
+use crate::query::Spalte;
+
+/// One search of `--suche=` or `--regex=`; a row passes when a searched column matches
+#[derive(Debug, Clone)]
+pub struct Suche {
+    pub muster: Regex,
+    /// Columns searched; none means every column of the result but the number
+    pub spalten: Vec<Spalte>,
+    /// Whether matches in the shown cells are marked
+    pub hervorheben: bool,
+}
+
+impl Suche {
+    /// A substring, ignoring case
+    pub fn text(text: &str) -> Self {
+        let muster = Regex::new(&format!("(?i){}", regex::escape(text)))
+            .expect("an escaped text is a valid regex");
+        Self::mit_muster(muster)
+    }
+
+    /// A regex in the syntax of the regex crate; `(?i)` ignores case
+    pub fn regex(muster: &str) -> Result<Self> {
+        Regex::new(muster)
+            .map(Self::mit_muster)
+            .map_err(|fehler| ConcatError::Parse(format!("invalid regex {}: {}", muster, fehler)))
+    }
+
+    fn mit_muster(muster: Regex) -> Self {
+        Self {
+            muster,
+            spalten: Vec::new(),
+            hervorheben: false,
+        }
+    }
+
+    pub fn spalten<S: Into<Spalte>>(mut self, spalten: impl IntoIterator<Item = S>) -> Self {
+        self.spalten = spalten.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn hervorheben(mut self, hervorheben: bool) -> Self {
+        self.hervorheben = hervorheben;
+        self
+    }
+
+    /// Whether a text matches, also with an empty match like the one of `^$`
+    pub fn passt(&self, text: &str) -> bool {
+        self.muster.is_match(text)
+    }
+
+    /// Byte ranges of the non-empty matches in a text, the ones there is something to mark
+    pub fn treffer(&self, text: &str) -> Vec<Range<usize>> {
+        self.muster
+            .find_iter(text)
+            .map(|m| m.range())
+            .filter(|bereich| !bereich.is_empty())
+            .collect()
+    }
+}
+
+/// Ranges sorted, overlapping and touching ones joined
+pub fn zusammenfassen(mut treffer: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    treffer.sort_by_key(|bereich| (bereich.start, bereich.end));
+    let mut zusammen: Vec<Range<usize>> = Vec::new();
+    for bereich in treffer {
+        match zusammen.last_mut() {
+            Some(letzter) if bereich.start <= letzter.end => {
+                letzter.end = letzter.end.max(bereich.end);
+            }
+            _ => zusammen.push(bereich),
+        }
+    }
+    zusammen
+}
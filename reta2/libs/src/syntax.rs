@@ -0,0 +1,291 @@
+//! Syntax module - the OutputSyntax trait of all output formats and the registry `--art=` looks them up in
+
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::breite::anzeige_breite;
+use crate::errors::Result;
+#[cfg(feature = "html_output")]
+use crate::html_dokument::HtmlDokumentSyntax;
+#[cfg(feature = "bbcode_output")]
+use crate::lib4tables::BbCodeSyntax;
+#[cfg(feature = "csv_output")]
+use crate::lib4tables::CsvSyntax;
+#[cfg(feature = "html_output")]
+use crate::lib4tables::HtmlSyntax;
+#[cfg(feature = "markdown_output")]
+use crate::lib4tables::MarkdownSyntax;
+use crate::lib4tables::{
+    AsciiDocSyntax, EmacsSyntax, LatexSyntax, NichtsSyntax, ShellSyntax, TypstSyntax, Zelle,
+};
+use crate::query::TableData;
+use crate::strukturiert::{JsonLinesSyntax, JsonSyntax};
+use crate::types::OrderedDict;
+
+/// A group of rows: the position of its first row and its heading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gruppe {
+    pub ab: usize,
+    pub titel: String,
+}
+
+/// The table being rendered, with the display width of every column
+#[derive(Debug, Clone)]
+pub struct TabellenKontext<'a> {
+    pub kopf: &'a [Zelle],
+    pub zeilen: &'a [(i32, Vec<Zelle>)],
+    pub breiten: Vec<usize>,
+    pub gruppen: &'a [Gruppe],
+}
+
+impl<'a> TabellenKontext<'a> {
+    pub fn new(kopf: &'a [Zelle], zeilen: &'a [(i32, Vec<Zelle>)]) -> Self {
+        let mut breiten = vec![0; kopf.len()];
+        for zellen in std::iter::once(kopf).chain(zeilen.iter().map(|(_, z)| z.as_slice())) {
+            for (i, zelle) in zellen.iter().enumerate() {
+                if i >= breiten.len() {
+                    breiten.push(0);
+                }
+                breiten[i] = breiten[i].max(anzeige_breite(&zelle.text));
+            }
+        }
+        Self { kopf, zeilen, breiten, gruppen: &[] }
+    }
+
+    /// Rows headed by group headings, see `OutputSyntax::gruppen_kopf`
+    pub fn mit_gruppen(mut self, gruppen: &'a [Gruppe]) -> Self {
+        self.gruppen = gruppen;
+        self
+    }
+
+    /// Groups starting at a row position
+    pub fn gruppen_ab(&self, position: usize) -> impl Iterator<Item = &'a Gruppe> {
+        self.gruppen.iter().filter(move |g| g.ab == position)
+    }
+}
+
+/// One row: the number it belongs to, 0 for the header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeilenKontext {
+    pub zeile: i32,
+    pub kopf: bool,
+}
+
+/// One cell with its position and the width of its column
+#[derive(Debug, Clone, Copy)]
+pub struct ZellenKontext<'a> {
+    pub zelle: &'a Zelle,
+    pub zeile: i32,
+    pub spalte: usize,
+    pub breite: usize,
+    pub kopf: bool,
+}
+
+/// An output format. `format_table` drives the hooks in this order: document, table,
+/// header (one row of header cells), then each row, closing in reverse.
+/// Formats that are not a stream of rows, like JSON, override `format_table` instead.
+pub trait OutputSyntax: Send + Sync {
+    /// Key in the registry, the value of `--art=`
+    fn name(&self) -> &'static str;
+
+    /// Further values of `--art=` meaning this format
+    fn aliase(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Whether number colors can be shown; other formats get them as text
+    fn kann_farben(&self) -> bool {
+        false
+    }
+
+    /// Emphasized text
+    fn format(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    fn begin_dokument(&self, _tabelle: &TabellenKontext, _out: &mut String) {}
+
+    fn end_dokument(&self, _tabelle: &TabellenKontext, _out: &mut String) {}
+
+    fn begin_tabelle(&self, _tabelle: &TabellenKontext, _out: &mut String) {}
+
+    fn end_tabelle(&self, _tabelle: &TabellenKontext, _out: &mut String) {}
+
+    fn begin_kopf(&self, _tabelle: &TabellenKontext, _out: &mut String) {}
+
+    fn end_kopf(&self, _tabelle: &TabellenKontext, _out: &mut String) {}
+
+    fn begin_zeile(&self, _zeile: &ZeilenKontext, _out: &mut String) {}
+
+    fn end_zeile(&self, _zeile: &ZeilenKontext, out: &mut String) {
+        out.push('\n');
+    }
+
+    /// A search match inside a cell, see `--hervorheben`; emphasized by default
+    fn treffer(&self, text: &str) -> String {
+        self.format(text)
+    }
+
+    /// Put between two cells of a row
+    fn zellen_trenner(&self) -> &'static str {
+        ""
+    }
+
+    fn zelle(&self, zelle: &ZellenKontext) -> String;
+
+    /// Heading row before the rows of a group, see `--gruppiere=`
+    fn gruppen_kopf(&self, gruppe: &Gruppe, _tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&self.format(&gruppe.titel));
+        out.push('\n');
+    }
+
+    /// Render a heading row and numbered rows through this syntax
+    fn format_table(&self, kopf: &[Zelle], zeilen: &[(i32, Vec<Zelle>)]) -> Result<String> {
+        Ok(rendern(self, &TabellenKontext::new(kopf, zeilen)))
+    }
+
+    /// Like `format_table`, with group headings before the rows they start at.
+    /// Formats overriding `format_table` override this too.
+    fn format_gruppiert(
+        &self,
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        gruppen: &[Gruppe],
+    ) -> Result<String> {
+        if gruppen.is_empty() {
+            return self.format_table(kopf, zeilen);
+        }
+        Ok(rendern(self, &TabellenKontext::new(kopf, zeilen).mit_gruppen(gruppen)))
+    }
+
+    /// A query result; formats that count rows, like JSON, override this to
+    /// take the countings from the result's metadata cache
+    fn format_daten(&self, tabelle: &TableData) -> Result<String> {
+        self.format_gruppiert(&tabelle.kopf, &tabelle.zeilen, &tabelle.gruppen)
+    }
+}
+
+/// The hook sequence behind the default `format_table`
+pub fn rendern<S: OutputSyntax + ?Sized>(syntax: &S, tabelle: &TabellenKontext) -> String {
+    let mut out = String::new();
+    let zeile = |out: &mut String, zeile: ZeilenKontext, zellen: &[Zelle]| {
+        syntax.begin_zeile(&zeile, out);
+        for (spalte, zelle) in zellen.iter().enumerate() {
+            if spalte > 0 {
+                out.push_str(syntax.zellen_trenner());
+            }
+            out.push_str(&syntax.zelle(&ZellenKontext {
+                zelle,
+                zeile: zeile.zeile,
+                spalte,
+                breite: tabelle.breiten.get(spalte).copied().unwrap_or(0),
+                kopf: zeile.kopf,
+            }));
+        }
+        syntax.end_zeile(&zeile, out);
+    };
+
+    syntax.begin_dokument(tabelle, &mut out);
+    syntax.begin_tabelle(tabelle, &mut out);
+    syntax.begin_kopf(tabelle, &mut out);
+    zeile(&mut out, ZeilenKontext { zeile: 0, kopf: true }, tabelle.kopf);
+    syntax.end_kopf(tabelle, &mut out);
+    for (position, (num, zellen)) in tabelle.zeilen.iter().enumerate() {
+        for gruppe in tabelle.gruppen_ab(position) {
+            syntax.gruppen_kopf(gruppe, tabelle, &mut out);
+        }
+        zeile(&mut out, ZeilenKontext { zeile: *num, kopf: false }, zellen);
+    }
+    syntax.end_tabelle(tabelle, &mut out);
+    syntax.end_dokument(tabelle, &mut out);
+    out
+}
+
+/// Output formats by name
+pub struct SyntaxRegistry {
+    syntaxen: OrderedDict<&'static str, Arc<dyn OutputSyntax>>,
+}
+
+static STANDARD: Lazy<RwLock<SyntaxRegistry>> = Lazy::new(|| RwLock::new(SyntaxRegistry::default()));
+
+impl Default for SyntaxRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(Arc::new(ShellSyntax));
+        #[cfg(feature = "html_output")]
+        {
+            registry.register(Arc::new(HtmlSyntax));
+            registry.register(Arc::new(HtmlDokumentSyntax::default()));
+        }
+        #[cfg(feature = "bbcode_output")]
+        registry.register(Arc::new(BbCodeSyntax));
+        #[cfg(feature = "markdown_output")]
+        registry.register(Arc::new(MarkdownSyntax));
+        #[cfg(feature = "csv_output")]
+        registry.register(Arc::new(CsvSyntax));
+        registry.register(Arc::new(EmacsSyntax));
+        registry.register(Arc::new(LatexSyntax));
+        registry.register(Arc::new(TypstSyntax));
+        registry.register(Arc::new(AsciiDocSyntax));
+        registry.register(Arc::new(JsonSyntax));
+        registry.register(Arc::new(JsonLinesSyntax));
+        registry.register(Arc::new(NichtsSyntax));
+        registry
+    }
+}
+
+impl SyntaxRegistry {
+    /// An empty registry, see `default` for the built-in formats
+    pub fn new() -> Self {
+        Self {
+            syntaxen: OrderedDict::new(),
+        }
+    }
+
+    /// The shared registry `--art=` uses; formats registered here are found by name
+    pub fn standard() -> &'static RwLock<Self> {
+        &STANDARD
+    }
+
+    /// Adds a format, replacing one with the same name
+    pub fn register(&mut self, syntax: Arc<dyn OutputSyntax>) {
+        self.syntaxen.insert(syntax.name(), syntax);
+    }
+
+    /// Looks up a name or alias
+    pub fn get(&self, art: &str) -> Option<Arc<dyn OutputSyntax>> {
+        self.syntaxen
+            .get(art)
+            .or_else(|| self.syntaxen.values().find(|s| s.aliase().contains(&art)))
+            .cloned()
+    }
+
+    pub fn namen(&self) -> Vec<&'static str> {
+        self.syntaxen.keys().copied().collect()
+    }
+}
+
+/// Adds a format to the shared registry
+pub fn syntax_registrieren(syntax: Arc<dyn OutputSyntax>) {
+    SyntaxRegistry::standard()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(syntax);
+}
+
+/// Parse the value of `--art=` against the shared registry
+pub fn syntax_fuer_art(art: &str) -> Option<Arc<dyn OutputSyntax>> {
+    SyntaxRegistry::standard()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(art)
+}
+
+/// Names of all formats in the shared registry, for help texts
+pub fn syntax_namen() -> Vec<&'static str> {
+    SyntaxRegistry::standard()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .namen()
+}
@@ -0,0 +1,361 @@
+//! Tabellenkalkulation module - OpenDocument spreadsheets (.ods) with one sheet per table
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::cache::MetadatenCache;
+use crate::errors::Result;
+use crate::lib4tables::{alle_farben, farb_klasse, html_escape, ist_liste, listen_punkte, Zelle};
+use crate::query::{Kombi, SpaltenQuelle, TableData};
+use crate::strukturiert::{umgebrochen, JsonZelle, StrukturierteTabelle};
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+/// One worksheet: the structured table for texts and metadata, plus the cell colors
+#[derive(Debug, Clone)]
+pub struct Arbeitsblatt {
+    pub name: String,
+    pub tabelle: StrukturierteTabelle,
+    /// `farben[i][k]` is the number coloring cell k of row i, if any
+    pub farben: Vec<Vec<Option<i32>>>,
+}
+
+impl Arbeitsblatt {
+    pub fn new(name: impl Into<String>, tabelle: StrukturierteTabelle) -> Self {
+        Self {
+            name: name.into(),
+            tabelle,
+            farben: Vec::new(),
+        }
+    }
+
+    /// Sheet from plain cells, keeping their colors
+    pub fn aus_zellen(
+        name: impl Into<String>,
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+    ) -> Self {
+        Self::aus_cache(name, kopf, zeilen, None)
+    }
+
+    /// Like `aus_zellen`, the countings taken from the cache when there is one
+    fn aus_cache(
+        name: impl Into<String>,
+        kopf: &[Zelle],
+        zeilen: &[(i32, Vec<Zelle>)],
+        cache: Option<&MetadatenCache>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            tabelle: StrukturierteTabelle::aus_cache(kopf, zeilen, cache, None),
+            farben: zeilen
+                .iter()
+                .map(|(_, zellen)| zellen.iter().map(|z| z.farbe).collect())
+                .collect(),
+        }
+    }
+
+    /// Sheet of some columns of a query result, by position
+    fn auswahl(name: &str, tabelle: &TableData, spalten: &[usize]) -> Self {
+        let waehlen = |zellen: &[Zelle]| -> Vec<Zelle> {
+            spalten.iter().filter_map(|&i| zellen.get(i).cloned()).collect()
+        };
+        let zeilen: Vec<(i32, Vec<Zelle>)> = tabelle
+            .zeilen
+            .iter()
+            .map(|(zahl, zellen)| (*zahl, waehlen(zellen)))
+            .collect();
+        Self::aus_cache(name, &waehlen(&tabelle.kopf), &zeilen, tabelle.cache.as_deref())
+    }
+}
+
+/// A spreadsheet document, e.g. the main table and the kombi13/kombi15 joins
+#[derive(Debug, Clone, Default)]
+pub struct Tabellenkalkulation {
+    pub blaetter: Vec<Arbeitsblatt>,
+}
+
+impl Tabellenkalkulation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A query result as sheets: "tabelle" with everything but the kombi columns, then
+    /// "kombi13" and "kombi15" with the numbers and the columns joined from each
+    pub fn aus_tabelle(tabelle: &TableData) -> Self {
+        let mut kombis: Vec<Kombi> = Vec::new();
+        for spalte in &tabelle.spalten {
+            if let SpaltenQuelle::Kombi(kombi, _) = spalte.quelle {
+                if !kombis.contains(&kombi) {
+                    kombis.push(kombi);
+                }
+            }
+        }
+        let positionen = |passt: &dyn Fn(&SpaltenQuelle) -> bool| -> Vec<usize> {
+            (0..tabelle.kopf.len())
+                .filter(|&i| tabelle.spalten.get(i).is_none_or(|s| passt(&s.quelle)))
+                .collect()
+        };
+
+        let mut dokument = Self::new();
+        let haupt = positionen(&|quelle| !matches!(quelle, SpaltenQuelle::Kombi(..)));
+        dokument.blatt_hinzufuegen(Arbeitsblatt::auswahl("tabelle", tabelle, &haupt));
+        for kombi in kombis {
+            let spalten = positionen(&|quelle| match quelle {
+                SpaltenQuelle::Zahl => true,
+                SpaltenQuelle::Kombi(k, _) => *k == kombi,
+                _ => false,
+            });
+            let name = kombi.datei().trim_end_matches(".csv");
+            dokument.blatt_hinzufuegen(Arbeitsblatt::auswahl(name, tabelle, &spalten));
+        }
+        dokument
+    }
+
+    pub fn blatt_hinzufuegen(&mut self, blatt: Arbeitsblatt) {
+        self.blaetter.push(blatt);
+    }
+
+    /// content.xml: cell styles per color class as in farb_klasse, rows
+    /// colored like HtmlSyntax.colored_begin_col, header comments with the parameters
+    pub fn content_xml(&self) -> String {
+        let mut out = String::new();
+        out.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        out.push_str(concat!(
+            r#"<office:document-content"#,
+            r#" xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0""#,
+            r#" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0""#,
+            r#" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0""#,
+            r#" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0""#,
+            r#" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0""#,
+            r#" xmlns:dc="http://purl.org/dc/elements/1.1/""#,
+            r#" office:version="1.2">"#
+        ));
+
+        out.push_str("<office:automatic-styles>");
+        out.push_str(concat!(
+            r#"<style:style style:name="kopf" style:family="table-cell">"#,
+            r#"<style:text-properties fo:font-weight="bold"/></style:style>"#
+        ));
+        for (klasse, bg, fg) in alle_farben() {
+            let _ = write!(
+                out,
+                concat!(
+                    r#"<style:style style:name="{}" style:family="table-cell">"#,
+                    r#"<style:table-cell-properties fo:background-color="{}" fo:wrap-option="wrap"/>"#,
+                    r#"<style:text-properties fo:color="{}"/></style:style>"#
+                ),
+                klasse, bg, fg
+            );
+        }
+        out.push_str("</office:automatic-styles>");
+
+        out.push_str("<office:body><office:spreadsheet>");
+        for blatt in &self.blaetter {
+            blatt_xml(&mut out, blatt);
+        }
+        out.push_str("</office:spreadsheet></office:body></office:document-content>");
+        out
+    }
+
+    /// The .ods file: an uncompressed zip container with mimetype, manifest and content
+    pub fn ods(&self) -> Vec<u8> {
+        let manifest = concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">"#,
+            r#"<manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>"#,
+            r#"<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>"#,
+            r#"</manifest:manifest>"#
+        );
+
+        let mut zip = ZipSchreiber::default();
+        // The mimetype must be the first entry and stored uncompressed
+        zip.datei("mimetype", MIMETYPE.as_bytes());
+        zip.datei("META-INF/manifest.xml", manifest.as_bytes());
+        zip.datei("content.xml", self.content_xml().as_bytes());
+        zip.fertig()
+    }
+
+    pub fn speichern(&self, pfad: &Path) -> Result<()> {
+        fs::write(pfad, self.ods())?;
+        Ok(())
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    html_escape(text).replace('\'', "&apos;")
+}
+
+/// Sheet names may not contain []*?:/\ in spreadsheet programs
+fn blatt_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if "[]*?:/\\".contains(c) { '_' } else { c })
+        .collect()
+}
+
+fn absaetze(zeilen: &[String]) -> String {
+    if zeilen.is_empty() {
+        return "<text:p/>".to_string();
+    }
+    zeilen
+        .iter()
+        .map(|z| format!("<text:p>{}</text:p>", xml_escape(z)))
+        .collect()
+}
+
+/// Cell content: `<li>`/`[*]` lists become a `<text:list>` after the paragraphs of the
+/// text before them, other cells one paragraph per line
+fn zellen_inhalt(zelle: &JsonZelle) -> String {
+    if !ist_liste(&zelle.text) {
+        return absaetze(&zelle.zeilen);
+    }
+    let (davor, punkte) = listen_punkte(&zelle.text);
+    let mut out = String::new();
+    if !davor.is_empty() {
+        out.push_str(&absaetze(&umgebrochen(&davor, None)));
+    }
+    out.push_str("<text:list>");
+    for punkt in punkte {
+        let _ = write!(out, "<text:list-item><text:p>{}</text:p></text:list-item>", xml_escape(&punkt));
+    }
+    out.push_str("</text:list>");
+    out
+}
+
+fn blatt_xml(out: &mut String, blatt: &Arbeitsblatt) {
+    let tabelle = &blatt.tabelle;
+    let _ = write!(out, r#"<table:table table:name="{}">"#, xml_escape(&blatt_name(&blatt.name)));
+    let _ = write!(
+        out,
+        r#"<table:table-column table:number-columns-repeated="{}"/>"#,
+        tabelle.spalten.len().max(1)
+    );
+
+    out.push_str("<table:table-row>");
+    for spalte in &tabelle.spalten {
+        out.push_str(r#"<table:table-cell table:style-name="kopf" office:value-type="string">"#);
+        let mut kommentar: Vec<String> = spalte.parameter.iter().map(|(haupt, _)| haupt.clone()).collect();
+        if !spalte.tags.is_empty() {
+            kommentar.push(
+                spalte
+                    .tags
+                    .iter()
+                    .map(|tag| format!("{:?}", tag))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+        if !kommentar.is_empty() {
+            let _ = write!(
+                out,
+                "<office:annotation><dc:creator>reta</dc:creator>{}</office:annotation>",
+                absaetze(&kommentar)
+            );
+        }
+        let _ = write!(out, "<text:p>{}</text:p></table:table-cell>", xml_escape(&spalte.name));
+    }
+    out.push_str("</table:table-row>");
+
+    for (i, zeile) in tabelle.zeilen.iter().enumerate() {
+        let zeilen_stil = farb_klasse(zeile.zeile);
+        out.push_str("<table:table-row>");
+        for (k, zelle) in zeile.zellen.iter().enumerate() {
+            let stil = blatt
+                .farben
+                .get(i)
+                .and_then(|f| f.get(k).copied().flatten())
+                .and_then(farb_klasse)
+                .or(zeilen_stil)
+                .map(|s| format!(r#" table:style-name="{}""#, s))
+                .unwrap_or_default();
+            let wert = match zelle.text.trim().parse::<f64>() {
+                Ok(zahl) if zelle.zeilen.len() == 1 => {
+                    format!(r#" office:value-type="float" office:value="{}""#, zahl)
+                }
+                _ => r#" office:value-type="string""#.to_string(),
+            };
+            let _ = write!(
+                out,
+                "<table:table-cell{}{}>{}</table:table-cell>",
+                stil,
+                wert,
+                zellen_inhalt(zelle)
+            );
+        }
+        out.push_str("</table:table-row>");
+    }
+    out.push_str("</table:table>");
+}
+
+/// Minimal zip writer for stored (uncompressed) entries, enough for OpenDocument
+#[derive(Default)]
+struct ZipSchreiber {
+    daten: Vec<u8>,
+    verzeichnis: Vec<u8>,
+    anzahl: u16,
+}
+
+impl ZipSchreiber {
+    fn datei(&mut self, name: &str, inhalt: &[u8]) {
+        let offset = self.daten.len() as u32;
+        let crc = crc32(inhalt);
+        let groesse = inhalt.len() as u32;
+        let name_laenge = name.len() as u16;
+        // 1980-01-01 00:00, the earliest date zip can express
+        let (zeit, datum) = (0u16, 0x0021u16);
+
+        self.daten.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        for wert in [20u16, 0, 0, zeit, datum] {
+            self.daten.extend_from_slice(&wert.to_le_bytes());
+        }
+        for wert in [crc, groesse, groesse] {
+            self.daten.extend_from_slice(&wert.to_le_bytes());
+        }
+        self.daten.extend_from_slice(&name_laenge.to_le_bytes());
+        self.daten.extend_from_slice(&0u16.to_le_bytes());
+        self.daten.extend_from_slice(name.as_bytes());
+        self.daten.extend_from_slice(inhalt);
+
+        self.verzeichnis.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        for wert in [20u16, 20, 0, 0, zeit, datum] {
+            self.verzeichnis.extend_from_slice(&wert.to_le_bytes());
+        }
+        for wert in [crc, groesse, groesse] {
+            self.verzeichnis.extend_from_slice(&wert.to_le_bytes());
+        }
+        for wert in [name_laenge, 0, 0, 0, 0] {
+            self.verzeichnis.extend_from_slice(&wert.to_le_bytes());
+        }
+        self.verzeichnis.extend_from_slice(&0u32.to_le_bytes());
+        self.verzeichnis.extend_from_slice(&offset.to_le_bytes());
+        self.verzeichnis.extend_from_slice(name.as_bytes());
+        self.anzahl += 1;
+    }
+
+    fn fertig(mut self) -> Vec<u8> {
+        let offset = self.daten.len() as u32;
+        let groesse = self.verzeichnis.len() as u32;
+        self.daten.extend_from_slice(&self.verzeichnis);
+        self.daten.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        for wert in [0u16, 0, self.anzahl, self.anzahl] {
+            self.daten.extend_from_slice(&wert.to_le_bytes());
+        }
+        self.daten.extend_from_slice(&groesse.to_le_bytes());
+        self.daten.extend_from_slice(&offset.to_le_bytes());
+        self.daten.extend_from_slice(&0u16.to_le_bytes());
+        self.daten
+    }
+}
+
+fn crc32(daten: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in daten {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let maske = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & maske);
+        }
+    }
+    !crc
+}
@@ -1,14 +1,25 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use indexmap::{IndexSet, IndexMap};
 use num_rational::Ratio;
 use serde::{Deserialize, Serialize};
 
+use crate::lib4tables_enum::ST;
+
 // Type aliases
 pub type Row = Vec<String>;
 pub type Table = Vec<Row>;
 pub type OrderedSet<T> = IndexSet<T>;
 pub type OrderedDict<K, V> = IndexMap<K, V>;
 pub type DefaultOrderedDict<K, V> = IndexMap<K, V>;
+/// Pairs of fractions, as collected by Concat for the gebrochen-rational columns
+pub type BruchPaare = OrderedSet<(Ratio<i32>, Ratio<i32>)>;
+/// Fraction pairs by "UniGal" etc., then "stern"/"gleichf", then "mul"/"div"
+pub type BruchKombis = OrderedDict<String, OrderedDict<String, OrderedDict<String, BruchPaare>>>;
+/// Words per row, column, parameter and HTML class
+pub type HtmlWoerter = Vec<Vec<Vec<Vec<(String, String)>>>>;
+
+/// Highest row of religion.csv, the default upper bound for row ranges
+pub const HOECHSTE_ZEILE: i32 = 1024;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Fraction(pub Ratio<i32>);
@@ -22,14 +33,12 @@ impl Fraction {
         // Simple float to fraction conversion
         // For better conversion, use a more sophisticated algorithm
         let tolerance = 1e-6;
-        let mut n = 1;
-        let mut m = 1;
         
         for i in 1..1000 {
             for j in 1..1000 {
                 let approx = i as f64 / j as f64;
                 if (approx - value).abs() < tolerance {
-                    return Some(Fraction(Ratio::new(i as i32, j as i32)));
+                    return Some(Fraction(Ratio::new(i, j)));
                 }
             }
         }
@@ -43,6 +52,7 @@ pub struct Tables {
     pub generated_spalten_parameter_tags: OrderedDict<usize, HashSet<ST>>,
     pub spalten_vanilla_amount: usize,
     pub last_line_number: usize,
+    /// Parameter rows by `SpaltenTypKey::schluessel`
     pub data_dict: OrderedDict<usize, Row>,
     pub html_output_yes: bool,
     pub bbcode_output_yes: bool,
@@ -80,7 +90,7 @@ pub struct ParametersMain {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HtmlTagParaClassWoerter {
-    pub words: Vec<Vec<Vec<Vec<(String, String)>>>>,
+    pub words: HtmlWoerter,
 }
 
 pub struct Transpose;
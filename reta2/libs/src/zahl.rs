@@ -0,0 +1,356 @@
+//! Zahl module - everything reta knows about one single number
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::breite::{anzeige_breite, auffuellen};
+use crate::cache::{MetadatenCache, ZahlMetadaten};
+use crate::center::{i18n, multiples, NPmEnum};
+use crate::classifier::klassifikatoren;
+use crate::concat::Concat;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{html_escape, moon_number};
+use crate::types::OrderedDict;
+
+/// Countings (Zählungen): a new counting starts at every number that
+/// follows a moon, equivalent to Python's Prepare.setZaehlungen
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Zaehlungen {
+    pub bis: i32,
+    pub anfaenge: OrderedDict<i32, i32>,
+    pub zahl_zu_zaehlung: OrderedDict<i32, i32>,
+    pub monde: OrderedDict<i32, (Vec<i32>, Vec<i32>)>,
+}
+
+impl Zaehlungen {
+    pub fn new(bis: i32) -> Self {
+        let mut zaehlungen = Self::default();
+        zaehlungen.erweitern(bis);
+        zaehlungen
+    }
+
+    /// Continue counting up to `bis`, keeping what was counted already
+    pub fn erweitern(&mut self, bis: i32) {
+        let mut is_moon = if self.bis == 0 {
+            true
+        } else {
+            !moon_number(self.bis).0.is_empty()
+        };
+
+        for i in (self.bis + 1)..=bis {
+            let was_moon = is_moon;
+            let moon_type = moon_number(i);
+            is_moon = !moon_type.0.is_empty();
+
+            if was_moon && !is_moon {
+                let zaehlung = self.anfaenge.len() as i32 + 1;
+                self.anfaenge.insert(zaehlung, i);
+            }
+
+            self.zahl_zu_zaehlung.insert(i, self.anfaenge.len() as i32);
+            self.monde.insert(i, moon_type);
+        }
+
+        self.bis = self.bis.max(bis);
+    }
+
+    pub fn zeile_which_zaehlung(&self, zeile: i32) -> Option<i32> {
+        self.zahl_zu_zaehlung.get(&zeile).copied()
+    }
+
+    /// The counting of one number without counting everything below it:
+    /// 1 plus every moon m < zahl whose successor is no moon
+    pub fn zaehlung_von(zahl: i32) -> Option<i32> {
+        if zahl < 1 {
+            return None;
+        }
+        let mut monde = BTreeSet::new();
+        let mut basis: i32 = 2;
+        while basis <= zahl / basis {
+            let mut potenz = basis * basis;
+            loop {
+                monde.insert(potenz);
+                match potenz.checked_mul(basis) {
+                    Some(naechste) if naechste <= zahl => potenz = naechste,
+                    _ => break,
+                }
+            }
+            basis += 1;
+        }
+        let anfaenge = monde
+            .iter()
+            .filter(|&&mond| mond < zahl && !monde.contains(&(mond + 1)))
+            .count();
+        Some(anfaenge as i32 + 1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfilFormat {
+    Text,
+    Json,
+    Html,
+}
+
+impl FromStr for ProfilFormat {
+    type Err = ConcatError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" | "shell" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "html" => Ok(Self::Html),
+            _ => Err(ConcatError::Parse(format!("unknown output format: {}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Mond {
+    pub basis: i32,
+    pub exponent: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerierterText {
+    pub name: String,
+    pub text: String,
+}
+
+/// Structured profile of one number
+#[derive(Debug, Clone, Serialize)]
+pub struct ZahlProfil {
+    pub zahl: i32,
+    pub primfaktoren: Vec<(i32, i32)>,
+    pub prim_creativity: i32,
+    pub monde: Vec<Mond>,
+    pub primzahlkreuz_innen: bool,
+    pub primzahlkreuz_aussen: bool,
+    pub teiler: Vec<i32>,
+    pub multiples: Vec<(i32, i32)>,
+    pub zaehlung: Option<i32>,
+    pub n_pm: Vec<NPmEnum>,
+    /// Labels of every registered classifier
+    pub klassen: OrderedDict<&'static str, Vec<&'static str>>,
+    pub texte: Vec<GenerierterText>,
+}
+
+impl ZahlProfil {
+    pub fn new(zahl: i32, zaehlungen: &Zaehlungen) -> Result<Self> {
+        Self::aus_metadaten(zahl, &ZahlMetadaten::new(zahl), zaehlungen)
+    }
+
+    /// Uses the precomputed metadata where the cache covers the number.
+    /// Numbers above the counted range get their counting from `Zaehlungen::zaehlung_von`.
+    pub fn aus_cache(zahl: i32, cache: &MetadatenCache) -> Result<Self> {
+        match cache.get(zahl) {
+            Some(metadaten) => Self::aus_metadaten(zahl, metadaten, &cache.zaehlungen),
+            None => Self::new(zahl, &cache.zaehlungen),
+        }
+    }
+
+    fn aus_metadaten(zahl: i32, metadaten: &ZahlMetadaten, zaehlungen: &Zaehlungen) -> Result<Self> {
+        if zahl < 1 {
+            return Err(ConcatError::InvalidRowIndex(zahl.max(0) as usize));
+        }
+
+        let (basen, exponenten) = zaehlungen
+            .monde
+            .get(&zahl)
+            .cloned()
+            .unwrap_or_else(|| moon_number(zahl));
+        let monde = basen
+            .into_iter()
+            .zip(exponenten)
+            .map(|(basis, exponent_minus2)| Mond {
+                basis,
+                exponent: exponent_minus2 + 2,
+            })
+            .collect();
+
+        let prim_creativity_type = metadaten.prim_creativity;
+        let texte = [
+            (
+                i18n::krea_zahl("Evolutions-Züchtungs-Kreativität"),
+                krea_zahl_text(prim_creativity_type),
+            ),
+            (
+                i18n::gleichheit_freiheit_vergleich(
+                    "Gleichheit, Freiheit, Dominieren (Ordnungen [12]) Generiert",
+                ),
+                Concat::gleichheit_freiheit_vergleich(zahl),
+            ),
+            (
+                i18n::ausgabe_string(
+                    "Energie oder Denkart oder Gefühlsart oder Materie-Art oder Topologie-Art",
+                ),
+                Concat::geist_emotion_energie_materie_topologie_von(metadaten),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(name, text)| GenerierterText { name, text })
+        .collect();
+
+        let registry = klassifikatoren();
+        let klassen = registry
+            .namen()
+            .into_iter()
+            .filter_map(|name| Some((name, registry.get(name)?.labels(zahl))))
+            .collect();
+
+        Ok(Self {
+            zahl,
+            primfaktoren: metadaten.primfaktoren.clone(),
+            prim_creativity: prim_creativity_type,
+            monde,
+            primzahlkreuz_innen: metadaten.primzahlkreuz_innen,
+            primzahlkreuz_aussen: metadaten.primzahlkreuz_aussen,
+            teiler: metadaten.teiler.clone(),
+            multiples: multiples(zahl),
+            zaehlung: if zahl <= zaehlungen.bis {
+                zaehlungen.zeile_which_zaehlung(zahl)
+            } else {
+                Zaehlungen::zaehlung_von(zahl)
+            },
+            n_pm: NPmEnum::of_zahl(zahl),
+            klassen,
+            texte,
+        })
+    }
+
+    pub fn ausgeben(&self, format: ProfilFormat) -> Result<String> {
+        match format {
+            ProfilFormat::Text => Ok(self.als_text()),
+            ProfilFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| ConcatError::Parse(e.to_string())),
+            ProfilFormat::Html => Ok(self.als_html()),
+        }
+    }
+
+    /// Label and value pairs in display order, shared by text and HTML output
+    fn eintraege(&self) -> Vec<(String, String)> {
+        let faktoren = self
+            .primfaktoren
+            .iter()
+            .map(|&(prim, anzahl)| {
+                if anzahl == 1 {
+                    prim.to_string()
+                } else {
+                    format!("{}^{}", prim, anzahl)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" * ");
+        let monde = if self.monde.is_empty() {
+            i18n::mond_exp_log2("kein Mond")
+        } else {
+            self.monde
+                .iter()
+                .map(|mond| format!("{}^{}", mond.basis, mond.exponent))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let ja_nein = |b: bool| if b { "ja" } else { "nein" }.to_string();
+
+        let mut eintraege = vec![
+            ("Zahl".to_string(), self.zahl.to_string()),
+            ("Primfaktoren".to_string(), faktoren),
+            (
+                "Primzahl-Kreativität".to_string(),
+                self.prim_creativity.to_string(),
+            ),
+            ("Monde".to_string(), monde),
+            (
+                format!("Primzahlkreuz {}", i18n::innen_aussen("für innen")),
+                ja_nein(self.primzahlkreuz_innen),
+            ),
+            (
+                format!("Primzahlkreuz {}", i18n::innen_aussen("für außen")),
+                ja_nein(self.primzahlkreuz_aussen),
+            ),
+            (
+                "Teiler".to_string(),
+                self.teiler
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            (
+                i18n::multipl("Multiplikationen"),
+                self.multiples
+                    .iter()
+                    .map(|(a, b)| format!("{}*{}", a, b))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            (
+                "Zählung".to_string(),
+                self.zaehlung.map(|z| z.to_string()).unwrap_or_default(),
+            ),
+            (
+                "nPm".to_string(),
+                self.n_pm
+                    .iter()
+                    .map(|e| e.name())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        ];
+        eintraege.extend(
+            self.klassen
+                .iter()
+                .map(|(name, labels)| (format!("Klasse {}", name), labels.join(", "))),
+        );
+        eintraege.extend(
+            self.texte
+                .iter()
+                .map(|text| (text.name.clone(), text.text.clone())),
+        );
+        eintraege
+    }
+
+    pub fn als_text(&self) -> String {
+        let eintraege = self.eintraege();
+        let breite = eintraege
+            .iter()
+            .map(|(name, _)| anzeige_breite(name))
+            .max()
+            .unwrap_or(0);
+
+        let mut out = String::new();
+        for (name, wert) in eintraege {
+            let _ = writeln!(out, "{} {}", auffuellen(&format!("{}:", name), breite + 1), wert);
+        }
+        out
+    }
+
+    pub fn als_html(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, r#"<table border=0 id="zahl{}">"#, self.zahl);
+        for (name, wert) in self.eintraege() {
+            let _ = writeln!(
+                out,
+                "<tr><th>{}</th><td>{}</td></tr>",
+                html_escape(&name),
+                html_escape(&wert)
+            );
+        }
+        out.push_str("</table>\n");
+        out
+    }
+}
+
+fn krea_zahl_text(prim_creativity_type: i32) -> String {
+    match prim_creativity_type {
+        0 => i18n::krea_zahl("0. Primzahl 1"),
+        1 => i18n::krea_zahl("1. Primzahl und Sonnenzahl"),
+        2 => i18n::krea_zahl("2. Sonnenzahl, aber keine Primzahl"),
+        3 => i18n::krea_zahl("3. Mondzahl"),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,400 @@
+//! Zeilen module - the row conditions of the `-zeilen` parameter group, equivalent to
+//! Python's Prepare.filterOriginalLines
+//!
+//! `-zeilen --vorhervonausschnitt=1-50 --typ=sonne --primzahlen=aussenalle` keeps the sun
+//! numbers up to 50 that have a prime factor 6k+5. Each kind of condition narrows what the
+//! ones before it selected, in the order of `zeilen_filtern`.
+
+use std::collections::BTreeSet;
+
+use crate::center::bereich_to_numbers2;
+use crate::errors::{ConcatError, Result};
+use crate::lib4tables::{
+    could_be_prime_number_primzahlkreuz, divisor_generator, moon_number, prim_fak, prim_repeat,
+};
+use crate::zahl::Zaehlungen;
+
+/// Sun numbers above this have no rows of their own in religion.csv and are left out
+pub const SONNEN_HOECHSTENS: i32 = 114;
+
+/// `--zeit=`: today is 10, yesterday 1-9, tomorrow everything above 10
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zeit {
+    Heute,
+    Gestern,
+    Morgen,
+}
+
+/// `--primzahlen=`: primes 6k+5 are outer (aussen), primes 6k+1 inner (innen); "erste"
+/// are the primes themselves, "alle" every number with such a prime factor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Primzahlen {
+    AussenErste,
+    InnenErste,
+    AussenAlle,
+    InnenAlle,
+}
+
+/// `--typ=`: the kind of star a number is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gestirn {
+    Sonne,
+    Mond,
+    Planet,
+    SchwarzeSonne,
+    /// Prime factors with exponent 1 next to ones with a higher exponent, like 12 = 2²·3
+    SonneMitMondanteil,
+}
+
+/// One condition of `-zeilen`; the comments name the parameter lines of the former Prepare
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Zeilenbedingung {
+    /// `--alles`, "all"
+    Alles,
+    /// `--vorhervonausschnitt=`, "_a_": a range as in `bereich_to_numbers2`
+    Bereich(String),
+    /// `--vorhervonausschnittteiler=`, "_w_": with the divisors of the range's numbers
+    Teiler,
+    /// `--vielfachevonzahlen=`, "_b_": multiples of the numbers, up to `SONNEN_HOECHSTENS`
+    Vielfache(String),
+    /// `--zeit=`, "=", "<" and ">"
+    Zeit(Zeit),
+    /// `--zaehlung=`, "_n_": the numbers of these countings
+    Zaehlung(String),
+    /// `--primzahlen=`
+    Primzahlen(Primzahlen),
+    /// `--typ=`, "sonne", "mond", "planet", "schwarzesonne" and "SonneMitMondanteil"
+    Gestirn(Gestirn),
+    /// `--primzahlvielfache=`, "<n>p": n times a number that may be a prime
+    Primzahlvielfache(i32),
+    /// `--potenzenvonzahlen=`, "_^_": powers of the numbers, without 1
+    Potenzen(String),
+    /// `--invertieren`, "_i_": the neighbours of the selected numbers instead of them
+    Invertieren,
+    /// `--nachtraeglichneuabzaehlung=`, "_z_": the n-th of the selected numbers
+    Neuabzaehlung(String),
+    /// `--nachtraeglichneuabzaehlungvielfache=`, "_y_": as `Neuabzaehlung` with multiples
+    NeuabzaehlungVielfache(String),
+}
+
+impl Zeilenbedingung {
+    /// The conditions of one `--name=werte` argument of the `-zeilen` group
+    pub fn parse(arg: &str) -> Result<Vec<Self>> {
+        let unbekannt = || ConcatError::Parse(format!("unknown -zeilen parameter: {}", arg));
+        let text = arg.strip_prefix("--").ok_or_else(unbekannt)?;
+        let (name, werte) = text.split_once('=').unwrap_or((text, ""));
+        let woerter = || werte.split(',').map(str::trim).filter(|w| !w.is_empty());
+        let mit_wort = |wort: &str, bedingung: Option<Self>| {
+            bedingung.ok_or_else(|| {
+                ConcatError::Parse(format!("unknown value of --{}: {}", name, wort))
+            })
+        };
+
+        Ok(match name {
+            "alles" => vec![Self::Alles],
+            "vorhervonausschnitt" => vec![Self::Bereich(werte.to_string())],
+            "vorhervonausschnittteiler" => vec![Self::Bereich(werte.to_string()), Self::Teiler],
+            "vielfachevonzahlen" => vec![Self::Vielfache(werte.to_string())],
+            "zaehlung" => vec![Self::Zaehlung(werte.to_string())],
+            "potenzenvonzahlen" => vec![Self::Potenzen(werte.to_string())],
+            "invertieren" => vec![Self::Invertieren],
+            "nachtraeglichneuabzaehlung" => vec![Self::Neuabzaehlung(werte.to_string())],
+            "nachtraeglichneuabzaehlungvielfache" => {
+                vec![Self::NeuabzaehlungVielfache(werte.to_string())]
+            }
+            "primzahlvielfache" => bereich_to_numbers2(werte, false, 0)
+                .into_iter()
+                .map(Self::Primzahlvielfache)
+                .collect(),
+            "zeit" => woerter()
+                .map(|wort| {
+                    mit_wort(
+                        wort,
+                        match wort {
+                            "heute" => Some(Self::Zeit(Zeit::Heute)),
+                            "gestern" => Some(Self::Zeit(Zeit::Gestern)),
+                            "morgen" => Some(Self::Zeit(Zeit::Morgen)),
+                            _ => None,
+                        },
+                    )
+                })
+                .collect::<Result<_>>()?,
+            "typ" => woerter()
+                .map(|wort| {
+                    mit_wort(
+                        wort,
+                        match wort {
+                            "sonne" => Some(Self::Gestirn(Gestirn::Sonne)),
+                            "mond" => Some(Self::Gestirn(Gestirn::Mond)),
+                            "planet" => Some(Self::Gestirn(Gestirn::Planet)),
+                            "schwarzesonne" => Some(Self::Gestirn(Gestirn::SchwarzeSonne)),
+                            "sonnemitmondanteil" => {
+                                Some(Self::Gestirn(Gestirn::SonneMitMondanteil))
+                            }
+                            _ => None,
+                        },
+                    )
+                })
+                .collect::<Result<_>>()?,
+            "primzahlen" => woerter()
+                .map(|wort| {
+                    mit_wort(
+                        wort,
+                        match wort {
+                            "aussenerste" => Some(Self::Primzahlen(Primzahlen::AussenErste)),
+                            "innenerste" => Some(Self::Primzahlen(Primzahlen::InnenErste)),
+                            "aussenalle" => Some(Self::Primzahlen(Primzahlen::AussenAlle)),
+                            "innenalle" => Some(Self::Primzahlen(Primzahlen::InnenAlle)),
+                            _ => None,
+                        },
+                    )
+                })
+                .collect::<Result<_>>()?,
+            _ => return Err(unbekannt()),
+        })
+    }
+}
+
+/// Parts of ranges that remove numbers, "-5" or "v-5", without their sign
+fn minus_teile(bereiche: &[&String]) -> String {
+    bereiche
+        .iter()
+        .flat_map(|b| b.split(','))
+        .map(str::trim)
+        .filter_map(|teil| match teil.strip_prefix("v-") {
+            Some(rest) => Some(format!("v{}", rest)),
+            None => teil.strip_prefix('-').map(str::to_string),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The numbers up to `hoechste_zeile` the conditions select. Without any condition, or with
+/// `Alles`, every number is selected first; ranges add to that, every other kind of
+/// condition narrows it. Counting conditions look numbers up in `zaehlungen`.
+pub fn zeilen_filtern(
+    bedingungen: &[Zeilenbedingung],
+    hoechste_zeile: i32,
+    zaehlungen: &Zaehlungen,
+) -> BTreeSet<i32> {
+    use Zeilenbedingung as Z;
+    let alle = || (1..=hoechste_zeile).collect::<BTreeSet<i32>>();
+    let alles = bedingungen.is_empty() || bedingungen.contains(&Z::Alles);
+    let mut zahlen = if alles { alle() } else { BTreeSet::new() };
+
+    let bereiche: Vec<&String> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Bereich(bereich) => Some(bereich),
+            _ => None,
+        })
+        .collect();
+    let ob_bereich = !bereiche.is_empty();
+    if ob_bereich {
+        let verbunden = bereiche.iter().map(|b| b.as_str()).collect::<Vec<_>>().join(",");
+        zahlen.extend(bereich_to_numbers2(&verbunden, false, hoechste_zeile));
+        if bedingungen.contains(&Z::Teiler) {
+            let teiler: Vec<i32> = zahlen.iter().flat_map(|&n| divisor_generator(n)).collect();
+            zahlen.extend(teiler);
+        }
+        let minus = bereich_to_numbers2(&minus_teile(&bereiche), false, hoechste_zeile);
+        zahlen.retain(|n| !minus.contains(n));
+    }
+
+    let vielfache: Vec<&String> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Vielfache(bereich) => Some(bereich),
+            _ => None,
+        })
+        .collect();
+    let ob_vielfache = !vielfache.is_empty();
+    if ob_vielfache {
+        if zahlen.is_empty() {
+            zahlen = (1..=SONNEN_HOECHSTENS.min(hoechste_zeile)).collect();
+        }
+        let verbunden = vielfache.iter().map(|b| b.as_str()).collect::<Vec<_>>().join(",");
+        let ja = bereich_to_numbers2(&verbunden, true, SONNEN_HOECHSTENS);
+        if !ja.is_empty() {
+            zahlen.retain(|n| ja.contains(n));
+        }
+        let minus = bereich_to_numbers2(&minus_teile(&vielfache), true, hoechste_zeile);
+        zahlen.retain(|n| !minus.contains(n));
+    }
+
+    let mut ob_zeit = false;
+    let mut zeiten = BTreeSet::new();
+    for bedingung in bedingungen {
+        if let Z::Zeit(zeit) = bedingung {
+            ob_zeit = true;
+            match zeit {
+                Zeit::Heute => {
+                    zeiten.insert(10);
+                }
+                Zeit::Gestern => zeiten.extend(1..10),
+                Zeit::Morgen => zeiten.extend(11..=hoechste_zeile),
+            }
+        }
+    }
+    if ob_zeit {
+        // A time narrows a range, without one it is the selection itself
+        if alles || ob_bereich || ob_vielfache {
+            zahlen.retain(|n| zeiten.contains(n));
+        } else {
+            zahlen.extend(zeiten);
+        }
+    }
+
+    let zaehlungen_bereich: Vec<&str> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Zaehlung(bereich) => Some(bereich.as_str()),
+            _ => None,
+        })
+        .collect();
+    if !zaehlungen_bereich.is_empty() {
+        let gewaehlt = bereich_to_numbers2(&zaehlungen_bereich.join(","), false, hoechste_zeile);
+        if zahlen.is_empty() {
+            zahlen = alle();
+        }
+        zahlen.retain(|&n| {
+            zaehlungen
+                .zeile_which_zaehlung(n)
+                .is_some_and(|z| gewaehlt.contains(&z))
+        });
+    }
+
+    let primzahlen: Vec<Primzahlen> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Primzahlen(p) => Some(*p),
+            _ => None,
+        })
+        .collect();
+    if !primzahlen.is_empty() {
+        zahlen.retain(|&n| {
+            // 1 counts as the first inner number; 2 and 3 are neither
+            let faktoren = prim_fak(n);
+            let erste = n == 1 || faktoren.len() == 1;
+            let innen = n == 1 || faktoren.iter().any(|&p| p > 3 && p % 6 == 1);
+            let aussen = n != 1 && faktoren.iter().any(|&p| p > 3 && p % 6 == 5);
+            primzahlen.iter().any(|p| match p {
+                Primzahlen::AussenErste => aussen && erste,
+                Primzahlen::InnenErste => innen && erste,
+                Primzahlen::AussenAlle => aussen,
+                Primzahlen::InnenAlle => innen,
+            })
+        });
+    }
+
+    let gestirne: Vec<Gestirn> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Gestirn(g) => Some(*g),
+            _ => None,
+        })
+        .collect();
+    if !gestirne.is_empty() {
+        if zahlen.is_empty() {
+            zahlen = alle();
+        }
+        zahlen.retain(|&n| {
+            gestirne.iter().any(|g| match g {
+                Gestirn::Mond => ist_mond(n, zaehlungen),
+                Gestirn::Sonne => !ist_mond(n, zaehlungen),
+                Gestirn::SchwarzeSonne => n % 3 == 0,
+                Gestirn::Planet => n % 2 == 0,
+                Gestirn::SonneMitMondanteil => {
+                    let exponenten = prim_repeat(prim_fak(n));
+                    exponenten.iter().any(|&(_, e)| e == 1)
+                        && exponenten.iter().any(|&(_, e)| e > 1)
+                }
+            })
+        });
+    }
+
+    let primzahlvielfache: Vec<i32> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Primzahlvielfache(zahl) => Some(*zahl),
+            _ => None,
+        })
+        .collect();
+    if !primzahlvielfache.is_empty() {
+        if zahlen.is_empty() {
+            zahlen = alle();
+        }
+        zahlen.retain(|&n| {
+            primzahlvielfache
+                .iter()
+                .any(|&z| n % z == 0 && could_be_prime_number_primzahlkreuz(n / z))
+        });
+    }
+
+    let basen: Vec<&str> = bedingungen
+        .iter()
+        .filter_map(|b| match b {
+            Z::Potenzen(bereich) => Some(bereich.as_str()),
+            _ => None,
+        })
+        .collect();
+    if !basen.is_empty() {
+        if zahlen.is_empty() {
+            zahlen = alle();
+        }
+        let groesste = zahlen.last().copied().unwrap_or_default();
+        let mut potenzen = BTreeSet::new();
+        for basis in bereich_to_numbers2(&basen.join(","), false, hoechste_zeile) {
+            let mut potenz = basis;
+            while basis > 1 && potenz <= groesste {
+                potenzen.insert(potenz);
+                match potenz.checked_mul(basis) {
+                    Some(naechste) => potenz = naechste,
+                    None => break,
+                }
+            }
+        }
+        zahlen.retain(|n| potenzen.contains(n));
+    }
+
+    zahlen.retain(|&n| n <= SONNEN_HOECHSTENS || ist_mond(n, zaehlungen));
+
+    if bedingungen.contains(&Z::Invertieren) {
+        zahlen = (1..=hoechste_zeile)
+            .filter(|n| {
+                !zahlen.contains(n) && (zahlen.contains(&(n - 1)) || zahlen.contains(&(n + 1)))
+            })
+            .collect();
+    }
+
+    // The n-th of the selection, counted after every other condition
+    let mut stellen = None::<BTreeSet<i32>>;
+    for bedingung in bedingungen {
+        let (bereich, vielfache) = match bedingung {
+            Z::Neuabzaehlung(bereich) => (bereich, false),
+            Z::NeuabzaehlungVielfache(bereich) => (bereich, true),
+            _ => continue,
+        };
+        stellen
+            .get_or_insert_with(BTreeSet::new)
+            .extend(bereich_to_numbers2(bereich, vielfache, zahlen.len() as i32));
+    }
+    if let Some(stellen) = stellen {
+        zahlen = zahlen
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| stellen.contains(&(*i as i32 + 1)))
+            .map(|(_, n)| n)
+            .collect();
+    }
+
+    zahlen
+}
+
+/// Moons are the numbers that are a power; counted numbers are looked up, the rest computed
+fn ist_mond(zahl: i32, zaehlungen: &Zaehlungen) -> bool {
+    match zaehlungen.monde.get(&zahl) {
+        Some((basen, _)) => !basen.is_empty(),
+        None => !moon_number(zahl).0.is_empty(),
+    }
+}
@@ -1,5 +0,0 @@
-// src/lib.rs
-pub mod tables;
-pub mod syntax;
-pub mod utils;
-pub mod types;
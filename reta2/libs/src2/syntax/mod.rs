@@ -1,9 +0,0 @@
-// src/syntax/mod.rs
-pub mod output_syntax;
-pub mod markdown;
-pub mod html;
-pub mod bbcode;
-pub mod csv;
-pub mod emacs;
-
-pub use output_syntax::OutputSyntax;
@@ -0,0 +1,33 @@
+use lib4tables_concat::*;
+
+#[test]
+fn test_anzeige_breite() {
+    assert_eq!(anzeige_breite("Mond"), 4);
+    // Decomposed umlaut: u + combining diaeresis is one column
+    assert_eq!(anzeige_breite("Gru\u{308}n"), 4);
+    assert_eq!(anzeige_breite("Grün"), 4);
+    assert_eq!(anzeige_breite("日本"), 4);
+    assert_eq!(anzeige_breite("☀️ 🌙"), 5);
+    assert_eq!(anzeige_breite("👨‍👩‍👧"), 2);
+    assert_eq!(anzeige_breite("\x1b[43m\x1b[30m7\x1b[0m"), 1);
+    assert_eq!(anzeige_breite("\x1b]8;;https://x\x07Link\x1b]8;;\x07"), 4);
+}
+
+#[test]
+fn test_umbrechen_und_abschneiden() {
+    assert_eq!(umbrechen("abcdef", 4), vec!["abcd", "ef"]);
+    assert_eq!(umbrechen("u\u{308}u\u{308}u\u{308}", 2), vec!["u\u{308}u\u{308}", "u\u{308}"]);
+    assert_eq!(umbrechen("a日本", 2), vec!["a", "日", "本"]);
+    assert_eq!(umbrechen("\x1b[1mabc\x1b[0m", 2), vec!["\x1b[1mab", "c\x1b[0m"]);
+    assert!(umbrechen("", 3).is_empty());
+    assert_eq!(get_text_wrap_things("日本語", 4), vec!["日本", "語"]);
+
+    assert_eq!(abschneiden("abc", 3), "abc");
+    assert_eq!(abschneiden("日本語", 4), "日…");
+    assert_eq!(abschneiden("\x1b[1m日x\x1b[0m", 2), "\x1b[1m\x1b[0m…");
+    assert_eq!(auffuellen("Gru\u{308}n", 6), "Gru\u{308}n  ");
+
+    let kopf = vec![Zelle::new("Symbol"), Zelle::new("n")];
+    let zeilen = vec![(1, vec![Zelle::new("日本"), Zelle::new("1")]), (2, vec![Zelle::new("Gru\u{308}n"), Zelle::new("2")])];
+    assert_eq!(ShellSyntax.format_table(&kopf, &zeilen).unwrap(), "Symbol n\n日本   1\nGru\u{308}n   2\n");
+}
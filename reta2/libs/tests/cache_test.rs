@@ -0,0 +1,133 @@
+mod common;
+
+use lib4tables_concat::cache::{cache_schluessel, daten_dateien, zaehlungen_bis};
+use lib4tables_concat::*;
+use std::fs;
+use std::sync::Arc;
+
+#[test]
+fn test_cache_schluessel() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    fs::write(ordner.path().join("religion.csv"), "1;a\n")?;
+    fs::write(ordner.path().join("notizen.txt"), "kein csv")?;
+
+    let dateien = daten_dateien(ordner.path())?;
+    assert_eq!(dateien.len(), 1);
+
+    let vorher = cache_schluessel(&dateien, 100)?;
+    assert_eq!(vorher, cache_schluessel(&dateien, 100)?);
+    assert_ne!(vorher, cache_schluessel(&dateien, 200)?);
+
+    // Same size and modification time, only the contents tell the change apart
+    let csv = ordner.path().join("religion.csv");
+    let geaendert = fs::metadata(&csv)?.modified()?;
+    fs::write(&csv, "1;b\n")?;
+    fs::File::options().write(true).open(&csv)?.set_modified(geaendert)?;
+    assert_ne!(vorher, cache_schluessel(&dateien, 100)?);
+    Ok(())
+}
+
+#[test]
+fn test_metadaten_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    let csv = ordner.path().join("religion.csv");
+    let cache_datei = ordner.path().join("cache").join("reta.json");
+    fs::write(&csv, "1;a\n")?;
+
+    let dateien = vec![csv];
+    let cache = MetadatenCache::laden_oder_berechnen(&cache_datei, &dateien, 64)?;
+    assert!(cache_datei.exists());
+    assert_eq!(cache.get(12).unwrap().teiler, vec![1, 2, 3, 4, 6, 12]);
+    assert!(cache.get(65).is_none());
+
+    let geladen = MetadatenCache::laden_oder_berechnen(&cache_datei, &dateien, 64)?;
+    assert_eq!(geladen.schluessel, cache.schluessel);
+    assert_eq!(geladen.zahlen, cache.zahlen);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let modus = fs::metadata(cache_datei.parent().unwrap())?.permissions().mode();
+        assert_eq!(modus & 0o777, 0o700);
+
+        // A symlink planted in place of the cache is not followed but replaced
+        let fremd = ordner.path().join("fremd.json");
+        fs::write(&fremd, "{}")?;
+        fs::remove_file(&cache_datei)?;
+        std::os::unix::fs::symlink(&fremd, &cache_datei)?;
+        MetadatenCache::laden_oder_berechnen(&cache_datei, &dateien, 64)?;
+        assert!(fs::symlink_metadata(&cache_datei)?.is_file());
+        assert_eq!(fs::read_to_string(&fremd)?, "{}");
+    }
+
+    let profil = ZahlProfil::aus_cache(64, &geladen)?;
+    let ohne_cache = ZahlProfil::new(64, &Zaehlungen::new(64))?;
+    assert_eq!(profil.zaehlung, ohne_cache.zaehlung);
+    assert_eq!(profil.primfaktoren, ohne_cache.primfaktoren);
+    Ok(())
+}
+
+#[test]
+fn test_cache_datei_je_quelle() -> Result<(), Box<dyn std::error::Error>> {
+    let eins = tempfile::tempdir()?;
+    let zwei = tempfile::tempdir()?;
+    let quelle = Datenquelle::ordner(eins.path());
+    assert_eq!(quelle.cache_datei(), Datenquelle::ordner(eins.path()).cache_datei());
+    assert_ne!(quelle.cache_datei(), Datenquelle::ordner(zwei.path()).cache_datei());
+
+    // A profile swapping in a file has a cache of its own
+    let mut profil = Datenquelle {
+        profil: Some("en".to_string()),
+        ..quelle.clone()
+    };
+    assert_ne!(quelle.cache_datei(), profil.cache_datei());
+    let vorher = profil.cache_datei();
+    profil
+        .dateien
+        .insert("religion.csv".to_string(), zwei.path().join("religion-en.csv"));
+    assert_ne!(vorher, profil.cache_datei());
+    Ok(())
+}
+
+#[test]
+fn test_query_mit_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = common::religion(12);
+    let query = Query::rows("1-12")
+        .gruppiere(Schluessel::parse("zaehlung")?)
+        .sortiere(Sortierung::parse_liste("-primfaktoren")?);
+
+    let ohne = query.ausfuehren(&daten)?;
+    // A cache reaching far enough and one too short for the rows give the same table
+    for hoechste_zeile in [64, 5] {
+        let cache = Arc::new(MetadatenCache::berechnen("test".to_string(), hoechste_zeile));
+        let mit = query.clone().cache(cache.clone()).ausfuehren(&daten)?;
+        assert!(mit.cache.is_some());
+        assert_eq!(mit.zahlen(), ohne.zahlen());
+        assert_eq!(mit.rendern(&JsonSyntax)?, ohne.rendern(&JsonSyntax)?);
+        assert_eq!(mit.rendern(&JsonLinesSyntax)?, ohne.rendern(&JsonLinesSyntax)?);
+        assert_eq!(mit.rendern(&ShellSyntax)?, ohne.rendern(&ShellSyntax)?);
+
+        let pivot = Pivot::parse("zaehlung")?;
+        let gepivot = mit.pivotieren(&pivot)?;
+        assert_eq!(gepivot.kopf, ohne.pivotieren(&pivot)?.kopf);
+        assert!(gepivot.cache.is_some());
+    }
+
+    // The rows carry their counting and group in the structured table
+    let strukturiert = query
+        .cache(Arc::new(MetadatenCache::berechnen("test".to_string(), 64)))
+        .ausfuehren(&daten)?
+        .strukturiert();
+    let zeile = strukturiert.zeilen.iter().find(|z| z.zeile == 12).unwrap();
+    assert_eq!(zeile.zaehlung, Zaehlungen::new(12).zeile_which_zaehlung(12));
+    assert!(zeile.gruppe.is_some());
+    Ok(())
+}
+
+#[test]
+fn test_zaehlungen_bis() {
+    let cache = MetadatenCache::berechnen("test".to_string(), 10);
+    assert_eq!(zaehlungen_bis(Some(&cache), 10).bis, 10);
+    assert_eq!(zaehlungen_bis(Some(&cache), 20).bis, 20);
+    assert_eq!(zaehlungen_bis(None, 7).bis, 7);
+}
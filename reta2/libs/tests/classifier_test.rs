@@ -0,0 +1,124 @@
+mod common;
+
+use lib4tables_concat::*;
+
+struct Gerade;
+
+impl Classifier for Gerade {
+    fn name(&self) -> &'static str {
+        "gerade"
+    }
+
+    fn alle_labels(&self) -> Vec<&'static str> {
+        vec!["gerade", "ungerade"]
+    }
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str> {
+        vec![if zahl % 2 == 0 { "gerade" } else { "ungerade" }]
+    }
+}
+
+#[test]
+fn test_standard_classifiers() {
+    let registry = klassifikatoren();
+
+    assert!(registry.hat(7, "primzahl"));
+    assert!(registry.hat(7, "aussen"));
+    assert!(registry.hat(8, "mond"));
+    assert!(registry.hat(12, "sonnemitmondanteil"));
+    assert!(!registry.hat(5, "planet"));
+    assert_eq!(registry.zelle("gestirn", 6).unwrap(), "sonne, planet, schwarzesonne");
+    assert_eq!(registry.zelle("primkreativitaet", 8).unwrap(), "mondzahl");
+    assert!(registry.zelle("unbekannt", 6).is_err());
+}
+
+#[test]
+fn test_npm() {
+    let registry = klassifikatoren();
+
+    // 1/n columns only exist up to the highest gebrochen-rational parameter
+    assert!(registry.hat(7, "gal1pN"));
+    assert!(!registry.hat(30, "gal1pN"));
+    assert!(registry.hat(30, "galN"));
+    assert_ne!(registry.zelle("npm", 7).unwrap(), registry.zelle("npm", 30).unwrap());
+    assert_eq!(NPmEnum::of_zahl(1), NPmEnum::n());
+    assert_eq!(NPmEnum::of_zahl(GEBROCHEN_SPALTEN_HOECHSTENS).len(), 8);
+}
+
+#[test]
+fn test_registry_filter() -> Result<(), Box<dyn std::error::Error>> {
+    let mut registry = ClassifierRegistry::default();
+    registry.register(Box::new(Gerade));
+
+    let typen = registry.typen("ungerade,mond")?;
+    let zahlen: Vec<i32> = (1..=10).filter(|&z| registry.passt(z, &typen)).collect();
+    assert_eq!(zahlen, vec![1, 3, 4, 5, 7, 8, 9]);
+
+    assert!(registry.typen("foo").is_err());
+    assert!(klassifikatoren().typen("gerade").is_err());
+    Ok(())
+}
+
+/// Multiples of 7, with colors of their own
+struct Siebener;
+
+impl Classifier for Siebener {
+    fn name(&self) -> &'static str {
+        "siebener"
+    }
+
+    fn alle_labels(&self) -> Vec<&'static str> {
+        vec!["siebenfach"]
+    }
+
+    fn labels(&self, zahl: i32) -> Vec<&'static str> {
+        match zahl % 7 {
+            0 => vec!["siebenfach"],
+            _ => Vec::new(),
+        }
+    }
+
+    fn farb_klasse(&self, zahl: i32) -> Option<&'static str> {
+        (zahl % 7 == 0).then_some("siebenfach")
+    }
+
+    fn farben(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        vec![("siebenfach", "#123456", "#abcdef")]
+    }
+
+    fn shell_farben(&self, zahl: i32) -> Option<&'static str> {
+        (zahl % 7 == 0).then_some("\x1b[45m")
+    }
+}
+
+#[test]
+fn test_classifier_registrieren() -> Result<(), Box<dyn std::error::Error>> {
+    classifier_registrieren(Box::new(Siebener));
+    let daten = common::religion(21);
+
+    // Row filter, generated column and grouping go through the shared registry
+    let tabelle = Query::rows("1-21")
+        .typen(["siebenfach"])
+        .klassen(["siebener"])
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![7, 14, 21]);
+    assert_eq!(tabelle.zeilen[0].1[1].text, "siebenfach");
+    let gruppiert = Query::rows("6-8")
+        .gruppiere(Schluessel::parse("siebener")?)
+        .ausfuehren(&daten)?;
+    assert_eq!(gruppiert.zahlen(), vec![7, 6, 8]);
+
+    // Colors: the classifier registered last decides, others stay as they were
+    assert_eq!(farb_klasse(14), Some("siebenfach"));
+    assert_eq!(farben(14), Some(("#123456", "#abcdef")));
+    assert_eq!(shell_farben(21), "\x1b[45m");
+    assert_eq!(farb_klasse(5), Some("primzahl-ungerade"));
+    assert_eq!(farb_klasse(9), Some("mondzahl-ungerade"));
+    assert!(alle_farben().contains(&("siebenfach", "#123456", "#abcdef")));
+    #[cfg(feature = "html_output")]
+    assert!(Thema::Dunkel
+        .farben()
+        .css()
+        .contains("#bigtable .siebenfach { background-color:#123456; color:#abcdef; }"));
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::process::Command;
+
+fn reta(args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    let ausgabe = Command::new(env!("CARGO_BIN_EXE_reta"))
+        .args(args)
+        .output()?;
+    assert!(
+        ausgabe.status.success(),
+        "{}",
+        String::from_utf8_lossy(&ausgabe.stderr)
+    );
+    Ok(String::from_utf8(ausgabe.stdout)?)
+}
+
+fn daten() -> Result<tempfile::TempDir, Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    let zeilen: String = (1..=9).map(|n| format!("{};R{}\n", n, n)).collect();
+    fs::write(
+        ordner.path().join("religion.csv"),
+        format!("Nummer;Religion\n{}", zeilen),
+    )?;
+    Ok(ordner)
+}
+
+#[test]
+fn test_tabelle_gruppen_ohne_endless() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = daten()?;
+    let daten = format!("--daten={}", ordner.path().display());
+    // Not a terminal, so the default shell output is printed as the pager would fall back
+    let text = reta(&["tabelle", "1-9", "--gruppiere=primkreativitaet", &daten])?;
+    for titel in [
+        "primkreativitaet: eins",
+        "primkreativitaet: primzahl",
+        "primkreativitaet: primvielfaches",
+    ] {
+        assert!(text.contains(titel), "{} missing in\n{}", titel, text);
+    }
+    assert_eq!(
+        text,
+        reta(&[
+            "tabelle",
+            "1-9",
+            "--gruppiere=primkreativitaet",
+            &daten,
+            "--endless"
+        ])?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_tabelle_zeilen_gruppe() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = daten()?;
+    let daten = format!("--daten={}", ordner.path().display());
+    let text = reta(&["tabelle", &daten, "--art=csv", "-zeilen", "--alles", "--typ=mond"])?;
+    for zahl in 1..=9 {
+        let zeile = format!("R{}", zahl);
+        assert_eq!(text.contains(&zeile), [4, 8, 9].contains(&zahl), "{}", text);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_tabelle_alte_gruppen() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = daten()?;
+    let daten = format!("--daten={}", ordner.path().display());
+    let alt = reta(&[
+        "tabelle",
+        &daten,
+        "-zeilen",
+        "--vorhervonausschnitt=1-5",
+        "--typ=sonne",
+        "-spalten",
+        "--religionen=Religion",
+        "-ausgabe",
+        "--art=csv",
+        "--onetable",
+    ])?;
+    let neu = reta(&["tabelle", "1-5", &daten, "--spalten=Religion", "--typ=sonne", "--art=csv"])?;
+    assert_eq!(alt, neu);
+    assert!(alt.contains("R5") && !alt.contains("R4"), "{}", alt);
+    Ok(())
+}
+
+#[test]
+fn test_modulo_potenz_diagramm_daten() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = daten()?;
+    let daten = format!("--daten={}", ordner.path().display());
+
+    // The texts come from religion.csv of the chosen data directory, not the working directory
+    let potenz = reta(&["potenz", "1-9", "--spalte=1", "--endless", &daten])?;
+    assert!(potenz.contains("2: R2"), "{}", potenz);
+    assert!(potenz.contains("8: R8"), "{}", potenz);
+
+    let modulo = reta(&["modulo", "5-6", "--basen=2", "--spalte=1", "--endless", &daten])?;
+    assert!(modulo.contains("5: R5"), "{}", modulo);
+    assert!(modulo.contains("6: R6"), "{}", modulo);
+
+    let svg = reta(&["diagramm", "1-9", &daten])?;
+    assert!(svg.starts_with("<svg"), "{}", svg);
+    Ok(())
+}
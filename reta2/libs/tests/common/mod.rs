@@ -0,0 +1,48 @@
+//! Test data shared by the integration tests: small religion.csv and kombi tables
+
+// Each test crate uses only some of these
+#![allow(dead_code)]
+
+use lib4tables_concat::*;
+
+/// A table from rows of `;` separated cells, like a CSV file
+pub fn tabelle(zeilen: &[&str]) -> Table {
+    zeilen
+        .iter()
+        .map(|z| z.split(';').map(str::to_string).collect())
+        .collect()
+}
+
+/// religion.csv with a header and the rows 1 to `bis`: the number, then the cells of `zellen`
+pub fn nummeriert(kopf: &[&str], bis: i32, zellen: impl Fn(i32) -> Vec<String>) -> Daten {
+    let mut tabelle = vec![kopf.iter().map(|k| k.to_string()).collect::<Vec<_>>()];
+    for zahl in 1..=bis {
+        let mut zeile = vec![zahl.to_string()];
+        zeile.extend(zellen(zahl));
+        tabelle.push(zeile);
+    }
+    Daten::new(tabelle)
+}
+
+/// religion.csv of `breite` columns S0, S1, ... and one row z0, z1, ..., wide enough for the
+/// registry's columns below `breite`
+pub fn breit(breite: usize) -> Daten {
+    let kopf: Vec<String> = (0..breite).map(|i| format!("S{}", i)).collect();
+    let zeile: Vec<String> = (0..breite).map(|i| format!("z{}", i)).collect();
+    Daten::new(vec![kopf, zeile])
+}
+
+/// religion.csv wide enough for the registry's columns 4, 8, 9, 10 and 11
+pub fn registry_breit() -> Daten {
+    breit(12)
+}
+
+/// Numbers 1 to `bis` with "R<n>" in the Religion column
+pub fn religion(bis: i32) -> Daten {
+    nummeriert(&["Nummer", "Religion"], bis, |zahl| vec![format!("R{}", zahl)])
+}
+
+/// The crate directory as data source: its spalten.json is the built-in registry
+pub fn datenquelle() -> Datenquelle {
+    Datenquelle::ordner(std::path::Path::new(env!("CARGO_MANIFEST_DIR")))
+}
@@ -0,0 +1,168 @@
+use std::fs;
+use std::path::Path;
+
+use lib4tables_concat::pruefung::quelle_pruefen;
+use lib4tables_concat::*;
+
+const KONFIG: &str = r#"{
+    "daten": "csv",
+    "profil": "de",
+    "profile": {
+        "de": {},
+        "en": {"dateien": {"religion.csv": "csv/religion-en.csv"}},
+        "test": {"daten": "fixtures"}
+    }
+}"#;
+
+/// Column registry of the test data, in place of the built-in one
+const SPALTEN: &str = r#"[
+    {"id": "religion", "namen": {"de": "Religion", "en": "religion"}, "aliase": ["Glaube"],
+     "typ": "ordinary", "quelle": "religion.csv", "spalte": 1}
+]"#;
+
+fn ordner_mit_konfig() -> Result<tempfile::TempDir, Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    fs::create_dir(ordner.path().join("csv"))?;
+    fs::create_dir(ordner.path().join("fixtures"))?;
+    fs::write(
+        ordner.path().join("csv/religion.csv"),
+        "Nummer;Religion\n1;Eins\n2;Zwei\n",
+    )?;
+    fs::write(
+        ordner.path().join("csv/religion-en.csv"),
+        "Number;Religion\n1;One\n2;Two\n",
+    )?;
+    fs::write(
+        ordner.path().join("csv/kombi13.csv"),
+        "Kombi;Tiere\n2;Hund\n",
+    )?;
+    fs::write(
+        ordner.path().join("fixtures/religion.csv"),
+        "Nummer;Religion\n1;Test\n",
+    )?;
+    fs::write(ordner.path().join("csv/spalten.json"), SPALTEN)?;
+    fs::write(ordner.path().join("reta.json"), KONFIG)?;
+    Ok(ordner)
+}
+
+#[test]
+fn test_reihenfolge() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = ordner_mit_konfig()?;
+    let konfig = Konfiguration::laden(&ordner.path().join("reta.json"))?;
+    let flag = Path::new("/daten/flag");
+    let umgebung = Path::new("/daten/umgebung");
+
+    let quelle = konfig.aufloesen(Some(flag), Some("test"), Some(umgebung))?;
+    assert_eq!(
+        (quelle.ordner.as_path(), quelle.herkunft),
+        (flag, Herkunft::Flag)
+    );
+
+    // A profile chosen for the query names its own directory
+    let quelle = konfig.aufloesen(None, Some("test"), Some(umgebung))?;
+    assert_eq!(quelle.ordner, ordner.path().join("fixtures"));
+    assert_eq!(quelle.herkunft, Herkunft::Profil);
+
+    // The default profile has no directory of its own
+    let quelle = konfig.aufloesen(None, None, Some(umgebung))?;
+    assert_eq!(
+        (quelle.ordner.as_path(), quelle.herkunft),
+        (umgebung, Herkunft::Umgebung)
+    );
+    assert_eq!(quelle.profil.as_deref(), Some("de"));
+
+    let quelle = konfig.aufloesen(None, None, None)?;
+    assert_eq!(quelle.ordner, ordner.path().join("csv"));
+    assert_eq!(quelle.herkunft, Herkunft::Konfiguration);
+
+    let leer = Konfiguration::default().aufloesen(None, None, None)?;
+    assert!(matches!(
+        leer.herkunft,
+        Herkunft::NebenProgramm | Herkunft::Arbeitsverzeichnis
+    ));
+    assert!(leer.ordner.ends_with("csv"));
+
+    assert!(konfig.aufloesen(None, Some("fr"), None).is_err());
+    assert_eq!(konfig.profil_namen(), vec!["de", "en", "test"]);
+    Ok(())
+}
+
+#[test]
+fn test_profil_pro_query() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = ordner_mit_konfig()?;
+    let konfig = Konfiguration::laden(&ordner.path().join("reta.json"))?;
+    let query = Query::rows("1-2").columns(["Religion"]);
+    let texte = |profil: &str| -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let quelle = konfig.aufloesen(None, Some(profil), None)?;
+        let tabelle = query.ausfuehren(&Daten::aus_quelle(&quelle)?)?;
+        Ok(tabelle
+            .zeilen
+            .iter()
+            .map(|(_, z)| z[1].text.clone())
+            .collect())
+    };
+    assert_eq!(texte("de")?, vec!["Eins", "Zwei"]);
+    assert_eq!(texte("en")?, vec!["One", "Two"]);
+    assert_eq!(texte("test")?, vec!["Test"]);
+
+    // The English profile swaps religion.csv only; the kombi files stay shared
+    let en = konfig.aufloesen(None, Some("en"), None)?;
+    assert!(Daten::aus_quelle(&en)?.kombis.contains_key(&Kombi::Galaxie));
+    let dateien = en.csv_dateien()?;
+    assert_eq!(
+        dateien.get("religion.csv"),
+        Some(&ordner.path().join("csv/religion-en.csv"))
+    );
+    assert!(dateien.contains_key("kombi13.csv"));
+    Ok(())
+}
+
+#[test]
+fn test_konfiguration_fehler() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    let pfad = ordner.path().join("reta.json");
+    fs::write(&pfad, "{\"profile\": 3}")?;
+    assert!(Konfiguration::laden(&pfad).is_err());
+    assert!(Konfiguration::laden(&ordner.path().join("fehlt.json")).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pruefung_mit_profil() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = ordner_mit_konfig()?;
+    fs::write(
+        ordner.path().join("csv/religion-en.csv"),
+        "Number;Religion\n1;One\nzwei;Two\n",
+    )?;
+    let konfig = Konfiguration::laden(&ordner.path().join("reta.json"))?;
+    assert!(quelle_pruefen(&konfig.aufloesen(None, Some("de"), None)?)?.is_empty());
+    let befunde = quelle_pruefen(&konfig.aufloesen(None, Some("en"), None)?)?;
+    assert_eq!(befunde.len(), 1);
+    assert!(befunde[0].datei.ends_with("religion-en.csv"));
+    Ok(())
+}
+
+#[test]
+fn test_spalten_registry_der_quelle() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = ordner_mit_konfig()?;
+    let konfig = Konfiguration::laden(&ordner.path().join("reta.json"))?;
+    let quelle = konfig.aufloesen(None, None, None)?;
+    assert_eq!(quelle.spalten_registry()?.namen(), vec!["religion"]);
+    let daten = Daten::aus_quelle(&quelle)?;
+    let tabelle = Query::rows("2").columns(["glaube"]).ausfuehren(&daten)?;
+    assert_eq!(tabelle.zeilen[0].1[1].text, "Zwei");
+
+    // Without a spalten.json of its own the built-in registry applies
+    let fixtures = konfig.aufloesen(None, Some("test"), None)?;
+    assert_eq!(
+        fixtures.spalten_registry()?,
+        SpaltenRegistry::standard().clone()
+    );
+
+    fs::write(ordner.path().join("csv/spalten.json"), "[{")?;
+    assert!(quelle.spalten_registry().is_err());
+    let befunde = quelle_pruefen(&quelle)?;
+    assert_eq!(befunde.len(), 1);
+    assert!(befunde[0].datei.ends_with("spalten.json"));
+    Ok(())
+}
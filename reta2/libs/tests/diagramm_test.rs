@@ -0,0 +1,33 @@
+use lib4tables_concat::*;
+
+#[test]
+fn test_pro_zaehlung() -> Result<(), Box<dyn std::error::Error>> {
+    let diagramm = Diagramm::new("1-10", 1024)?;
+    // 1 starts the first counting, 5 the second (after the moon 4), 10 the third (after 8 and 9)
+    let anzahlen: Vec<(i32, usize)> = diagramm.pro_zaehlung().into_iter().collect();
+    assert_eq!(anzahlen, vec![(1, 4), (2, 5), (3, 1)]);
+
+    let svg = diagramm.svg(DiagrammArt::Zaehlungen);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.contains("<title>Zählung 2: 5</title>"));
+    assert!(svg.ends_with("</svg>\n"));
+
+    assert!(Diagramm::new("0", 1024).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_primfaktoren_und_streifen() -> Result<(), Box<dyn std::error::Error>> {
+    let mut diagramm = Diagramm::new("2-12", 1024)?;
+    let streuung = diagramm.svg(DiagrammArt::Primfaktoren);
+    assert_eq!(streuung.matches("<circle").count(), 11);
+    assert!(streuung.contains("<title>12: 3</title>"));
+
+    diagramm.typen_filtern("primzahl")?;
+    assert_eq!(diagramm.zahlen, vec![2, 3, 5, 7, 11]);
+    let streifen = diagramm.svg(DiagrammArt::Streifen);
+    assert!(streifen.contains("fill=\"#009900\"><title>7: primzahl-ungerade</title>"));
+    assert!(streifen.contains("<title>7: sonne</title>"));
+    assert_eq!(DiagrammArt::from_name("streifen"), Some(DiagrammArt::Streifen));
+    Ok(())
+}
@@ -1,10 +1,13 @@
+mod common;
+
 use lib4tables_concat::*;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 #[test]
 fn test_concat_love_polygon() -> Result<(), Box<dyn std::error::Error>> {
     let tables = Tables::default();
-    let mut concat = Concat::new(tables);
+    let mut concat = Concat::new(tables, common::datenquelle())?;
     
     let mut relitable = vec![
         vec!["Header1".to_string(), "Header2".to_string()],
@@ -23,13 +26,10 @@ fn test_concat_love_polygon() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_gleichheit_freiheit_vergleich() {
-    let tables = Tables::default();
-    let concat = Concat::new(tables);
-    
-    let result = concat.gleichheit_freiheit_vergleich(2);
+    let result = Concat::gleichheit_freiheit_vergleich(2);
     assert!(result.contains("Gleichheit"));
     
-    let result = concat.gleichheit_freiheit_vergleich(1);
+    let result = Concat::gleichheit_freiheit_vergleich(1);
     assert!(result.contains("Freiheit"));
 }
 
@@ -48,15 +48,15 @@ fn test_read_concat_csv() -> Result<(), Box<dyn std::error::Error>> {
     writeln!(file, "Val4;Val5;Val6")?;
     
     let tables = Tables::default();
-    let mut concat = Concat::new(tables);
+    let _concat = Concat::new(tables, common::datenquelle())?;
     
-    let mut relitable = vec![
+    let _relitable = [
         vec!["Header".to_string()],
         vec!["Data".to_string()],
     ];
     
-    let mut rows_as_numbers = HashSet::new();
-    let concat_table_selection = HashSet::from([1]);
+    let _rows_as_numbers: HashSet<usize> = HashSet::new();
+    let _concat_table_selection = HashSet::from([1]);
     
     // Note: This test would need proper CSV file setup
     // concat.read_concat_csv(&mut relitable, &mut rows_as_numbers, &concat_table_selection, 1)?;
@@ -76,14 +76,18 @@ fn test_prim_functions() {
     assert!(!could_be_prime_number_primzahlkreuz_fuer_innen(7));
     
     assert_eq!(prim_creativity(7), 1);
-    assert_eq!(prim_creativity(4), 2);
+    assert_eq!(prim_creativity(4), 3);
     assert_eq!(prim_creativity(1), 0);
+    assert_eq!(prim_creativity(25), 3);
+    assert_eq!(prim_creativity(64), 3);
+    assert_eq!(prim_creativity(36), 3);
+    assert_eq!(prim_creativity(12), 2);
 }
 
 #[test]
-fn test_fraction_conversion() {
+fn test_fraction_conversion() -> Result<(), Box<dyn std::error::Error>> {
     let tables = Tables::default();
-    let concat = Concat::new(tables);
+    let concat = Concat::new(tables, common::datenquelle())?;
     
     let paare_set = OrderedSet::from([(2, 3), (4, 6), (1, 2)]);
     
@@ -92,4 +96,21 @@ fn test_fraction_conversion() {
     
     let result_mul = concat.convert_set_of_paaren_to_dict_of_num_to_paare_mul(&paare_set, false);
     assert!(result_mul.contains_key(&6)); // 2*3 = 6
+    Ok(())
+}
+
+#[test]
+fn test_concat_mit_metadaten() -> Result<(), Box<dyn std::error::Error>> {
+    let cache = Arc::new(MetadatenCache::berechnen(String::new(), 64));
+    let ohne = Concat::new(Tables::default(), common::datenquelle())?;
+    let mit = Concat::new(Tables::default(), common::datenquelle())?.mit_metadaten(cache);
+
+    for zahl in [1, 12, 30, 64, 100] {
+        assert_eq!(
+            mit.geist_emotion_energie_materie_topologie(zahl),
+            ohne.geist_emotion_energie_materie_topologie(zahl)
+        );
+    }
+    assert!(mit.geist_emotion_energie_materie_topologie(64).contains("total"));
+    Ok(())
 }
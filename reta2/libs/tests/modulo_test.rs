@@ -0,0 +1,42 @@
+use lib4tables_concat::*;
+
+#[test]
+fn test_bereich_to_numbers2() {
+    let zahlen: Vec<i32> = bereich_to_numbers2("1-5,-3,10", false, 20).into_iter().collect();
+    assert_eq!(zahlen, vec![1, 2, 4, 5, 10]);
+
+    let vielfache: Vec<i32> = bereich_to_numbers2("v7", false, 30).into_iter().collect();
+    assert_eq!(vielfache, vec![7, 14, 21, 28]);
+
+    let nachbarn: Vec<i32> = bereich_to_numbers2("10+1", false, 20).into_iter().collect();
+    assert_eq!(nachbarn, vec![9, 11]);
+
+    // Neighbours beyond i32 are left out instead of overflowing
+    let rand = bereich_to_numbers2("2147483647+2147483647", false, i32::MAX);
+    assert_eq!(rand.into_iter().collect::<Vec<_>>(), Vec::<i32>::new());
+    let rand = bereich_to_numbers2("5+-2147483647", false, i32::MAX);
+    assert_eq!(rand.into_iter().collect::<Vec<_>>(), Vec::<i32>::new());
+    let rand = bereich_to_numbers2("2147483646+1", false, i32::MAX);
+    assert_eq!(rand.into_iter().collect::<Vec<_>>(), vec![2147483645, 2147483647]);
+    // Without a limit, ranges end at BEREICH_HOECHSTENS
+    let offen = bereich_to_numbers2("1-2147483647", false, 0);
+    assert_eq!(offen.len(), BEREICH_HOECHSTENS as usize);
+    assert_eq!(offen.last(), Some(&BEREICH_HOECHSTENS));
+}
+
+#[cfg(feature = "csv_output")]
+#[test]
+fn test_modulo_tabelle() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = ModuloTabelle::new("10-12", "1-4", 100)?;
+    assert_eq!(tabelle.basen, vec![2, 3, 4]);
+
+    let (kopf, zeilen) = tabelle.zeilen(false, false);
+    assert_eq!(kopf.len(), 4);
+    assert_eq!(zeilen[0].1[2].text, "1 (2)");
+
+    let csv = tabelle.ausgeben(&CsvSyntax, false)?;
+    assert!(csv.starts_with("n;mod 2;mod 3;mod 4"));
+
+    assert!(ModuloTabelle::new("10", "1", 100).is_err());
+    Ok(())
+}
@@ -0,0 +1,289 @@
+use lib4tables_concat::*;
+
+#[test]
+fn test_latex_escape() {
+    assert_eq!(latex_escape("50% & $5_#{x}"), "50\\% \\& \\$5\\_\\#\\{x\\}");
+    assert_eq!(latex_escape("a\\b^c~"), "a\\textbackslash{}b\\textasciicircum{}c\\textasciitilde{}");
+}
+
+#[test]
+fn test_latex_listen() {
+    assert_eq!(
+        latex_zelle("<ul><li>a & b</li> | <li>c</li></ul>"),
+        "\\begin{itemize}\\item a \\& b \\item c\\end{itemize}"
+    );
+    assert_eq!(
+        latex_zelle("[list][*]x[*]y"),
+        "\\begin{itemize}\\item x \\item y\\end{itemize}"
+    );
+}
+
+#[test]
+fn test_latex_mehrzeilig() {
+    assert_eq!(latex_zelle("Liebe<br>50%\nFrieden"), "Liebe\\newline{}50\\%\\newline{}Frieden");
+
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text"), Zelle::new("Wort")];
+    let zeilen = vec![(2, vec![Zelle::new("2"), Zelle::new("eins<br>zwei"), Zelle::new("drei")])];
+    let latex = LatexSyntax.format_table(&kopf, &zeilen).unwrap();
+    assert!(latex.starts_with("\\begin{tabular}{lp{0.3\\linewidth}l}\n"));
+    assert!(latex.contains("eins\\newline{}zwei"));
+}
+
+#[test]
+fn test_latex_table() {
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text")];
+    let zeilen: Vec<(i32, Vec<Zelle>)> = (1..=3)
+        .map(|i| (i, vec![Zelle::new(i.to_string()), Zelle::new("<li>eins</li>")]))
+        .collect();
+    let latex = LatexSyntax.format_table(&kopf, &zeilen).unwrap();
+
+    assert!(latex.starts_with("\\begin{tabular}{lp{0.3\\linewidth}}\n\\toprule\n"));
+    assert!(latex.contains("\\midrule\n"));
+    assert!(latex.contains("\\rowcolor[HTML]{009900}"));
+    assert!(latex.ends_with("\\bottomrule\n\\end{tabular}\n"));
+
+    let viele: Vec<(i32, Vec<Zelle>)> =
+        (1..=50).map(|i| (i, vec![Zelle::new(i.to_string())])).collect();
+    let lang = LatexSyntax.format_table(&kopf[..1], &viele).unwrap();
+    assert!(lang.starts_with("\\begin{longtable}{l}"));
+    assert!(lang.contains("\\endhead\n"));
+}
+
+#[test]
+fn test_strukturierte_tabelle() -> Result<(), Box<dyn std::error::Error>> {
+    let kopf = vec![
+        Zelle::new("n"),
+        Zelle::new("Text").mit_parameter("Mond", vec![ST::Galaxie, ST::Universum]),
+    ];
+    let zeilen = vec![
+        (4, vec![Zelle::new("4"), Zelle::new("abcdef<br>gh")]),
+        (5, vec![Zelle::new("5"), Zelle::new("")]),
+    ];
+    let tabelle = StrukturierteTabelle::new(&kopf, &zeilen, &Zaehlungen::new(5), Some(4));
+    assert_eq!(tabelle.zeilen[0].zellen[1].zeilen, vec!["abcd", "ef", "gh"]);
+    assert_eq!(tabelle.zeilen[1].zaehlung, Some(2));
+    assert_eq!(tabelle.spalten[1].parameter, Some(("Mond".to_string(), "Text".to_string())));
+    assert_eq!(tabelle.spalten[1].tags, vec![ST::Galaxie, ST::Universum]);
+
+    let json: serde_json::Value = serde_json::from_str(&tabelle.json()?)?;
+    assert_eq!(json["zeilen"][0]["zeile"], 4);
+    assert_eq!(json["spalten"][1]["parameter"], serde_json::json!(["Mond", "Text"]));
+
+    let jsonl = JsonLinesSyntax.format_table(&kopf, &zeilen)?;
+    assert_eq!(jsonl.lines().count(), 3);
+    for zeile in jsonl.lines() {
+        serde_json::from_str::<serde_json::Value>(zeile)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_org_tabelle() {
+    let kopf = vec![Zelle::new("n"), Zelle::new("a|b")];
+    let zeilen = vec![
+        (7, vec![Zelle::new("7"), Zelle::new("x<br>y")]),
+        (10, vec![Zelle::new("10"), Zelle::new("z")]),
+    ];
+    let org = org_tabelle(&kopf, &zeilen, &[2, 6], Some("Meine Tabelle"));
+
+    assert!(org.contains("#+NAME: meine-tabelle\n#+CAPTION: Meine Tabelle\n"));
+    assert!(org.contains("#+ATTR_HTML: :class meine-tabelle-tabelle\n"));
+    assert!(org.contains("tr:nth-child(1) { background-color:#009900; color:#ffffff; }"));
+    assert!(org.contains("| / | <>   | <>"));
+    assert!(org.contains("|   | <r2> | <l6>"));
+    assert!(org.contains("|   |    7 | x @@html:<br>@@ y"));
+    assert!(org.contains("a\\vert{}b"));
+    assert!(!org.contains("#+BEGIN_EXPORT html"));
+
+    // Untitled tables of one file get names and classes of their own
+    let namen: Vec<String> = [&zeilen[..1], &zeilen[1..]]
+        .iter()
+        .map(|zeilen| {
+            let org = EmacsSyntax.format_table(&kopf, zeilen).unwrap();
+            let name = org.lines().find_map(|l| l.strip_prefix("#+NAME: ")).unwrap_or_default();
+            assert!(org.contains(&format!("#+ATTR_HTML: :class {}-tabelle\n", name)));
+            name.to_string()
+        })
+        .collect();
+    assert!(namen[0].starts_with("reta-7-7-"), "{}", namen[0]);
+    assert!(namen[1].starts_with("reta-10-10-"), "{}", namen[1]);
+    // The same table keeps its name between runs
+    assert_eq!(
+        EmacsSyntax.format_table(&kopf, &zeilen[..1]).unwrap(),
+        EmacsSyntax.format_table(&kopf, &zeilen[..1]).unwrap()
+    );
+
+    let listen = vec![(1, vec![Zelle::new("1"), Zelle::new("<ul><li>a</li><li>b</li></ul>")])];
+    let org = EmacsSyntax.format_table(&kopf, &listen).unwrap();
+    assert!(org.contains("| a; b"));
+    if cfg!(feature = "html_output") {
+        assert!(org.starts_with("#+BEGIN_EXPORT html\n<table"));
+        assert!(org.ends_with("#+END_COMMENT\n"));
+        // The exported HTML keeps the list as a list, only its items are escaped
+        let export = &org[..org.find("#+END_EXPORT").unwrap_or(0)];
+        assert!(export.contains("<td><ul><li>a</li><li>b</li></ul></td>"));
+        assert!(!export.contains("&lt;li&gt;"));
+    } else {
+        assert!(!org.contains("#+BEGIN_EXPORT html"));
+    }
+}
+
+#[cfg(feature = "html_output")]
+#[test]
+fn test_html_dokument() {
+    let kopf = vec![
+        Zelle::new("n"),
+        Zelle::new("Mond").mit_parameter("Mond Typ", vec![ST::Galaxie]),
+    ];
+    let zeilen = vec![(7, vec![Zelle::new("7"), Zelle::farbig("<b>", 6)])];
+    let tabelle = StrukturierteTabelle::gezaehlt(&kopf, &zeilen, None);
+
+    let html = html_dokument::html_dokument(&tabelle, &[vec![None, Some(6)]], Thema::Dunkel, "A & B", "en");
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>A &amp; B</title>"));
+    assert!(html.contains("position:sticky"));
+    assert!(html.contains("#bigtable .primzahl-ungerade { background-color:#0f3f0f;"));
+    assert!(html.contains("<th class=\"z_0 r_1 p1_Mond_Typ p2_Mond p4_Galaxie\" data-spalte=\"1\">"));
+    assert!(html.contains("<tr class=\"primzahl-ungerade\" data-zeile=\"7\" data-zaehlung=\"2\">"));
+    assert!(html.contains("<td class=\"z_7 r_1 vielfaches-gerade\">&lt;b&gt;</td>"));
+    assert!(html.contains("<script>"));
+    assert!(html.contains("<html lang=\"en\">"));
+
+    let liste = vec![(2, vec![Zelle::new("2"), Zelle::new("a<li>eins</li><li>zwei</li>")])];
+    let html = html_dokument::html_dokument_aus_zellen(&kopf, &liste, Thema::Hell, "reta");
+    assert!(html.contains("<html lang=\"de\">"));
+    assert!(html.contains("<td class=\"z_2 r_1\">a<ul><li>eins</li><li>zwei</li></ul></td>"));
+
+    let syntax = syntax_fuer_art("html-dokument").unwrap();
+    assert!(syntax.format_table(&kopf, &zeilen).unwrap().contains("#bigtable .null { background-color:#ff2222;"));
+}
+
+#[test]
+fn test_tabellenkalkulation() {
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text").mit_parameter("Mond", Vec::new())];
+    let zeilen = vec![(7, vec![Zelle::new("7"), Zelle::farbig("a<br>b & c", 6)])];
+    let tabelle = StrukturierteTabelle::gezaehlt(&kopf, &zeilen, None);
+
+    let mut dokument = Tabellenkalkulation::new();
+    dokument.blatt_hinzufuegen(Arbeitsblatt::aus_zellen("main", &kopf, &zeilen));
+    dokument.blatt_hinzufuegen(Arbeitsblatt::new("kombi13/15", tabelle));
+
+    let xml = dokument.content_xml();
+    assert!(xml.contains(r#"<table:table table:name="main">"#));
+    assert!(xml.contains(r#"<table:table table:name="kombi13_15">"#));
+    assert!(xml.contains(r#"style:name="primzahl-ungerade""#));
+    assert!(xml.contains(r#"<table:table-cell table:style-name="primzahl-ungerade" office:value-type="float" office:value="7">"#));
+    assert!(xml.contains(r#"table:style-name="vielfaches-gerade" office:value-type="string"><text:p>a</text:p><text:p>b &amp; c</text:p>"#));
+    assert!(xml.contains("<office:annotation><dc:creator>reta</dc:creator><text:p>Mond</text:p></office:annotation>"));
+
+    let liste = vec![(2, vec![Zelle::new("2"), Zelle::new("vor<li>eins</li><li>a & b</li>")])];
+    let mut mit_liste = Tabellenkalkulation::new();
+    mit_liste.blatt_hinzufuegen(Arbeitsblatt::aus_zellen("main", &kopf, &liste));
+    assert!(mit_liste.content_xml().contains(
+        "<text:p>vor</text:p><text:list><text:list-item><text:p>eins</text:p></text:list-item>\
+         <text:list-item><text:p>a &amp; b</text:p></text:list-item></text:list>"
+    ));
+
+    let ods = dokument.ods();
+    assert_eq!(&ods[..4], b"PK\x03\x04");
+    assert_eq!(&ods[30..38], b"mimetype");
+    assert_eq!(&ods[38..84], b"application/vnd.oasis.opendocument.spreadsheet");
+}
+
+/// A third-party format that only implements the hooks
+struct TsvSyntax;
+
+impl OutputSyntax for TsvSyntax {
+    fn name(&self) -> &'static str {
+        "tsv"
+    }
+
+    fn begin_dokument(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&format!("# {} Zeilen\n", tabelle.zeilen.len()));
+    }
+
+    fn end_kopf(&self, tabelle: &TabellenKontext, out: &mut String) {
+        out.push_str(&vec!["--"; tabelle.kopf.len()].join("\t"));
+        out.push('\n');
+    }
+
+    fn zellen_trenner(&self) -> &'static str {
+        "\t"
+    }
+
+    fn zelle(&self, zelle: &ZellenKontext) -> String {
+        if zelle.kopf {
+            self.format(&zelle.zelle.text)
+        } else {
+            format!("{}:{}={}", zelle.zeile, zelle.spalte, zelle.zelle.text)
+        }
+    }
+}
+
+#[test]
+fn test_syntax_registry() {
+    let registry = SyntaxRegistry::default();
+    assert_eq!(registry.get("").unwrap().name(), "shell");
+    assert_eq!(registry.get("tex").unwrap().name(), "latex");
+    assert_eq!(registry.get("jsonlines").unwrap().name(), "jsonl");
+    assert!(registry.get("tsv").is_none());
+
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text")];
+    let zeilen = vec![(2, vec![Zelle::new("2"), Zelle::new("a;b")])];
+    assert_eq!(NichtsSyntax.format_table(&kopf, &zeilen).unwrap(), "");
+
+    assert!(syntax_fuer_art("tsv").is_none());
+    syntax_registrieren(std::sync::Arc::new(TsvSyntax));
+    let tsv = syntax_fuer_art("tsv").unwrap();
+    assert_eq!(tsv.format_table(&kopf, &zeilen).unwrap(), "# 1 Zeilen\nn\tText\n--\t--\n2:0=2\t2:1=a;b\n");
+    assert!(syntax_namen().contains(&"tsv"));
+}
+
+#[cfg(all(feature = "html_output", feature = "csv_output", feature = "markdown_output"))]
+#[test]
+fn test_optionale_formate() {
+    let registry = SyntaxRegistry::default();
+    assert!(registry.namen().contains(&"html-dokument"));
+
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text")];
+    let zeilen = vec![(2, vec![Zelle::new("2"), Zelle::new("a;b")])];
+    assert_eq!(CsvSyntax.format_table(&kopf, &zeilen).unwrap(), "n;Text\n2;\"a;b\"\n");
+    assert_eq!(MarkdownSyntax.format_table(&kopf, &zeilen).unwrap(), "| n | Text |\n|:--|:--|\n| 2 | a;b |\n");
+    assert!(ShellSyntax.kann_farben() && !CsvSyntax.kann_farben());
+}
+
+#[test]
+fn test_typst() {
+    assert_eq!(typst_escape("#a [b] 2*3 // x"), "\\#a \\[b\\] 2\\*3 \\// x");
+    assert_eq!(typst_escape("- a<br>= b"), "\\- a \\\n\\= b");
+    assert_eq!(typst_zelle("<li>a_1</li><li>b</li>"), "\n- a\\_1\n- b\n");
+
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text")];
+    let zeilen = vec![
+        (7, vec![Zelle::new("7"), Zelle::new("sieben")]),
+        (6, vec![Zelle::new("6"), Zelle::farbig("sechs", 2)]),
+    ];
+    let typst = syntax_fuer_art("typst").unwrap().format_table(&kopf, &zeilen).unwrap();
+    assert!(typst.starts_with("#table(\n  columns: 2,\n"));
+    assert!(typst.contains("  fill: (x, y) => (none, rgb(\"#009900\"), rgb(\"#ffff66\")).at(y, default: none),\n"));
+    assert!(typst.contains("  table.header([#strong[n]], [#strong[Text]]),\n"));
+    assert!(typst.contains("  [#text(fill: rgb(\"#ffffff\"))[7]], [#text(fill: rgb(\"#ffffff\"))[sieben]],\n"));
+    assert!(typst.contains("  [#text(fill: rgb(\"#000099\"))[6]], table.cell(fill: rgb(\"#66ff66\"))[#text(fill: rgb(\"#000000\"))[sechs]],\n"));
+    assert!(typst.ends_with(")\n"));
+}
+
+#[test]
+fn test_asciidoc() {
+    assert_eq!(asciidoc_zelle("a|b<br>c"), "|a\\|b +\nc");
+    assert_eq!(asciidoc_zelle("Monde:<li>a</li><li>b</li>"), "a|Monde:\n\n* a\n* b\n");
+
+    let kopf = vec![Zelle::new("n"), Zelle::new("Text")];
+    let zeilen = vec![
+        (1, vec![Zelle::new("1"), Zelle::new("eins")]),
+        (2, vec![Zelle::new("2"), Zelle::new("[*]x[*]y")]),
+    ];
+    assert_eq!(
+        syntax_fuer_art("adoc").unwrap().format_table(&kopf, &zeilen).unwrap(),
+        "[cols=\"2*\",options=\"header\"]\n|===\n|n |Text\n\n|1 |eins\n|2 a|* x\n* y\n\n|===\n"
+    );
+}
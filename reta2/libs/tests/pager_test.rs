@@ -0,0 +1,111 @@
+use lib4tables_concat::*;
+
+fn tabelle() -> (Vec<Zelle>, Vec<(i32, Vec<Zelle>)>) {
+    let kopf: Vec<Zelle> = std::iter::once(Zelle::new("n"))
+        .chain((1..=6).map(|i| Zelle::new(format!("Spalte {}", i))))
+        .collect();
+    let zeilen = (1..=30)
+        .map(|n| {
+            let mut zellen = vec![Zelle::farbig(n.to_string(), n)];
+            zellen.extend((1..=6).map(|i| Zelle::new(format!("text {}-{}", n, i))));
+            (n, zellen)
+        })
+        .collect();
+    (kopf, zeilen)
+}
+
+#[test]
+fn test_pager_blaettern_und_springen() {
+    let (kopf, zeilen) = tabelle();
+    let mut pager = Pager::new(&kopf, &zeilen, 30, 10);
+    assert!(!pager.passt());
+
+    let bild = pager.bildschirm();
+    assert_eq!(bild.len(), 10);
+    assert!(bild[0].contains("Spalte 1"));
+    assert!(bild[1].contains(&format!("{}1 \x1b[0m", shell_farben(1))));
+
+    assert!(pager.taste(Taste::BildRunter));
+    assert_eq!(pager.oben, 8);
+    // The header stays frozen
+    assert!(pager.bildschirm()[0].contains("Spalte 1"));
+    assert!(pager.taste(Taste::Ende));
+    assert_eq!(pager.oben, 22);
+
+    for taste in [Taste::Zeichen(':'), Taste::Zeichen('1'), Taste::Zeichen('7'), Taste::Enter] {
+        pager.taste(taste);
+    }
+    assert_eq!(pager.oben, 16);
+    assert!(pager.springen(3));
+    assert_eq!(pager.oben, 2);
+
+    pager.taste(Taste::Rechts);
+    assert_eq!(pager.links, 2);
+    assert!(!pager.bildschirm()[0].contains("Spalte 1"));
+    assert!(pager.bildschirm()[0].contains("n "));
+    assert!(!pager.taste(Taste::Zeichen('q')));
+}
+
+#[test]
+fn test_pager_suche_und_ansicht() {
+    let (kopf, zeilen) = tabelle();
+    let mut pager = Pager::new(&kopf, &zeilen, 30, 10);
+
+    // Incremental: "text 2" finds row 2 at once, "text 25" then row 25
+    for c in "/text 2".chars() {
+        pager.taste(Taste::Zeichen(c));
+    }
+    assert_eq!(pager.oben, 1);
+    pager.taste(Taste::Zeichen('5'));
+    pager.taste(Taste::Enter);
+    // Row 25 would be at the top, but the last page starts at row 23
+    assert_eq!(pager.oben, 22);
+    assert!(pager.bildschirm()[3].contains("\x1b[7m"));
+    pager.taste(Taste::Zeichen('n'));
+    assert_eq!(pager.suche.as_deref(), Some("text 25"));
+
+    pager.taste(Taste::Anfang);
+    pager.taste(Taste::Zeichen('t'));
+    assert_eq!(pager.ansicht, Ansicht::Geteilt);
+    // Two columns of 10 fit next to "n", so 7 columns become 3 split tables
+    pager.taste(Taste::Rechts);
+    assert_eq!(pager.oben, 31);
+    assert!(pager.bildschirm()[0].contains("Spalte 3"));
+    pager.taste(Taste::Zeichen('t'));
+    assert_eq!(pager.ansicht, Ansicht::EineTabelle);
+    assert_eq!(pager.oben, 0);
+}
+
+#[test]
+fn test_pager_gruppen() {
+    let (kopf, zeilen) = tabelle();
+    let gruppen = vec![
+        Gruppe {
+            ab: 0,
+            titel: "erste".to_string(),
+        },
+        Gruppe {
+            ab: 2,
+            titel: "zweite".to_string(),
+        },
+    ];
+    let mut pager = Pager::new(&kopf, &zeilen, 30, 10).mit_gruppen(&gruppen);
+    let bild = pager.bildschirm();
+    assert!(bild[1].contains("erste"));
+    assert!(bild[2].contains(&format!("{}1 \x1b[0m", shell_farben(1))));
+    assert!(bild[4].contains("zweite"));
+    // Jumps land on the row, not on its heading
+    assert!(pager.springen(3));
+    assert_eq!(pager.oben, 4);
+}
+
+#[test]
+fn test_pager_abbrechen() {
+    let (kopf, zeilen) = tabelle();
+    let mut pager = Pager::new(&kopf, &zeilen, 30, 10);
+    // Ctrl-C quits even while a search is typed, where q would be part of it
+    for c in "/q".chars() {
+        assert!(pager.taste(Taste::Zeichen(c)));
+    }
+    assert!(!pager.taste(Taste::Abbrechen));
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use lib4tables_concat::*;
+
+fn daten() -> Daten {
+    common::nummeriert(&["Nummer", "Religion", "Motiv"], 10, |zahl| {
+        let motiv = if zahl % 2 == 0 { "gerade" } else { "" };
+        vec![format!("R{}", zahl), motiv.to_string()]
+    })
+}
+
+fn texte(zellen: &[Zelle]) -> Vec<&str> {
+    zellen.iter().map(|z| z.text.as_str()).collect()
+}
+
+#[test]
+fn test_transponiert() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("2,3,5")
+        .anordnung(Anordnung::Transponiert)
+        .ausfuehren(&daten())?;
+    assert_eq!(texte(&tabelle.kopf), vec!["n", "2", "3", "5"]);
+    assert_eq!(tabelle.kopf[1].farbe, Some(2));
+    assert_eq!(tabelle.zeilen.len(), 2);
+    assert_eq!(tabelle.zeilen[0].0, 1);
+    assert_eq!(
+        texte(&tabelle.zeilen[0].1),
+        vec!["Religion", "R2", "R3", "R5"]
+    );
+    assert_eq!(texte(&tabelle.zeilen[1].1), vec!["Motiv", "gerade", "", ""]);
+    assert_eq!(tabelle.spalten[2].quelle, SpaltenQuelle::Nummer(3));
+
+    // Transposing twice gives the table back
+    let zurueck = tabelle.transponiert().transponiert();
+    assert_eq!(texte(&zurueck.zeilen[1].1), texte(&tabelle.zeilen[1].1));
+    Ok(())
+}
+
+#[test]
+fn test_pivot() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = daten();
+    let tabelle = Query::rows("1-10")
+        .anordnung(Anordnung::Pivot(Pivot::parse("primkreativitaet")?))
+        .ausfuehren(&daten)?;
+    assert_eq!(
+        texte(&tabelle.kopf),
+        vec!["primkreativitaet", "eins", "primzahl", "primvielfaches", "mondzahl"]
+    );
+    assert_eq!(texte(&tabelle.zeilen[0].1)[2], "2, 3, 5, 7");
+    assert_eq!(texte(&tabelle.zeilen[0].1)[4], "4, 8, 9");
+    assert_eq!(texte(&tabelle.zeilen[2].1)[2], "gerade (2)");
+    assert_eq!(texte(&tabelle.zeilen[2].1)[3], "gerade (6, 10)");
+    assert_eq!(texte(&tabelle.zeilen[2].1)[4], "gerade (4, 8)");
+
+    let gezaehlt = Query::rows("1-10")
+        .anordnung(Anordnung::Pivot(Pivot::parse("primkreativitaet:zaehlen")?))
+        .ausfuehren(&daten)?;
+    assert_eq!(texte(&gezaehlt.zeilen[0].1), vec!["n", "1", "4", "2", "3"]);
+    assert_eq!(texte(&gezaehlt.zeilen[2].1), vec!["Motiv", "0", "1", "2", "2"]);
+
+    assert!(Pivot::parse("komet").is_err());
+    assert!(Pivot::parse("gestirn:summe").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_gruppierung_zaehlung() -> Result<(), Box<dyn std::error::Error>> {
+    let zahlen: Vec<i32> = (1..=10).collect();
+    let zaehlungen = Zaehlungen::new(10);
+    let gruppen = Gruppierung::parse("zaehlung")?.gruppen(&zahlen)?;
+    for (zaehlung, nummern) in &gruppen {
+        for zahl in nummern {
+            assert_eq!(
+                zaehlungen
+                    .zeile_which_zaehlung(*zahl)
+                    .map(|z| z.to_string()),
+                Some(zaehlung.clone())
+            );
+        }
+    }
+    assert_eq!(gruppen.values().map(Vec::len).sum::<usize>(), 10);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use lib4tables_concat::*;
+
+#[test]
+fn test_get_logarithm_only_as_pure_int() {
+    assert_eq!(get_logarithm_only_as_pure_int(64, 4), Some(3));
+    assert_eq!(get_logarithm_only_as_pure_int(1, 7), Some(0));
+    assert_eq!(get_logarithm_only_as_pure_int(12, 2), None);
+    assert_eq!(get_logarithm_only_as_pure_int(8, 1), None);
+}
+
+#[cfg(feature = "csv_output")]
+#[test]
+fn test_potenz_tabelle() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tabelle = PotenzTabelle::new("2-8,64", 1024)?;
+    let beziehungen: Vec<(i32, i32, i32)> = tabelle
+        .beziehungen
+        .iter()
+        .map(|p| (p.basis, p.exponent, p.ergebnis))
+        .collect();
+    assert_eq!(
+        beziehungen,
+        vec![(2, 2, 4), (2, 3, 8), (2, 6, 64), (4, 3, 64), (8, 2, 64)]
+    );
+    // Bases and exponents outside the selection are left out
+    let beziehungen: Vec<(i32, i32)> = PotenzTabelle::new("2-4,64", 1024)?
+        .beziehungen
+        .iter()
+        .map(|p| (p.basis, p.exponent))
+        .collect();
+    assert_eq!(beziehungen, vec![(2, 2), (4, 3)]);
+    assert!(PotenzTabelle::new("60-70", 1024)?.beziehungen.is_empty());
+
+    let mut relitable: Table = (0..=64).map(|i| vec![i.to_string(), format!("Zeile {}", i)]).collect();
+    relitable[0][1] = "Name".to_string();
+    tabelle.texte_setzen(&relitable, 1)?;
+    assert!(tabelle.ausgeben(&CsvSyntax, false)?.contains("2: Zeile 2;6: Zeile 6;64: Zeile 64"));
+    assert!(tabelle.texte_setzen(&relitable, 2).is_err());
+
+    assert!(PotenzTabelle::new("0", 1024).is_err());
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::fs;
+
+use lib4tables_concat::pruefung::{bruch_lesen, datei_pruefen, ordner_pruefen, spalten_pruefen};
+use lib4tables_concat::*;
+
+#[test]
+fn test_schema_fuer_datei() {
+    assert_eq!(
+        Schema::fuer_datei("religion.csv"),
+        Some(Schema {
+            erste_spalte: ErsteSpalte::Zahl,
+            fortlaufend: true,
+        })
+    );
+    assert_eq!(
+        Schema::fuer_datei("kombi15.csv").map(|s| s.erste_spalte),
+        Some(ErsteSpalte::Kombi)
+    );
+    assert_eq!(
+        Schema::fuer_datei("bruchStrukGroesse.csv").map(|s| s.erste_spalte),
+        Some(ErsteSpalte::Bruch)
+    );
+    assert_eq!(Schema::fuer_datei("notizen.csv"), None);
+}
+
+#[test]
+fn test_datei_pruefen() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    let religion = ordner.path().join("religion.csv");
+    fs::write(
+        &religion,
+        "Nummer;Religion;Motiv\n1;Eins;Anfang\n2;Zwei\nzwei;Drei;Wissen\n5;Vier;Ordnung;zuviel\n",
+    )?;
+    let befunde = datei_pruefen(&religion, Schema::fuer_datei("religion.csv").unwrap())?;
+    let stellen: Vec<(u64, usize)> = befunde.iter().map(|b| (b.zeile, b.spalte)).collect();
+    assert_eq!(stellen, vec![(3, 3), (4, 1), (5, 4), (5, 1)]);
+    assert_eq!(befunde[0].meldung, "expected 3 columns, found 2");
+    assert!(befunde[3].meldung.contains("expected 4"));
+    assert!(befunde[0]
+        .to_string()
+        .ends_with("religion.csv:3:3: expected 3 columns, found 2"));
+
+    // Quoted cells may hold the separator
+    let kombi = ordner.path().join("kombi13.csv");
+    fs::write(
+        &kombi,
+        "Kombi;Tiere\n2/3;\"Hund; Katze\"\n(4);Maus\n3/x;Igel\n",
+    )?;
+    let befunde = datei_pruefen(&kombi, Schema::fuer_datei("kombi13.csv").unwrap())?;
+    assert_eq!(befunde.len(), 1);
+    assert_eq!((befunde[0].zeile, befunde[0].spalte), (4, 1));
+    assert!(befunde[0].meldung.contains("invalid kombi number"));
+
+    let bruch = ordner.path().join("bruch13.csv");
+    fs::write(&bruch, "Bruch;Text\n1/2;halb\n3;drei\n1/0;nichts\n")?;
+    let befunde = datei_pruefen(&bruch, Schema::fuer_datei("bruch13.csv").unwrap())?;
+    assert_eq!(befunde.len(), 1);
+    assert_eq!(befunde[0].zeile, 4);
+
+    let leer = ordner.path().join("prim.csv");
+    fs::write(&leer, "")?;
+    let befunde = datei_pruefen(&leer, Schema::fuer_datei("prim.csv").unwrap())?;
+    assert_eq!(befunde[0].meldung, "empty file");
+    Ok(())
+}
+
+#[test]
+fn test_bruch_lesen() {
+    assert_eq!(bruch_lesen(" 3/5 "), Ok(num_rational::Ratio::new(3, 5)));
+    assert_eq!(bruch_lesen("4"), Ok(num_rational::Ratio::from_integer(4)));
+    assert!(bruch_lesen("drei").is_err());
+    assert!(bruch_lesen("2/0").is_err());
+}
+
+#[test]
+fn test_ordner_pruefen() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    fs::write(ordner.path().join("notizen.csv"), "frei;geschrieben\nx\n")?;
+    fs::write(ordner.path().join("prim.csv"), "Zahl;Text\n2;zwei\n")?;
+    let befunde = ordner_pruefen(ordner.path())?;
+    assert_eq!(befunde.len(), 1);
+    assert_eq!(befunde[0].meldung, "file missing");
+
+    fs::write(
+        ordner.path().join("religion.csv"),
+        "Nummer;Religion\n1;Eins\n",
+    )?;
+    fs::write(
+        ordner.path().join("spalten.json"),
+        r#"[{"id": "religion", "typ": "ordinary", "quelle": "religion.csv", "spalte": 1}]"#,
+    )?;
+    assert!(ordner_pruefen(ordner.path())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_spalten_pruefen() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    let quelle = Datenquelle::ordner(ordner.path());
+    let mut kopf: Vec<String> = (0..10).map(|i| format!("S{}", i)).collect();
+    kopf[4] = "strukturgröße".to_string();
+    kopf[5] = "Strukturalie".to_string();
+    kopf[8] = " Liebe ".to_string();
+    fs::write(
+        ordner.path().join("religion.csv"),
+        format!("{}\n", kopf.join(";")),
+    )?;
+    let registry = SpaltenRegistry::aus_json(
+        r#"[
+        {"id": "strukturgroesse", "namen": {"de": "Strukturgröße"}, "typ": "ordinary",
+         "quelle": "religion.csv", "spalte": 4},
+        {"id": "strukturalie", "typ": "ordinary", "aliase": ["strukturalie"],
+         "quelle": "religion.csv", "spalte": 5},
+        {"id": "liebe", "namen": {"de": "Liebe"}, "typ": "ordinary",
+         "quelle": "religion.csv", "spalte": 8},
+        {"id": "wirkung", "namen": {"de": "Wirkung"}, "typ": "ordinary",
+         "quelle": "religion.csv", "spalte": 9},
+        {"id": "energie", "typ": "ordinary", "quelle": "religion.csv", "spalte": 242},
+        {"id": "tiere", "typ": "kombi1", "quelle": "kombi13.csv", "spalte": 1}
+    ]"#,
+    )?;
+    let befunde = spalten_pruefen(&registry, &quelle)?;
+    let meldungen: Vec<String> = befunde.iter().map(ToString::to_string).collect();
+    assert_eq!(meldungen.len(), 2);
+    assert!(meldungen[0].ends_with("religion.csv:1:10: column wirkung has the header \"S9\""));
+    assert!(meldungen[1]
+        .ends_with("religion.csv:1:0: column energie is number 242, the header has 10 columns"));
+    Ok(())
+}
@@ -0,0 +1,232 @@
+mod common;
+
+use std::fs;
+
+use lib4tables_concat::query::kombi_zahlen;
+use lib4tables_concat::*;
+
+use common::tabelle;
+
+fn daten() -> Daten {
+    let mut daten = Daten::new(tabelle(&[
+        "Nummer;Religion;Motiv",
+        "1;Eins;Anfang",
+        "2;Zwei;Liebe",
+        "3;Drei;Wissen",
+        "4;Vier;Ordnung",
+        "5;Fünf;Freiheit",
+    ]));
+    daten.kombi_setzen(
+        Kombi::Galaxie,
+        tabelle(&[
+            "Kombi;Tiere;Pflanzen",
+            "2/3;Hund;Eiche",
+            "(3);Katze;",
+            "-5;;Rose",
+        ]),
+    );
+    daten
+}
+
+#[test]
+fn test_rows_and_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1-10,-4")
+        .columns(["Motiv"])
+        .columns([1])
+        .ausfuehren(&daten())?;
+    // Rows past the end of religion.csv are left out
+    assert_eq!(tabelle.zahlen(), vec![1, 2, 3, 5]);
+    let kopf: Vec<&str> = tabelle.kopf.iter().map(|z| z.text.as_str()).collect();
+    assert_eq!(kopf, vec!["n", "Motiv", "Religion"]);
+    assert_eq!(tabelle.zeilen[1].1[1].text, "Liebe");
+    assert_eq!(tabelle.spalten[2].quelle, SpaltenQuelle::Religion(1));
+
+    // Without columns, all but the number column
+    let alle = Query::rows("2").ausfuehren(&daten())?;
+    assert_eq!(alle.kopf.len(), 3);
+
+    assert!(Query::rows("1")
+        .columns(["Farbe"])
+        .ausfuehren(&daten())
+        .is_err());
+    assert!(Query::rows("1").columns([7]).ausfuehren(&daten()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_kombi() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1-5")
+        .columns([1])
+        .kombi(Kombi::Galaxie, Vec::<Spalte>::new())
+        .ausfuehren(&daten())?;
+    let zeile = |zahl: i32| {
+        tabelle
+            .zeilen
+            .iter()
+            .find(|(z, _)| *z == zahl)
+            .map(|(_, zellen)| zellen.iter().map(|z| z.text.clone()).collect::<Vec<_>>())
+            .unwrap()
+    };
+    assert_eq!(
+        tabelle.spalten[3].quelle,
+        SpaltenQuelle::Kombi(Kombi::Galaxie, 2)
+    );
+    assert_eq!(zeile(1), vec!["1", "Eins", "", ""]);
+    assert_eq!(
+        zeile(2),
+        vec!["2", "Zwei", "(2/3) Hund (2/3)", "(2/3) Eiche (2/3)"]
+    );
+    assert_eq!(zeile(3)[2], "(2/3) Hund (2/3); ((3)) Katze ((3))");
+    assert_eq!(zeile(5)[3], "(-5) Rose (-5)");
+
+    assert!(Query::rows("1")
+        .kombi(Kombi::Universum, [1])
+        .ausfuehren(&daten())
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_kombi_zahlen() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(kombi_zahlen("3/5")?, vec![3, 5]);
+    assert_eq!(kombi_zahlen("(4)")?, vec![4]);
+    assert_eq!(kombi_zahlen(" -2 ")?, vec![2]);
+    assert!(kombi_zahlen("drei").is_err());
+    Ok(())
+}
+
+#[cfg(feature = "csv_output")]
+#[test]
+fn test_filter_klassen_und_ausgabe() -> Result<(), Box<dyn std::error::Error>> {
+    let query = Query::rows("1-5")
+        .columns(["Religion"])
+        .typen(["primzahl"])
+        .klassen(["gestirn"])
+        .output(std::sync::Arc::new(CsvSyntax));
+    let tabelle = query.ausfuehren(&daten())?;
+    assert_eq!(tabelle.zahlen(), vec![2, 3, 5]);
+    assert_eq!(
+        query.rendern(&daten())?,
+        "n;gestirn;Religion\n2;sonne, planet;Zwei\n3;sonne, schwarzesonne;Drei\n5;sonne;Fünf\n"
+    );
+    assert_eq!(tabelle.strukturiert().zeilen[0].zeile, 2);
+
+    let schmal = Query::rows("2").columns(["Religion"]).breite(2).ausfuehren(&daten())?;
+    assert_eq!(schmal.strukturiert().zeilen[0].zellen[1].zeilen, vec!["Zw", "ei"]);
+
+    assert!(Query::rows("1")
+        .typen(["komet"])
+        .ausfuehren(&daten())
+        .is_err());
+    assert!(Query::rows("1")
+        .klassen(["komet"])
+        .ausfuehren(&daten())
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_laden() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    fs::write(
+        ordner.path().join("religion.csv"),
+        "Nummer;Religion\n1;Eins\n2;Zwei\n",
+    )?;
+    fs::write(
+        ordner.path().join("kombi15.csv"),
+        "Kombi;Sterne\n2;Sirius\n",
+    )?;
+    let daten = Daten::laden(ordner.path())?;
+    assert!(daten.kombis.contains_key(&Kombi::Universum));
+    assert!(!daten.kombis.contains_key(&Kombi::Galaxie));
+
+    let tabelle = Query::rows("1-2")
+        .kombi(Kombi::Universum, ["Sterne"])
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zeilen[1].1[2].text, "(2) Sirius (2)");
+
+    assert!(Daten::laden(&ordner.path().join("fehlt")).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_ods_blatt_je_tabelle() -> Result<(), Box<dyn std::error::Error>> {
+    let mut daten = daten();
+    daten.kombi_setzen(
+        Kombi::Universum,
+        tabelle(&["Kombi;Sterne", "1;Sirius"]),
+    );
+    let tabelle = Query::rows("1-3")
+        .columns(["Motiv"])
+        .kombi(Kombi::Galaxie, ["Tiere"])
+        .kombi(Kombi::Universum, ["Sterne"])
+        .ausfuehren(&daten)?;
+    let dokument = Tabellenkalkulation::aus_tabelle(&tabelle);
+    let namen: Vec<&str> = dokument.blaetter.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(namen, vec!["tabelle", "kombi13", "kombi15"]);
+    let spalten = |i: usize| -> Vec<String> {
+        dokument.blaetter[i]
+            .tabelle
+            .spalten
+            .iter()
+            .map(|s| s.name.clone())
+            .collect()
+    };
+    assert_eq!(spalten(0), vec!["n", "Motiv"]);
+    assert_eq!(spalten(1), vec!["n", "Tiere"]);
+    assert_eq!(spalten(2), vec!["n", "Sterne"]);
+
+    // Without kombi columns there is one sheet
+    let ohne = Query::rows("1-3").ausfuehren(&daten)?;
+    assert_eq!(Tabellenkalkulation::aus_tabelle(&ohne).blaetter.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_generierte_spalten() -> Result<(), Box<dyn std::error::Error>> {
+    // Wide enough for the registry's kreativitaet column 64
+    let kopf: Vec<String> = (0..65).map(|i| format!("S{}", i)).collect();
+    let kopf: Vec<&str> = kopf.iter().map(String::as_str).collect();
+    let daten = common::nummeriert(&kopf, 4, |zahl| vec![format!("z{}", zahl); 64]);
+
+    // Selecting the parameter column runs its generators
+    let tabelle = Query::rows("1-4").columns(["kreativitaet"]).ausfuehren(&daten)?;
+    let namen: Vec<&str> = tabelle.spalten.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        namen,
+        vec![
+            "n",
+            "S64",
+            "Evolutions-Züchtungs-Kreativität",
+            "Mond-Typ eines Sternpolygons",
+            "Mond-Typ eines gleichförmigen Polygons",
+        ]
+    );
+    assert_eq!(
+        tabelle.spalten[2].quelle,
+        SpaltenQuelle::Generiert("primkreativitaet_generiert".to_string())
+    );
+    let kreativitaet: Vec<&str> = tabelle.zeilen.iter().map(|(_, z)| z[2].text.as_str()).collect();
+    assert_eq!(
+        kreativitaet,
+        vec![
+            "0. Primzahl 1",
+            "1. Primzahl und Sonnenzahl",
+            "1. Primzahl und Sonnenzahl",
+            "3. Mondzahl",
+        ]
+    );
+    // The header keeps the registry id as its parameter
+    assert_eq!(tabelle.kopf[2].parameter.as_deref(), Some("primkreativitaet_generiert"));
+
+    // A generated column named on its own selects its parameter column
+    let auch = Query::rows("1-4")
+        .columns(["primkreativitaet_generiert"])
+        .ausfuehren(&daten)?;
+    assert_eq!(auch.spalten, tabelle.spalten);
+
+    // Without a generated column the table is as it was
+    let ohne = Query::rows("1-4").columns(["S3"]).ausfuehren(&daten)?;
+    assert_eq!(ohne.spalten.len(), 2);
+    Ok(())
+}
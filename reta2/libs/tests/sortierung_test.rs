@@ -0,0 +1,127 @@
+mod common;
+
+use lib4tables_concat::*;
+
+fn daten() -> Daten {
+    common::nummeriert(&["Nummer", "Religion"], 12, |zahl| match zahl % 3 {
+        0 => vec![String::new()],
+        _ => vec![format!("R{}", zahl % 4)],
+    })
+}
+
+#[test]
+fn test_schluessel_parse() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(Schluessel::parse("primfaktoren")?, Schluessel::Primfaktoren);
+    assert_eq!(
+        Schluessel::parse("primkreativitaet")?,
+        Schluessel::Gruppierung(Gruppierung::Klassifikator("primkreativitaet".to_string()))
+    );
+    assert_eq!(
+        Schluessel::parse("3")?,
+        Schluessel::Spalte(Spalte::Index(3))
+    );
+    // --gruppiere= and --pivot= read groupings alike
+    assert_eq!(
+        Schluessel::parse("gestirn")?,
+        Schluessel::Gruppierung(Pivot::parse("gestirn")?.gruppierung)
+    );
+    let liste = Sortierung::parse_liste("-mondexponent, zaehlung")?;
+    assert!(liste[0].absteigend);
+    assert_eq!(liste[1].schluessel, Schluessel::Gruppierung(Gruppierung::Zaehlung));
+    assert!(Schluessel::parse(" ").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sortieren() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = daten();
+    let tabelle = Query::rows("1-12")
+        .sortiere(Sortierung::parse_liste("-primfaktoren")?)
+        .ausfuehren(&daten)?;
+    // 3 prime factors: 8 and 12; the numbers break ties; 1 has none
+    assert_eq!(
+        tabelle.zahlen(),
+        vec![8, 12, 4, 6, 9, 10, 2, 3, 5, 7, 11, 1]
+    );
+    assert!(tabelle.gruppen.is_empty());
+
+    // Empty cells come last, also descending
+    let tabelle = Query::rows("1-12")
+        .sortiere(Sortierung::parse_liste("-Religion")?)
+        .ausfuehren(&daten)?;
+    assert_eq!(&tabelle.zahlen()[..3], &[7, 11, 2]);
+    assert_eq!(&tabelle.zahlen()[8..], &[3, 6, 9, 12]);
+
+    let tabelle = Query::rows("1-12")
+        .sortiere(Sortierung::parse_liste("mondexponent")?)
+        .ausfuehren(&daten)?;
+    assert_eq!(&tabelle.zahlen()[..3], &[4, 9, 8]);
+
+    assert!(Query::rows("1")
+        .sortiere(Sortierung::parse_liste("Motiv")?)
+        .ausfuehren(&daten)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_gruppieren() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1-12")
+        .gruppiere(Schluessel::parse("primkreativitaet")?)
+        .sortiere(Sortierung::parse_liste("-primfaktoren")?)
+        .ausfuehren(&daten())?;
+    assert_eq!(
+        tabelle.zahlen(),
+        vec![1, 2, 3, 5, 7, 11, 12, 6, 10, 8, 4, 9]
+    );
+    let koepfe: Vec<(usize, &str)> = tabelle
+        .gruppen
+        .iter()
+        .map(|g| (g.ab, g.titel.as_str()))
+        .collect();
+    assert_eq!(
+        koepfe,
+        vec![
+            (0, "primkreativitaet: eins"),
+            (1, "primkreativitaet: primzahl"),
+            (6, "primkreativitaet: primvielfaches"),
+            (9, "primkreativitaet: mondzahl"),
+        ]
+    );
+
+    let text = tabelle.rendern(&ShellSyntax)?;
+    assert!(text.contains("primkreativitaet: primvielfaches"));
+    Ok(())
+}
+
+#[test]
+fn test_gruppen_koepfe_je_syntax() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1-4")
+        .gruppiere(Schluessel::parse("zaehlung")?)
+        .ausfuehren(&daten())?;
+    let titel = &tabelle.gruppen[0].titel;
+
+    #[cfg(feature = "markdown_output")]
+    assert!(tabelle
+        .rendern(&MarkdownSyntax)?
+        .contains(&format!("| **{}** | |\n", titel)));
+    #[cfg(feature = "html_output")]
+    assert!(tabelle.rendern(&HtmlSyntax)?.contains(&format!(
+        "<tr class=\"gruppe\"><th colspan=\"2\">{}</th></tr>",
+        titel
+    )));
+    assert!(tabelle
+        .rendern(&AsciiDocSyntax)?
+        .contains(&format!("2+|*{}*\n", titel)));
+    assert!(tabelle
+        .rendern(&LatexSyntax)?
+        .contains(&format!("\\multicolumn{{2}}{{l}}{{\\textbf{{{}}}}}", titel)));
+    assert!(tabelle
+        .rendern(&JsonLinesSyntax)?
+        .contains(&format!("\"gruppe\":\"{}\"", titel)));
+    assert!(tabelle
+        .rendern(&EmacsSyntax)?
+        .contains(&format!("*{}*", titel)));
+    assert!(!tabelle.rendern(&NichtsSyntax)?.contains(titel.as_str()));
+    Ok(())
+}
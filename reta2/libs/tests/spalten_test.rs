@@ -0,0 +1,137 @@
+mod common;
+
+use std::collections::HashSet;
+use std::fs;
+
+use lib4tables_concat::*;
+
+#[test]
+fn test_standard_registry() -> Result<(), Box<dyn std::error::Error>> {
+    let registry = SpaltenRegistry::standard();
+    assert_eq!(registry.nummer("kreativitaet")?, 64);
+    assert_eq!(registry.nummer("mond_typ_gleichfoermig")?, 56);
+    // Columns of the meta and concrete fractions of the universe
+    assert_eq!(registry.nummer("strukturalie")?, 5);
+    assert_eq!(registry.nummer("strukturalie_invers")?, 131);
+    assert_eq!(registry.nummer("strukturalie_universum")?, 198);
+    assert_eq!(registry.nummer("strukturalie_invers_universum")?, 201);
+    assert_eq!(
+        registry.tags("liebe_polygon_generiert")?,
+        HashSet::from([ST::SternPolygon, ST::Galaxie, ST::GleichfoermigesPolygon])
+    );
+    let vervielfacht: Vec<&str> = registry
+        .mit_generator("vervielfache_zeile")
+        .iter()
+        .map(|d| d.id.as_str())
+        .collect();
+    assert_eq!(
+        vervielfacht,
+        vec!["motive_vielfache", "strukturen_vielfache"]
+    );
+    assert!(registry
+        .vom_typ(SpaltenTypKey::Generated1)
+        .iter()
+        .all(|d| d.spalte.is_none() && d.parameter.is_some()));
+    // The tables read_concat_csv adds, by kind and source file
+    let prim = registry.definition("primzahlvielfache")?;
+    assert_eq!((prim.typ, prim.quelle.as_deref()), (SpaltenTypKey::Concat1, Some("prim.csv")));
+    let bruch = registry.definition("gebrochen_galaxie_1pn")?;
+    assert_eq!(bruch.typ.schluessel(), 6);
+    assert_eq!(bruch.quelle.as_deref(), Some("bruch13.csv"));
+    assert_eq!(registry.vom_typ(SpaltenTypKey::GebrGroe1).len(), 2);
+
+    // Ids and headers in every language
+    assert_eq!(
+        registry.finden("Structure Size").map(|d| d.id.as_str()),
+        Some("strukturgroesse")
+    );
+    assert_eq!(registry.finden("liebe").and_then(|d| d.spalte), Some(8));
+    assert_eq!(registry.definition("liebe")?.name("en"), "love");
+    assert_eq!(registry.definition("liebe")?.name("fr"), "Liebe");
+    assert!(registry.nummer("farbe").is_err());
+    assert!(registry.nummer("primkreativitaet_generiert").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_aus_json() -> Result<(), Box<dyn std::error::Error>> {
+    let doppelt = r#"[
+        {"id": "a", "typ": "ordinary", "spalte": 1},
+        {"id": "a", "typ": "ordinary", "spalte": 2}
+    ]"#;
+    assert!(SpaltenRegistry::aus_json(doppelt).is_err());
+    let verweis = r#"[{"id": "b", "typ": "generated1", "parameter": "fehlt"}]"#;
+    assert!(SpaltenRegistry::aus_json(verweis).is_err());
+    assert!(SpaltenRegistry::aus_json(r#"[{"id": "c", "typ": "farbe"}]"#).is_err());
+    // The first names of the kinds are still read
+    let alt = SpaltenRegistry::aus_json(r#"[{"id": "d", "typ": "religion"}, {"id": "e", "typ": "kombi"}]"#)?;
+    assert_eq!(alt.definition("d")?.typ, SpaltenTypKey::Ordinary);
+    assert_eq!(alt.definition("e")?.typ, SpaltenTypKey::Kombi1);
+
+    let ordner = tempfile::tempdir()?;
+    let pfad = ordner.path().join("spalten.json");
+    fs::write(
+        &pfad,
+        r#"[{"id": "motiv", "namen": {"de": "Motiv"}, "typ": "ordinary", "tags": ["Galaxie"], "quelle": "religion.csv", "spalte": 2}]"#,
+    )?;
+    let registry = SpaltenRegistry::laden(&pfad)?;
+    assert_eq!(registry.namen(), vec!["motiv"]);
+    assert_eq!(registry.tags("motiv")?, HashSet::from([ST::Galaxie]));
+    assert!(SpaltenRegistry::laden(&ordner.path().join("fehlt.json")).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_concat_folgt_registry() -> Result<(), Box<dyn std::error::Error>> {
+    // religion.csv with the love columns moved to the front
+    let registry = SpaltenRegistry::aus_json(
+        r#"[
+        {"id": "strukturgroesse", "typ": "ordinary", "spalte": 2},
+        {"id": "liebe", "typ": "ordinary", "spalte": 0},
+        {"id": "liebe_polygon", "typ": "ordinary", "spalte": 1},
+        {"id": "liebe_polygon_generiert", "typ": "generated1", "tags": ["Galaxie"],
+         "generator": "concat_love_polygon", "parameter": "liebe_polygon", "aus": "liebe"}
+    ]"#,
+    )?;
+    let mut concat = Concat::mit_spalten(Tables::default(), common::datenquelle(), registry);
+    let mut relitable = vec![
+        vec!["Liebe".to_string(), "Polygon".to_string(), "SG".to_string()],
+        vec!["herzlich".to_string(), String::new(), "5".to_string()],
+    ];
+    let mut rows_as_numbers = HashSet::from([1]);
+    concat.concat_love_polygon(&mut relitable, &mut rows_as_numbers)?;
+    assert_eq!(relitable[1].len(), 4);
+    assert!(relitable[1][3].starts_with("herzlich"));
+    assert!(relitable[1][3].contains("(5)"));
+    assert_eq!(
+        concat
+            .tables
+            .generated_spalten_parameter_tags
+            .values()
+            .next(),
+        Some(&HashSet::from([ST::Galaxie]))
+    );
+
+    // A registry without the column fails instead of reading the wrong one
+    concat.spalten = SpaltenRegistry::new();
+    assert!(concat
+        .concat_love_polygon(&mut relitable, &mut rows_as_numbers)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_query_mit_registry_namen() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = common::breit(5);
+    let tabelle = Query::rows("1")
+        .columns(["structure size", "S1"])
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.spalten[1].quelle, SpaltenQuelle::Religion(4));
+    assert_eq!(tabelle.zeilen[0].1[1].text, "z4");
+    // Known to the registry, but past the end of this table
+    assert!(Query::rows("1")
+        .columns(["liebe"])
+        .ausfuehren(&daten)
+        .is_err());
+    Ok(())
+}
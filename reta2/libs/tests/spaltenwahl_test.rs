@@ -0,0 +1,87 @@
+mod common;
+
+use lib4tables_concat::*;
+
+#[test]
+fn test_term_parse() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        Term::parse("-@galaxie")?,
+        Term {
+            auswahl: Auswahl::Gruppe(ST::Galaxie),
+            weg: true,
+        }
+    );
+    assert_eq!(
+        Term::parse(" 3 ")?.auswahl,
+        Auswahl::Spalte(Spalte::Index(3))
+    );
+    assert_eq!(Term::parse("*")?.auswahl, Auswahl::Alle);
+    assert_eq!(Term::parse("-Motiv")?.text(), "-Motiv");
+    assert_eq!(Term::parse("@universum")?.text(), "@Universum");
+    assert!(Term::parse("@Komet").is_err());
+    assert!(Term::parse("-").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_mengen() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = common::registry_breit();
+    let nummern = |text: &str| SpaltenAuswahl::parse(text)?.nummern(&daten);
+
+    // Galaxie columns of the registry within the table: 4, 8, 9, 10, 11
+    assert_eq!(nummern("@Galaxie")?, vec![4, 8, 9, 10, 11]);
+    assert_eq!(nummern("@Galaxie,-liebe,2")?, vec![4, 9, 10, 11, 2]);
+    // A removal wins over any addition, wherever it stands
+    assert_eq!(nummern("-S3,3,S3")?, Vec::<usize>::new());
+    assert_eq!(
+        nummern("@Galaxie,-@GleichfoermigesPolygon")?,
+        vec![4, 8, 10, 11]
+    );
+    // Only removals start from every column
+    assert_eq!(nummern("-1,-@Galaxie")?, vec![2, 3, 5, 6, 7]);
+    assert_eq!(nummern("")?, (1..12).collect::<Vec<_>>());
+    assert!(nummern("S99").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_erklaerung() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = common::registry_breit();
+    let query = Query::rows("1")
+        .gruppe(ST::Galaxie)
+        .columns(["S8"])
+        .ohne(["Wirkung"])
+        .ohne_gruppe(ST::Universum);
+    let erklaerung = query.erklaeren(&daten)?;
+    assert_eq!(
+        erklaerung.spalten[&8],
+        Beitrag {
+            dazu: vec!["@Galaxie".to_string(), "S8".to_string()],
+            weg: Vec::new(),
+        }
+    );
+    assert_eq!(erklaerung.spalten[&10].weg, vec!["-Wirkung"]);
+    assert_eq!(erklaerung.nummern(), vec![4, 8, 9, 11]);
+
+    let tabelle = query.ausfuehren(&daten)?;
+    let kopf: Vec<&str> = tabelle.kopf.iter().map(|z| z.text.as_str()).collect();
+    // S9 is liebe_polygon, whose selection generates the love polygon column
+    assert_eq!(
+        kopf,
+        vec![
+            "n",
+            "S4",
+            "S8",
+            "S9",
+            "S11",
+            "S8 der eigenen Strukturgröße (S4) auf dich bei gleichförmigen Polygonen",
+        ]
+    );
+
+    let text = erklaerung.rendern(&daten.religion, &ShellSyntax)?;
+    let zeile = text.lines().find(|z| z.contains("S10")).unwrap();
+    assert!(zeile.contains("@Galaxie"));
+    assert!(zeile.contains("Wirkung"));
+    assert!(zeile.contains("nein"));
+    Ok(())
+}
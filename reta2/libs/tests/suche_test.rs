@@ -0,0 +1,188 @@
+mod common;
+
+use lib4tables_concat::*;
+
+fn daten() -> Daten {
+    let religion = [
+        "Liebe und Frieden",
+        "Krieg",
+        "liebevoll<br>gut",
+        "Weisheit",
+        "Gerechtigkeit",
+        "Barmherzigkeit",
+        "Liebe, Liebe",
+    ];
+    common::nummeriert(&["Nummer", "Religion", "Motiv"], 7, |zahl| {
+        let motiv = if zahl % 2 == 1 { "Liebe" } else { "" };
+        vec![religion[zahl as usize - 1].to_string(), motiv.to_string()]
+    })
+}
+
+#[test]
+fn test_suche_text_und_regex() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = daten();
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::text("LIEBE").spalten(["Religion"]))
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![1, 3, 7]);
+    // Without highlighting the cells stay unmarked
+    assert!(tabelle.zeilen[0].1[1].treffer.is_empty());
+
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::regex("keit$")?.spalten([1]))
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![5, 6]);
+
+    // Regex metacharacters in a text are plain text
+    assert!(Query::rows("1-7")
+        .suche(Suche::text("Liebe."))
+        .ausfuehren(&daten)?
+        .zahlen()
+        .is_empty());
+    assert!(Suche::regex("(Liebe").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_suche_spalten() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = daten();
+    // Without columns every shown column is searched
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::text("liebe"))
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![1, 3, 5, 7]);
+
+    // A column not shown is searched in religion.csv
+    let tabelle = Query::rows("1-7")
+        .columns(["Religion"])
+        .suche(Suche::text("liebe").spalten(["Motiv"]))
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![1, 3, 5, 7]);
+
+    assert!(Query::rows("1-7")
+        .suche(Suche::text("liebe").spalten(["Komet"]))
+        .ausfuehren(&daten)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_suche_mit_bereich_und_typ() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = daten();
+    let tabelle = Query::rows("2-7")
+        .typen(["primzahl"])
+        .suche(Suche::text("liebe").spalten(["Religion"]))
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![3, 7]);
+
+    // Every search has to match
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::text("liebe").spalten(["Motiv"]))
+        .suche(Suche::regex("^Liebe")?.spalten(["Religion"]))
+        .ausfuehren(&daten)?;
+    assert_eq!(tabelle.zahlen(), vec![1, 7]);
+    Ok(())
+}
+
+#[test]
+fn test_hervorheben() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::text("liebe").spalten(["Religion"]).hervorheben(true))
+        .suche(
+            Suche::regex("e, L")?
+                .spalten(["Religion"])
+                .hervorheben(true),
+        )
+        .ausfuehren(&daten())?;
+    assert_eq!(tabelle.zahlen(), vec![7]);
+    // Overlapping matches are joined
+    assert_eq!(tabelle.zeilen[0].1[1].treffer, vec![0..12]);
+    // Only the searched column is marked
+    assert!(tabelle.zeilen[0].1[2].treffer.is_empty());
+
+    let tabelle = Query::rows("1-3")
+        .suche(Suche::text("liebe").spalten(["Religion"]).hervorheben(true))
+        .ausfuehren(&daten())?;
+    assert!(tabelle
+        .rendern(&ShellSyntax)?
+        .contains("\x1b[7mLiebe\x1b[27m und Frieden"));
+    #[cfg(feature = "html_output")]
+    {
+        let html = tabelle.rendern(&HtmlSyntax)?;
+        assert!(html.contains("<td><mark>Liebe</mark> und Frieden</td>"));
+        assert!(html.contains("<mark>liebe</mark>voll&lt;br&gt;gut"));
+    }
+    #[cfg(feature = "markdown_output")]
+    assert!(tabelle
+        .rendern(&MarkdownSyntax)?
+        .contains("| **Liebe** und Frieden |"));
+    assert!(tabelle
+        .rendern(&LatexSyntax)?
+        .contains("\\textbf{Liebe} und Frieden"));
+    assert!(tabelle
+        .rendern(&JsonLinesSyntax)?
+        .contains("\"treffer\":[[0,5]]"));
+    // CSV stays plain data
+    #[cfg(feature = "csv_output")]
+    assert!(tabelle.rendern(&CsvSyntax)?.contains(";Liebe und Frieden;"));
+    Ok(())
+}
+
+#[test]
+fn test_hervorheben_shell_auffuellen() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::text("liebe").spalten(["Religion"]).hervorheben(true))
+        .ausfuehren(&daten())?;
+    let text = tabelle.rendern(&ShellSyntax)?;
+    // Religion is 17 columns wide and not the last column
+    assert!(text.contains("\x1b[7mLiebe\x1b[27m, \x1b[7mLiebe\x1b[27m      Liebe"));
+    let breiten: Vec<usize> = text.lines().map(anzeige_breite).collect();
+    assert!(breiten.iter().all(|&b| b == breiten[0]), "{:?}", breiten);
+    Ok(())
+}
+
+#[test]
+fn test_leere_treffer() -> Result<(), Box<dyn std::error::Error>> {
+    // Patterns matching with zero width filter like any other
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::regex("^$")?.spalten(["Motiv"]).hervorheben(true))
+        .ausfuehren(&daten())?;
+    assert_eq!(tabelle.zahlen(), vec![2, 4, 6]);
+    // There is nothing to mark
+    assert!(tabelle.zeilen[0].1[2].treffer.is_empty());
+    let tabelle = Query::rows("1-7")
+        .suche(Suche::regex("^")?)
+        .ausfuehren(&daten())?;
+    assert_eq!(tabelle.zahlen(), (1..=7).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn test_hervorheben_in_listen() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = Daten::new(common::tabelle(&[
+        "Nummer;Religion",
+        "1;Motive:<li>Liebe</li><li>Frieden und Liebe</li>",
+    ]));
+    let tabelle = Query::rows("1")
+        .suche(Suche::text("liebe").hervorheben(true))
+        .ausfuehren(&daten)?;
+    #[cfg(feature = "html_output")]
+    assert!(tabelle.rendern(&HtmlSyntax)?.contains(
+        "Motive:<ul><li><mark>Liebe</mark></li><li>Frieden und <mark>Liebe</mark></li></ul>"
+    ));
+    assert!(tabelle.rendern(&LatexSyntax)?.contains(
+        "\\begin{itemize}\\item \\textbf{Liebe} \\item Frieden und \\textbf{Liebe}\\end{itemize}"
+    ));
+    assert!(tabelle
+        .rendern(&AsciiDocSyntax)?
+        .contains("a|Motive:\n\n* *Liebe*\n* Frieden und *Liebe*\n"));
+
+    // A match reaching into the list markup stays unmarked
+    let tabelle = Query::rows("1")
+        .suche(Suche::regex("Liebe</li><li>Frieden")?.hervorheben(true))
+        .ausfuehren(&daten)?;
+    assert!(tabelle
+        .rendern(&LatexSyntax)?
+        .contains("\\item Liebe \\item Frieden und Liebe"));
+    Ok(())
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use std::fs;
+
+use lib4tables_concat::*;
+
+fn kopf(tabelle: &TableData) -> Vec<&str> {
+    tabelle.kopf.iter().map(|z| z.text.as_str()).collect()
+}
+
+#[test]
+fn test_aliase_und_umbenennen() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        SpaltenRegistry::standard()
+            .finden("sg")
+            .map(|d| d.id.as_str()),
+        Some("strukturgroesse")
+    );
+    let daten = common::registry_breit();
+    let query = Query::rows("1")
+        .auswahl(SpaltenAuswahl::parse("SG,liebe,S3")?)
+        .umbenennen("SG", "Größe")
+        .umbenennen("3", "Drei");
+    let tabelle = query.ausfuehren(&daten)?;
+    assert_eq!(kopf(&tabelle), vec!["n", "Größe", "S8", "Drei"]);
+    assert_eq!(tabelle.zeilen[0].1[1].text, "z4");
+
+    // The same layout with English headers, one renamed to something short
+    let en = Query::rows("1")
+        .columns(["SG", "liebe"])
+        .sprache("en")
+        .umbenennen("liebe", "Love")
+        .ausfuehren(&daten)?;
+    assert_eq!(kopf(&en), vec!["n", "structure size", "Love"]);
+
+    let fehler = Query::rows("1")
+        .columns(["SG"])
+        .umbenennen("liebe", "Liebe")
+        .ausfuehren(&daten);
+    assert!(fehler.is_err());
+    Ok(())
+}
+
+#[cfg(feature = "html_output")]
+#[test]
+fn test_html_behaelt_klassen() -> Result<(), Box<dyn std::error::Error>> {
+    let tabelle = Query::rows("1")
+        .columns(["SG"])
+        .umbenennen("SG", "Größe")
+        .ausfuehren(&common::registry_breit())?;
+    assert_eq!(tabelle.kopf[1].original.as_deref(), Some("S4"));
+    let html = tabelle.rendern(&HtmlSyntax)?;
+    assert!(html
+        .contains("<th class=\"p1_strukturgroesse p2_S4 p4_SternPolygon p4_Galaxie\">Größe</th>"));
+    Ok(())
+}
+
+#[test]
+fn test_vorlage_speichern_und_laden() -> Result<(), Box<dyn std::error::Error>> {
+    let ordner = tempfile::tempdir()?;
+    let pfad = ordner.path().join("reta.json");
+    fs::write(&pfad, "{\"daten\": \"csv\", \"profile\": {\"de\": {}}}")?;
+
+    let gespeichert = Query::rows("1")
+        .auswahl(SpaltenAuswahl::parse("@Galaxie,-liebe_polygon")?)
+        .umbenennen("mond_wirkung_zusatz", "effect too")
+        .sprache("en");
+    Konfiguration::vorlage_speichern(&pfad, "wiki-en", &gespeichert.als_vorlage())?;
+
+    let konfig = Konfiguration::laden(&pfad)?;
+    // The rest of the file is kept as written
+    assert_eq!(konfig.daten, Some(ordner.path().join("csv")));
+    assert_eq!(konfig.profil_namen(), vec!["de"]);
+    let vorlage = konfig.vorlage("wiki-en")?;
+    assert_eq!(vorlage.spalten, "@Galaxie,-liebe_polygon");
+    assert_eq!(vorlage.sprache.as_deref(), Some("en"));
+    assert!(konfig.vorlage("wiki-de").is_err());
+
+    let tabelle = Query::rows("1").vorlage(vorlage)?.ausfuehren(&common::registry_breit())?;
+    assert_eq!(
+        kopf(&tabelle),
+        vec!["n", "structure size", "love", "effect", "effect too"]
+    );
+    Ok(())
+}
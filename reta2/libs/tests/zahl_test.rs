@@ -0,0 +1,46 @@
+use lib4tables_concat::*;
+
+#[test]
+fn test_zaehlungen() {
+    let zaehlungen = Zaehlungen::new(30);
+
+    assert_eq!(zaehlungen.zeile_which_zaehlung(1), Some(1));
+    assert_eq!(zaehlungen.zeile_which_zaehlung(4), Some(1));
+    assert_eq!(zaehlungen.zeile_which_zaehlung(5), Some(2));
+    assert_eq!(zaehlungen.zeile_which_zaehlung(10), Some(3));
+    assert_eq!(zaehlungen.zeile_which_zaehlung(31), None);
+
+    let weiter = Zaehlungen::new(2000);
+    for zahl in 1..=2000 {
+        assert_eq!(Zaehlungen::zaehlung_von(zahl), weiter.zeile_which_zaehlung(zahl));
+    }
+    assert_eq!(Zaehlungen::zaehlung_von(0), None);
+}
+
+#[test]
+fn test_moon_number_grosse_zahlen() {
+    assert_eq!(moon_number(64), (vec![8, 4, 2], vec![0, 1, 4]));
+    assert_eq!(moon_number(1 << 30), (vec![32768, 1024, 64, 32, 8, 4, 2], vec![0, 1, 3, 4, 8, 13, 28]));
+    assert_eq!(moon_number(i32::MAX), (vec![], vec![]));
+    assert_eq!(moon_number(3), (vec![], vec![]));
+    assert_eq!(divisor_generator(36), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+}
+
+#[test]
+fn test_zahl_profil() -> Result<(), Box<dyn std::error::Error>> {
+    let zaehlungen = Zaehlungen::new(64);
+    let profil = ZahlProfil::new(64, &zaehlungen)?;
+
+    assert_eq!(profil.primfaktoren, vec![(2, 6)]);
+    assert_eq!(profil.monde.len(), 3);
+    assert_eq!(profil.prim_creativity, 3);
+    assert_eq!(profil.teiler, vec![1, 2, 4, 8, 16, 32, 64]);
+    assert!(profil.texte.iter().all(|t| !t.text.is_empty()));
+
+    let json: serde_json::Value = serde_json::from_str(&profil.ausgeben(ProfilFormat::Json)?)?;
+    assert_eq!(json["zahl"], 64);
+
+    assert!(profil.ausgeben(ProfilFormat::Html)?.starts_with("<table"));
+    assert!(ZahlProfil::new(0, &zaehlungen).is_err());
+    Ok(())
+}
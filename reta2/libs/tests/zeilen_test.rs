@@ -0,0 +1,125 @@
+mod common;
+
+use lib4tables_concat::*;
+
+fn filtern(bedingungen: &[Zeilenbedingung]) -> Vec<i32> {
+    zeilen_filtern(bedingungen, HOECHSTE_ZEILE, &Zaehlungen::new(HOECHSTE_ZEILE))
+        .into_iter()
+        .collect()
+}
+
+fn bereich(text: &str) -> Zeilenbedingung {
+    Zeilenbedingung::Bereich(text.to_string())
+}
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        Zeilenbedingung::parse("--typ=sonne,mond")?,
+        vec![
+            Zeilenbedingung::Gestirn(Gestirn::Sonne),
+            Zeilenbedingung::Gestirn(Gestirn::Mond),
+        ]
+    );
+    assert_eq!(
+        Zeilenbedingung::parse("--vorhervonausschnittteiler=12")?,
+        vec![bereich("12"), Zeilenbedingung::Teiler]
+    );
+    assert_eq!(
+        Zeilenbedingung::parse("--primzahlvielfache=2,3")?,
+        vec![
+            Zeilenbedingung::Primzahlvielfache(2),
+            Zeilenbedingung::Primzahlvielfache(3),
+        ]
+    );
+    assert!(Zeilenbedingung::parse("--typ=komet").is_err());
+    assert!(Zeilenbedingung::parse("--unbekannt").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_bereich_und_teiler() {
+    assert_eq!(filtern(&[bereich("12"), Zeilenbedingung::Teiler]), vec![1, 2, 3, 4, 6, 12]);
+    // A removing part also removes from everything
+    let alles = filtern(&[Zeilenbedingung::Alles, bereich("-2-1024")]);
+    assert_eq!(alles, vec![1]);
+}
+
+#[test]
+fn test_gestirne_und_primzahlen() {
+    use Zeilenbedingung::{Gestirn as G, Primzahlen as P};
+    assert_eq!(filtern(&[bereich("1-20"), G(Gestirn::Mond)]), vec![4, 8, 9, 16]);
+    assert_eq!(
+        filtern(&[bereich("1-30"), P(Primzahlen::AussenErste)]),
+        vec![5, 11, 17, 23, 29]
+    );
+    assert_eq!(filtern(&[bereich("1-15"), P(Primzahlen::InnenAlle)]), vec![1, 7, 13, 14]);
+    assert_eq!(
+        filtern(&[bereich("1-20"), G(Gestirn::SonneMitMondanteil)]),
+        vec![12, 18, 20]
+    );
+}
+
+#[test]
+fn test_zeit_und_zaehlung() {
+    assert_eq!(filtern(&[Zeilenbedingung::Zeit(Zeit::Gestern)]), (1..10).collect::<Vec<_>>());
+    assert_eq!(
+        filtern(&[Zeilenbedingung::Alles, Zeilenbedingung::Zeit(Zeit::Heute)]),
+        vec![10]
+    );
+    // The second counting starts after the moon 4 and ends with the moons 8 and 9
+    assert_eq!(
+        filtern(&[Zeilenbedingung::Zaehlung("2".to_string())]),
+        vec![5, 6, 7, 8, 9]
+    );
+}
+
+#[test]
+fn test_vielfache_und_potenzen() {
+    assert_eq!(
+        filtern(&[bereich("1-20"), Zeilenbedingung::Primzahlvielfache(2)]),
+        vec![4, 6, 10, 14]
+    );
+    assert_eq!(
+        filtern(&[bereich("1-40"), Zeilenbedingung::Potenzen("2".to_string())]),
+        vec![2, 4, 8, 16, 32]
+    );
+    assert_eq!(
+        filtern(&[Zeilenbedingung::Vielfache("50".to_string())]),
+        vec![50, 100]
+    );
+}
+
+#[test]
+fn test_sonnen_invertieren_neuabzaehlung() {
+    // Above 114 only moons are left
+    assert_eq!(
+        filtern(&[bereich("110-130")]),
+        vec![110, 111, 112, 113, 114, 121, 125, 128]
+    );
+    assert_eq!(filtern(&[bereich("5-7"), Zeilenbedingung::Invertieren]), vec![4, 8]);
+    assert_eq!(
+        filtern(&[
+            bereich("1-20"),
+            Zeilenbedingung::Gestirn(Gestirn::Mond),
+            Zeilenbedingung::Neuabzaehlung("2-3".to_string()),
+        ]),
+        vec![8, 9]
+    );
+}
+
+#[test]
+fn test_query_mit_bedingungen() -> Result<(), Box<dyn std::error::Error>> {
+    let daten = common::religion(20);
+    let monde = Query::rows("")
+        .bedingungen([Zeilenbedingung::Gestirn(Gestirn::Mond)])
+        .ausfuehren(&daten)?;
+    assert_eq!(monde.zahlen(), vec![4, 8, 9, 16]);
+
+    // The range of the query is one more condition
+    let planeten = Query::rows("1-10")
+        .bedingungen(Zeilenbedingung::parse("--typ=planet")?)
+        .ausfuehren(&daten)?;
+    assert_eq!(planeten.zahlen(), vec![2, 4, 6, 8, 10]);
+    Ok(())
+}